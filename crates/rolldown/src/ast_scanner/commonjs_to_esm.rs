@@ -0,0 +1,161 @@
+use oxc::{
+  ast::ast::{
+    AssignmentOperator, Expression, ObjectExpression, ObjectPropertyKind, Program, PropertyKind,
+    Statement,
+  },
+  span::{CompactStr, GetSpan},
+};
+use rolldown_common::{LocalExport, StmtInfoIdx, TaggedSymbolRef};
+use rustc_hash::FxHashSet;
+
+use super::{
+  AstScanner,
+  cjs_export_analyzer::{CommonJsAstType, is_object_define_property_es_module},
+  removable_members::object_member_name,
+};
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Checks if every reference to the global `exports` and `module` is part of a top-level
+  /// statement of one of these forms:
+  ///
+  /// ```js
+  /// exports.foo = ...;
+  /// exports.__esModule = true;
+  /// Object.defineProperty(exports, '__esModule', { value: true });
+  /// module.exports = { foo, bar: ... };
+  /// ```
+  ///
+  /// Such a module can be turned into an ES module by `LinkStage::convert_commonjs_to_esm`. Returns
+  /// the statements setting `__esModule`, which are dropped by the conversion.
+  pub fn analyze_convertible_commonjs(
+    &mut self,
+    program: &Program<'ast>,
+  ) -> Option<Vec<StmtInfoIdx>> {
+    let unresolved_references = self.result.symbol_ref_db.scoping().root_unresolved_references();
+    let exports_references = unresolved_references.get("exports").map_or(0, |refs| refs.len());
+    let module_references = unresolved_references.get("module").map_or(0, |refs| refs.len());
+
+    let mut recognized_exports_references = 0;
+    let mut recognized_module_references = 0;
+    let mut es_module_flag_stmts = vec![];
+    let mut export_names = FxHashSet::default();
+    let mut module_exports = None;
+    for (idx, stmt) in program.body.iter().enumerate() {
+      let Statement::ExpressionStatement(stmt) = stmt else {
+        continue;
+      };
+      // `0` is reserved for Module Namespace Object stmt info
+      let stmt_idx = StmtInfoIdx::new(idx + 1);
+      match &stmt.expression {
+        Expression::AssignmentExpression(assignment)
+          if assignment.operator == AssignmentOperator::Assign =>
+        {
+          let Some(member_expr) = assignment.left.as_member_expression() else {
+            continue;
+          };
+          let (Expression::Identifier(object), Some(property)) =
+            (member_expr.object(), member_expr.static_property_name())
+          else {
+            continue;
+          };
+          if !self.is_global_identifier_reference(object) {
+            continue;
+          }
+          match (object.name.as_str(), property) {
+            ("exports", "__esModule") => {
+              if matches!(&assignment.right, Expression::BooleanLiteral(lit) if lit.value) {
+                es_module_flag_stmts.push(stmt_idx);
+                recognized_exports_references += 1;
+              }
+            }
+            ("exports", name) => {
+              // `exports.foo` written twice can't become a single declaration.
+              if !export_names.insert(name) {
+                return None;
+              }
+              recognized_exports_references += 1;
+            }
+            ("module", "exports") => {
+              let Expression::ObjectExpression(obj) = &assignment.right else {
+                return None;
+              };
+              if module_exports.replace((stmt_idx, &**obj)).is_some() {
+                return None;
+              }
+              recognized_module_references += 1;
+            }
+            _ => {}
+          }
+        }
+        Expression::CallExpression(call_expr) => {
+          if is_object_define_property_es_module(&self.result.symbol_ref_db.ast_scopes, call_expr)
+            == Some(CommonJsAstType::EsModuleFlag)
+          {
+            es_module_flag_stmts.push(stmt_idx);
+            recognized_exports_references += 1;
+          }
+        }
+        _ => {}
+      }
+    }
+
+    // Any other reference, such as reading `exports`, assigning it in a function or passing
+    // `module` around, makes the exports dynamic.
+    if recognized_exports_references != exports_references
+      || recognized_module_references != module_references
+      || exports_references + module_references == 0
+    {
+      return None;
+    }
+
+    if let Some((stmt_idx, obj)) = module_exports {
+      // `module.exports = {}` replaces the object that `exports` points to.
+      if exports_references > 0 {
+        return None;
+      }
+      self.declare_module_exports_properties(stmt_idx, obj)?;
+    }
+    Some(es_module_flag_stmts)
+  }
+
+  /// Creates the export symbols for `module.exports = { foo, bar: ... }`, which are declared by
+  /// the statement once it's converted. `exports.foo = ...` already has them, see
+  /// `visit_assignment_expression`.
+  fn declare_module_exports_properties(
+    &mut self,
+    stmt_idx: StmtInfoIdx,
+    obj: &ObjectExpression<'ast>,
+  ) -> Option<()> {
+    let properties = obj
+      .properties
+      .iter()
+      .map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop)
+          if prop.kind == PropertyKind::Init && !prop.method =>
+        {
+          Some((object_member_name(prop)?, prop.key.span()))
+        }
+        _ => None,
+      })
+      .collect::<Option<Vec<_>>>()?;
+    let mut names = FxHashSet::default();
+    if !properties.iter().all(|(name, _)| names.insert(name.as_ref())) {
+      return None;
+    }
+
+    for (name, span) in &properties {
+      let exported_symbol = self.result.symbol_ref_db.create_facade_root_symbol_ref(name);
+      self
+        .result
+        .stmt_infos
+        .declare_symbol_for_stmt(stmt_idx, TaggedSymbolRef::LinkOnly(exported_symbol));
+      self
+        .result
+        .commonjs_exports
+        .entry(CompactStr::from(name.as_ref()))
+        .or_default()
+        .push(LocalExport { referenced: exported_symbol, span: *span, came_from_commonjs: true });
+    }
+    Some(())
+  }
+}
@@ -96,6 +96,10 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
         None,
       )
       .detect_side_effect_of_stmt(stmt);
+      if self.is_constant_ts_enum_declaration(stmt) {
+        // The IIFE building a constant enum only writes to the enum object.
+        self.current_stmt_info.side_effect = SideEffectDetail::empty();
+      }
 
       #[cfg(debug_assertions)]
       {
@@ -151,7 +155,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
 
   fn visit_for_of_statement(&mut self, it: &ast::ForOfStatement<'ast>) {
     let is_top_level_await = it.r#await && self.is_valid_tla_scope();
-    if is_top_level_await && !self.immutable_ctx.flat_options.keep_esm_import_export_syntax() {
+    if is_top_level_await && !self.is_top_level_await_supported() {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.immutable_ctx.id.resource_id().clone(),
         self.immutable_ctx.source.clone(),
@@ -171,7 +175,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
 
   fn visit_await_expression(&mut self, it: &ast::AwaitExpression<'ast>) {
     let is_top_level_await = self.is_valid_tla_scope();
-    if !self.immutable_ctx.flat_options.keep_esm_import_export_syntax() && is_top_level_await {
+    if is_top_level_await && !self.is_top_level_await_supported() {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.immutable_ctx.id.resource_id().clone(),
         self.immutable_ctx.source.clone(),
@@ -304,7 +308,8 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
           let is_special_property =
             static_name == "url" || static_name == "dirname" || static_name == "filename";
           let format = &self.immutable_ctx.options.format;
-          !is_special_property || matches!(format, OutputFormat::Iife | OutputFormat::Umd)
+          !is_special_property
+            || matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd)
         })
         // Here we need to set it to `true` to emit warnings when leaving `import.meta` alone along with the logic head of this.
         .unwrap_or(true);
@@ -567,6 +572,40 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
               self.add_member_expr_reference(root_symbol_id, props, span, ty);
             }
           }
+        } else if let Some(values) = self.result.ts_enum_values.get(&root_symbol_id.symbol)
+          && let Some((span, props)) = self.try_extract_parent_static_member_expr_chain(1)
+          && values.contains_key(&props[0].0)
+          && !span.is_unspanned()
+        {
+          // `Enum.Member` is inlined, so it shouldn't keep the enum object alive.
+          is_inserted_before = true;
+          self.add_member_expr_reference(
+            root_symbol_id,
+            props,
+            span,
+            MemberExprObjectReferencedType::Named,
+          );
+        } else if self.result.removable_members.contains_key(&root_symbol_id.symbol)
+          && let Some((span, props)) = self.try_extract_parent_static_member_expr_chain(1)
+          && !span.is_unspanned()
+        {
+          // Record which member is read, so unread members could be removed.
+          is_inserted_before = true;
+          self.add_member_expr_reference(
+            root_symbol_id,
+            props,
+            span,
+            MemberExprObjectReferencedType::Named,
+          );
+        }
+        if !is_inserted_before
+          && let Some(ty) = self.constructed_member_expr_type(ident_ref, root_symbol_id)
+        {
+          // `new X()` creates an instance without letting `X` escape, so the statics and instance
+          // methods that are never read could still be removed.
+          is_inserted_before = true;
+          self.add_member_expr_reference(root_symbol_id, vec![], ident_ref.span, ty);
+          self.result.member_reads.constructed.insert(ident_ref.span);
         }
         if !is_inserted_before {
           self.add_referenced_symbol(root_symbol_id);
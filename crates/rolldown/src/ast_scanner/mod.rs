@@ -1,10 +1,12 @@
 mod cjs_export_analyzer;
+mod commonjs_to_esm;
 pub mod const_eval;
 pub mod dynamic_import;
 mod hmr;
 pub mod impl_visit;
 mod import_assign_analyzer;
 mod new_url;
+pub mod removable_members;
 pub mod side_effect_detector;
 
 use arcstr::ArcStr;
@@ -31,11 +33,12 @@ use oxc_index::IndexVec;
 use rolldown_common::dynamic_import_usage::{DynamicImportExportsUsage, DynamicImportUsageInfo};
 use rolldown_common::{
   ConstExportMeta, ConstantValue, DynamicImportExprInfo, EcmaModuleAstUsage, EcmaViewMeta,
-  ExportsKind, FlatOptions, HmrInfo, ImportAttribute, ImportKind, ImportRecordIdx,
-  ImportRecordMeta, LocalExport, MemberExprObjectReferencedType, MemberExprRef, ModuleDefFormat,
-  ModuleId, ModuleIdx, NamedImport, RawImportRecord, SideEffectDetail, Specifier, StmtInfo,
-  StmtInfoIdx, StmtInfoMeta, StmtInfos, SymbolRef, SymbolRefDbForModule, SymbolRefFlags,
-  TaggedSymbolRef, ThisExprReplaceKind, generate_replace_this_expr_map,
+  EnumMemberValues, ExportsKind, FlatOptions, HmrInfo, ImportAttribute, ImportKind,
+  ImportRecordIdx, ImportRecordMeta, LocalExport, MemberExprObjectReferencedType, MemberExprRef,
+  MemberReads, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport, RawImportRecord,
+  SideEffectDetail, Specifier, StmtInfo, StmtInfoIdx, StmtInfoMeta, StmtInfos, SymbolRef,
+  SymbolRefDbForModule, SymbolRefFlags, TaggedSymbolRef, ThisExprReplaceKind,
+  generate_replace_this_expr_map,
 };
 use rolldown_ecmascript_utils::{BindingIdentifierExt, BindingPatternExt, FunctionExt};
 use rolldown_error::{BuildDiagnostic, BuildResult, CjsExportSpan};
@@ -110,6 +113,13 @@ pub struct ScanResult {
   pub hmr_hot_ref: Option<SymbolRef>,
   pub directive_range: Vec<Span>,
   pub constant_export_map: FxHashMap<SymbolId, ConstExportMeta>,
+  /// Member values of the top-level TypeScript enums, collected before the TypeScript transform.
+  pub ts_enum_values: FxHashMap<SymbolId, EnumMemberValues>,
+  /// Top-level object literals and classes -> names of the members that `treeshake.members` could
+  /// remove.
+  pub removable_members: FxHashMap<SymbolId, FxHashSet<CompactStr>>,
+  pub member_reads: MemberReads,
+  pub convertible_commonjs: Option<Vec<StmtInfoIdx>>,
   pub import_attribute_map: FxHashMap<ImportRecordIdx, ImportAttribute>,
 }
 
@@ -173,6 +183,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     options: &'me SharedOptions,
     allocator: &'ast oxc::allocator::Allocator,
     flat_options: FlatOptions,
+    ts_enum_values: FxHashMap<SymbolId, EnumMemberValues>,
   ) -> Self {
     let root_scope_id = scoping.root_scope_id();
     let mut symbol_ref_db = SymbolRefDbForModule::new(scoping, idx, root_scope_id);
@@ -213,6 +224,10 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       dummy_record_set: FxHashSet::default(),
       commonjs_exports: FxHashMap::default(),
       constant_export_map: FxHashMap::default(),
+      ts_enum_values,
+      removable_members: FxHashMap::default(),
+      member_reads: MemberReads::default(),
+      convertible_commonjs: None,
       ecma_view_meta: EcmaViewMeta::default(),
       import_attribute_map: FxHashMap::default(),
     };
@@ -271,11 +286,21 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       .all(|flag| flag.intersects(ScopeFlags::Top | ScopeFlags::StrictMode) || flag.is_block())
   }
 
+  /// Other formats than `esm` can only run TLA inside of the async function that
+  /// `experimental.topLevelAwaitWrapper` wraps the chunk with.
+  pub fn is_top_level_await_supported(&self) -> bool {
+    self.immutable_ctx.flat_options.keep_esm_import_export_syntax()
+      || self.immutable_ctx.options.experimental.is_top_level_await_wrapper_enabled()
+  }
+
   pub fn is_root_scope(&self) -> bool {
     self.scope_stack.iter().rev().all(|flag| flag.is_top())
   }
 
   pub fn scan(mut self, program: &Program<'ast>) -> BuildResult<ScanResult> {
+    if self.immutable_ctx.options.treeshake.members() {
+      self.collect_removable_members(program);
+    }
     self.visit_program(program);
     let mut exports_kind = ExportsKind::None;
 
@@ -347,6 +372,15 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
 
     self.result.exports_kind = exports_kind;
 
+    if self.immutable_ctx.options.experimental.is_commonjs_to_esm_enabled()
+      && exports_kind.is_commonjs()
+      && self.top_level_this_expr_set.is_empty()
+      && !self.result.ast_usage.contains(EcmaModuleAstUsage::TopLevelReturn)
+      && !self.result.ecma_view_meta.contains(EcmaViewMeta::Eval)
+    {
+      self.result.convertible_commonjs = self.analyze_convertible_commonjs(program);
+    }
+
     // If some commonjs module facade exports was used locally, we need to explicitly mark them as
     // has side effects, so that they should not be removed in linking stage.
     let mut bailout_inlined_cjs_exports_symbol_ids = FxHashSet::default();
@@ -937,6 +971,23 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       .push(MemberExprRef::new(object_ref, prop_and_span_list, span, obj_ref_type).into());
   }
 
+  /// `var Enum = function(Enum) { ... }(Enum || {})` emitted by the TypeScript
+  /// transform for an enum collected in [`ScanResult::ts_enum_values`].
+  pub fn is_constant_ts_enum_declaration(&self, stmt: &ast::Statement<'ast>) -> bool {
+    let decl = match stmt {
+      ast::Statement::VariableDeclaration(decl) => decl,
+      ast::Statement::ExportNamedDeclaration(export) => match &export.declaration {
+        Some(ast::Declaration::VariableDeclaration(decl)) => decl,
+        _ => return false,
+      },
+      _ => return false,
+    };
+    matches!(decl.declarations.as_slice(), [declarator] if declarator
+      .id
+      .get_binding_identifier()
+      .is_some_and(|id| self.result.ts_enum_values.contains_key(&id.symbol_id())))
+  }
+
   fn is_root_symbol(&self, symbol_id: SymbolId) -> bool {
     self.result.symbol_ref_db.scoping().root_scope_id()
       == self.result.symbol_ref_db.symbol_scope_id(symbol_id)
@@ -975,6 +1026,38 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   }
 
   /// StaticMemberExpression or ComputeMemberExpression with static key
+  /// The type of the reference to record for `X` in `new X()`, if `X` is a class whose unused
+  /// members could be removed by `treeshake.members`.
+  fn constructed_member_expr_type(
+    &self,
+    ident_ref: &IdentifierReference,
+    root_symbol_id: SymbolRef,
+  ) -> Option<MemberExprObjectReferencedType> {
+    if !self.immutable_ctx.options.treeshake.members() {
+      return None;
+    }
+    let Some(AstKind::NewExpression(new_expr)) = self.visit_path.last() else {
+      return None;
+    };
+    if new_expr.callee.without_parentheses().span() != ident_ref.span {
+      return None;
+    }
+    match self.result.named_imports.get(&root_symbol_id) {
+      Some(named_import) => match &named_import.imported {
+        Specifier::Star => None,
+        Specifier::Literal(name) if name.as_str() == "default" => {
+          Some(MemberExprObjectReferencedType::Default)
+        }
+        Specifier::Literal(_) => Some(MemberExprObjectReferencedType::Named),
+      },
+      None => self
+        .result
+        .removable_members
+        .contains_key(&root_symbol_id.symbol)
+        .then_some(MemberExprObjectReferencedType::Named),
+    }
+  }
+
   pub fn try_extract_parent_static_member_expr_chain(
     &self,
     max_len: usize,
@@ -0,0 +1,281 @@
+use std::borrow::Cow;
+
+use oxc::{
+  ast::ast::{
+    self, BindingPatternKind, ClassElement, Declaration, Expression, MethodDefinitionKind,
+    ObjectPropertyKind, PropertyKey, Statement, VariableDeclarationKind,
+  },
+  ast_visit::{Visit, walk},
+  span::CompactStr,
+  syntax::operator::BinaryOperator,
+};
+use rolldown_utils::concat_string;
+use rustc_hash::FxHashSet;
+
+use super::{AstScanner, side_effect_detector::SideEffectDetector};
+
+/// Instance methods are tracked by `treeshake.members` under their name with this prefix.
+pub const INSTANCE_METHOD_PREFIX: &str = "prototype.";
+
+/// Names of instance methods that could be called without being read in the bundle, by the
+/// language itself or by `console.log`, `JSON.stringify` and the like.
+pub const IMPLICITLY_CALLED_METHODS: [&str; 6] =
+  ["then", "toString", "valueOf", "toJSON", "toLocaleString", "handleEvent"];
+
+/// The name an object literal member is tracked under by `treeshake.members`.
+pub fn object_member_name<'a>(prop: &'a ast::ObjectProperty<'_>) -> Option<Cow<'a, str>> {
+  // `__proto__: value` sets the prototype rather than defining a property.
+  prop
+    .key
+    .static_name()
+    .filter(|name| name.as_ref() != "__proto__" && !name.starts_with(INSTANCE_METHOD_PREFIX))
+}
+
+/// The name a class member is tracked under by `treeshake.members`.
+///
+/// Instance fields are defined on every instance, so they are all tracked under `prototype` and only
+/// removed if the class is never instantiated. Instance methods are tracked one by one under
+/// [`INSTANCE_METHOD_PREFIX`], since they can only be reached by name.
+pub fn class_member_name<'a>(element: &'a ClassElement<'_>) -> Option<Cow<'a, str>> {
+  let (is_static, key) = match element {
+    ClassElement::MethodDefinition(method) if method.kind != MethodDefinitionKind::Constructor => {
+      (method.r#static, &method.key)
+    }
+    ClassElement::PropertyDefinition(prop) => (prop.r#static, &prop.key),
+    ClassElement::AccessorProperty(prop) => (prop.r#static, &prop.key),
+    _ => return None,
+  };
+  match key {
+    // Removing a private member would make the remaining references to it a syntax error.
+    PropertyKey::PrivateIdentifier(_) => None,
+    // Computed keys are evaluated when the class is defined, so keep the ones that aren't literals.
+    _ if is_static => key.static_name().filter(|name| !name.starts_with(INSTANCE_METHOD_PREFIX)),
+    _ if matches!(element, ClassElement::MethodDefinition(_)) => {
+      key.static_name().map(|name| Cow::Owned(concat_string!(INSTANCE_METHOD_PREFIX, name)))
+    }
+    _ => key.static_name().map(|_| Cow::Borrowed("prototype")),
+  }
+}
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Collects the members of top-level `const` object literals and class declarations that could be
+  /// removed if nothing reads them. Whether they are read is only known after linking, see
+  /// `LinkStage::compute_unused_members`.
+  pub fn collect_removable_members(&mut self, program: &ast::Program<'ast>) {
+    let mut collector = MemberReadCollector::default();
+    collector.visit_program(program);
+    self.result.member_reads.names = collector.names;
+    self.result.member_reads.has_computed_read = collector.has_computed_read;
+
+    for stmt in &program.body {
+      let declaration = match stmt {
+        Statement::ExportNamedDeclaration(decl) => decl.declaration.as_ref(),
+        _ => stmt.as_declaration(),
+      };
+      match declaration {
+        Some(Declaration::VariableDeclaration(decl))
+          if decl.kind == VariableDeclarationKind::Const =>
+        {
+          for declarator in &decl.declarations {
+            if let (
+              BindingPatternKind::BindingIdentifier(id),
+              Some(Expression::ObjectExpression(obj)),
+            ) = (&declarator.id.kind, &declarator.init)
+              && let Some(names) = self.removable_object_members(obj)
+            {
+              self.result.removable_members.insert(id.symbol_id(), names);
+            }
+          }
+        }
+        Some(Declaration::ClassDeclaration(class)) => {
+          if let Some(id) = &class.id
+            && let Some(names) = self.removable_class_members(class)
+          {
+            self.result.removable_members.insert(id.symbol_id(), names);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn removable_object_members(
+    &self,
+    obj: &ast::ObjectExpression<'ast>,
+  ) -> Option<FxHashSet<CompactStr>> {
+    // Methods called as `obj.method()` could pass `this` anywhere.
+    if ThisOrSuperFinder::contains(|finder| finder.visit_object_expression(obj)) {
+      return None;
+    }
+    let detector = self.side_effect_detector();
+    let members = obj.properties.iter().filter_map(|prop| {
+      let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+        return None;
+      };
+      let name = object_member_name(prop)?;
+      Some((name, !detector.detect_side_effect_of_expr(&prop.value).has_side_effect()))
+    });
+    removable_names(members)
+  }
+
+  fn removable_class_members(&self, class: &ast::Class<'ast>) -> Option<FxHashSet<CompactStr>> {
+    let has_decorators = !class.decorators.is_empty()
+      || class.body.body.iter().any(|element| match element {
+        ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+        ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+        ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+        _ => false,
+      });
+    // `this` in static members is the class itself.
+    if has_decorators
+      || ThisOrSuperFinder::contains(|finder| {
+        class
+          .body
+          .body
+          .iter()
+          .filter(|element| is_static(element))
+          .for_each(|element| finder.visit_class_element(element));
+      })
+    {
+      return None;
+    }
+    let detector = self.side_effect_detector();
+    let members = class.body.body.iter().filter_map(|element| {
+      let name = class_member_name(element)?;
+      let is_removable = !is_static(element)
+        || match element {
+          ClassElement::PropertyDefinition(prop) => prop.value.as_ref(),
+          ClassElement::AccessorProperty(prop) => prop.value.as_ref(),
+          _ => None,
+        }
+        .is_none_or(|value| !detector.detect_side_effect_of_expr(value).has_side_effect());
+      Some((name, is_removable))
+    });
+    removable_names(members)
+  }
+
+  fn side_effect_detector(&self) -> SideEffectDetector<'_> {
+    SideEffectDetector::new(
+      &self.result.symbol_ref_db.ast_scopes,
+      self.immutable_ctx.flat_options,
+      self.immutable_ctx.options,
+      None,
+    )
+  }
+}
+
+fn is_static(element: &ClassElement<'_>) -> bool {
+  match element {
+    ClassElement::StaticBlock(_) => true,
+    ClassElement::MethodDefinition(method) => method.r#static,
+    ClassElement::PropertyDefinition(prop) => prop.r#static,
+    ClassElement::AccessorProperty(prop) => prop.r#static,
+    ClassElement::TSIndexSignature(_) => false,
+  }
+}
+
+/// Names whose members can all be removed. `None` if there are none, so that the symbol isn't
+/// tracked at all.
+fn removable_names<'a>(
+  members: impl Iterator<Item = (Cow<'a, str>, bool)>,
+) -> Option<FxHashSet<CompactStr>> {
+  let mut removable = FxHashSet::default();
+  let mut kept = FxHashSet::default();
+  for (name, is_removable) in members {
+    if is_removable {
+      removable.insert(CompactStr::from(name.as_ref()));
+    } else {
+      kept.insert(CompactStr::from(name.as_ref()));
+    }
+  }
+  removable.retain(|name| !kept.contains(name));
+  (!removable.is_empty()).then_some(removable)
+}
+
+/// Collects the property names read in a module for [`MemberReads`]. Writes are counted too, which
+/// keeps setters.
+#[derive(Default)]
+struct MemberReadCollector {
+  names: FxHashSet<CompactStr>,
+  has_computed_read: bool,
+}
+
+impl MemberReadCollector {
+  fn add_key(&mut self, key: Option<Cow<'_, str>>) {
+    match key {
+      Some(name) => {
+        self.names.insert(name.as_ref().into());
+      }
+      None => self.has_computed_read = true,
+    }
+  }
+}
+
+impl<'a> Visit<'a> for MemberReadCollector {
+  fn visit_static_member_expression(&mut self, it: &ast::StaticMemberExpression<'a>) {
+    self.names.insert(it.property.name.as_str().into());
+    walk::walk_static_member_expression(self, it);
+  }
+
+  fn visit_computed_member_expression(&mut self, it: &ast::ComputedMemberExpression<'a>) {
+    self.add_key(it.static_property_name().map(|name| Cow::Borrowed(name.as_str())));
+    walk::walk_computed_member_expression(self, it);
+  }
+
+  fn visit_binding_property(&mut self, it: &ast::BindingProperty<'a>) {
+    self.add_key(it.key.static_name());
+    walk::walk_binding_property(self, it);
+  }
+
+  fn visit_assignment_target_property_identifier(
+    &mut self,
+    it: &ast::AssignmentTargetPropertyIdentifier<'a>,
+  ) {
+    self.names.insert(it.binding.name.as_str().into());
+    walk::walk_assignment_target_property_identifier(self, it);
+  }
+
+  fn visit_assignment_target_property_property(
+    &mut self,
+    it: &ast::AssignmentTargetPropertyProperty<'a>,
+  ) {
+    self.add_key(it.name.static_name());
+    walk::walk_assignment_target_property_property(self, it);
+  }
+
+  fn visit_binary_expression(&mut self, it: &ast::BinaryExpression<'a>) {
+    // `'name' in obj`
+    if it.operator == BinaryOperator::In {
+      match &it.left {
+        Expression::StringLiteral(lit) => {
+          self.names.insert(lit.value.as_str().into());
+        }
+        _ => self.has_computed_read = true,
+      }
+    }
+    walk::walk_binary_expression(self, it);
+  }
+}
+
+#[derive(Default)]
+struct ThisOrSuperFinder {
+  found: bool,
+}
+
+impl ThisOrSuperFinder {
+  fn contains(visit: impl FnOnce(&mut Self)) -> bool {
+    let mut finder = Self::default();
+    visit(&mut finder);
+    finder.found
+  }
+}
+
+impl Visit<'_> for ThisOrSuperFinder {
+  fn visit_this_expression(&mut self, _it: &ast::ThisExpression) {
+    self.found = true;
+  }
+
+  fn visit_super(&mut self, _it: &ast::Super) {
+    self.found = true;
+  }
+}
@@ -413,7 +413,7 @@ impl<'a> SideEffectDetector<'a> {
   }
 
   #[expect(clippy::too_many_lines)]
-  fn detect_side_effect_of_expr(&self, expr: &Expression) -> SideEffectDetail {
+  pub fn detect_side_effect_of_expr(&self, expr: &Expression) -> SideEffectDetail {
     match expr {
       Expression::BooleanLiteral(_)
       | Expression::NullLiteral(_)
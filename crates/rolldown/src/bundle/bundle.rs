@@ -5,7 +5,7 @@ use super::super::{
   module_loader::deferred_scan_data::defer_sync_scan_data,
   stages::{
     generate_stage::GenerateStage,
-    link_stage::LinkStage,
+    link_stage::{LinkStage, LinkStageOutput},
     scan_stage::{NormalizedScanStageOutput, ScanStage, ScanStageOutput},
   },
   types::{bundle_output::BundleOutput, scan_stage_cache::ScanStageCache},
@@ -13,7 +13,9 @@ use super::super::{
 };
 use anyhow::Context;
 use arcstr::ArcStr;
-use rolldown_common::{GetLocalDbMut, Module, ScanMode, SharedFileEmitter, SymbolRefDb};
+use rolldown_common::{
+  GetLocalDbMut, Module, NormalizedBundlerOptions, ScanMode, SharedFileEmitter, SymbolRefDb,
+};
 use rolldown_devtools::{action, trace_action, trace_action_enabled};
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
 use rolldown_fs::{FileSystem, OsFileSystem};
@@ -76,6 +78,92 @@ impl Bundle {
     self.append_plugin_timings_warning(result)
   }
 
+  #[tracing::instrument(level = "debug", skip_all, parent = &*self.bundle_span)]
+  /// Scans the modules once and generates an output for each of `outputs_options`.
+  ///
+  /// The outputs whose options link the modules the same way, see `is_link_compatible`, are
+  /// generated from copies of a single link output. Options that affect scanning, such as
+  /// `platform` or `define`, are always taken from `self.options`.
+  pub async fn generate_multiple(
+    mut self,
+    outputs_options: Vec<SharedOptions>,
+    is_write: bool,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    let start = self.plugin_driver.start_timing();
+    let result = async {
+      self.trace_action_session_meta();
+      trace_action!(action::BuildStart { action: "BuildStart" });
+      let scan_stage_output = self.scan_modules(ScanMode::Full).await?;
+
+      // Each output starts from the files emitted while building.
+      let file_emitter_snapshot = self.file_emitter.snapshot();
+
+      let groups = group_by_link_compatibility(&outputs_options);
+      let mut outputs = (0..outputs_options.len()).map(|_| None).collect::<Vec<_>>();
+      let mut pending_scan_stage_output = Some(scan_stage_output);
+      for (group_index, group) in groups.iter().enumerate() {
+        let scan_stage_output =
+          pending_scan_stage_output.take().expect("should have a scan stage output for each group");
+        // Linking mutates the module graph, so the next group links a copy.
+        let mut next_scan_stage_output =
+          (group_index + 1 < groups.len()).then(|| scan_stage_output.make_copy());
+
+        let link_start = self.plugin_driver.start_timing();
+        let mut link_stage_output =
+          LinkStage::new(scan_stage_output, &outputs_options[group[0]]).link();
+        self.plugin_driver.set_link_stage_time(link_start);
+
+        for (index, &output_index) in group.iter().enumerate() {
+          // Generating mutates the link output, so the next output of the group uses a copy.
+          let next_link_stage_output =
+            (index + 1 < group.len()).then(|| link_stage_output.make_copy());
+          let options = &outputs_options[output_index];
+          self.file_emitter.restore(&file_emitter_snapshot, Arc::clone(options)).await?;
+          if is_write {
+            self.clean_dir(options);
+          }
+          let mut output =
+            self.generate_with_options(&mut link_stage_output, options, is_write).await?;
+          if is_write {
+            self.write_output(&mut output, options).await?;
+          }
+          output.warnings.append(&mut self.warnings);
+          outputs[output_index] = Some(output);
+
+          // Copies don't have the scoping, which isn't changed by linking and generating.
+          let symbol_db = link_stage_output.symbol_db;
+          if let Some(mut next) = next_link_stage_output {
+            restore_scoping(&mut next.symbol_db, symbol_db);
+            link_stage_output = next;
+          } else {
+            if let Some(next) = &mut next_scan_stage_output {
+              restore_scoping(&mut next.symbol_ref_db, symbol_db);
+            }
+            break;
+          }
+        }
+        pending_scan_stage_output = next_scan_stage_output;
+      }
+      trace_action!(action::BuildEnd { action: "BuildEnd" });
+      Ok(
+        outputs
+          .into_iter()
+          .map(|output| output.expect("should generate each output"))
+          .collect::<Vec<_>>(),
+      )
+    }
+    .await;
+    self.plugin_driver.set_total_build_time(start);
+    result.map(|mut outputs| {
+      if let (Some(plugins), Some(output)) =
+        (self.plugin_driver.get_plugin_timings_info(), outputs.last_mut())
+      {
+        output.warnings.push(BuildDiagnostic::plugin_timings(plugins).with_severity_warning());
+      }
+      outputs
+    })
+  }
+
   #[tracing::instrument(level = "debug", skip_all, parent = &*self.bundle_span)]
   /// This method intentionally get the ownership of `self` to show that the method cannot be called multiple times.
   pub async fn scan(mut self) -> BuildResult<()> {
@@ -149,9 +237,21 @@ impl Bundle {
     &mut self,
     scan_stage_output: NormalizedScanStageOutput,
   ) -> BuildResult<BundleOutput> {
-    let dist_dir = self.options.cwd.join(&self.options.out_dir);
+    let options = Arc::clone(&self.options);
+    self.clean_dir(&options);
+
+    let mut output = self.bundle_up(scan_stage_output, /* is_write */ true).await?;
+
+    self.write_output(&mut output, &options).await?;
 
-    if self.options.clean_dir && self.options.dir.is_some() {
+    output.warnings.append(&mut self.warnings);
+
+    Ok(output)
+  }
+
+  fn clean_dir(&mut self, options: &SharedOptions) {
+    if options.clean_dir && options.dir.is_some() {
+      let dist_dir = options.cwd.join(&options.out_dir);
       if let Err(err) = clean_dir(&self.fs, &dist_dir) {
         self.warnings.push(
           BuildDiagnostic::could_not_clean_directory(
@@ -162,8 +262,14 @@ impl Bundle {
         );
       }
     }
+  }
 
-    let mut output = self.bundle_up(scan_stage_output, /* is_write */ true).await?;
+  async fn write_output(
+    &self,
+    output: &mut BundleOutput,
+    options: &SharedOptions,
+  ) -> BuildResult<()> {
+    let dist_dir = options.cwd.join(&options.out_dir);
 
     self.fs.create_dir_all(&dist_dir).with_context(|| {
       format!("Could not create directory for output chunks: {}", dist_dir.display())
@@ -184,14 +290,9 @@ impl Bundle {
         .with_context(|| format!("Failed to write file in {}", dest.display()))?;
     }
 
-    self
-      .plugin_driver
-      .write_bundle(&mut output.assets, &self.options, &mut output.warnings)
-      .await?;
-
-    output.warnings.append(&mut self.warnings);
+    self.plugin_driver.write_bundle(&mut output.assets, options, &mut output.warnings).await?;
 
-    Ok(output)
+    Ok(())
   }
 
   #[tracing::instrument(level = "debug", skip_all, parent = &*self.bundle_span)]
@@ -240,17 +341,28 @@ impl Bundle {
     let mut link_stage_output = LinkStage::new(scan_stage_output, &self.options).link();
     self.plugin_driver.set_link_stage_time(start);
 
+    let options = Arc::clone(&self.options);
+    let output = self.generate_with_options(&mut link_stage_output, &options, is_write).await?;
+
+    self.merge_immutable_fields_for_cache(link_stage_output.symbol_db);
+
+    Ok(output)
+  }
+
+  async fn generate_with_options(
+    &self,
+    link_stage_output: &mut LinkStageOutput,
+    options: &SharedOptions,
+    is_write: bool,
+  ) -> BuildResult<BundleOutput> {
     let bundle_output =
-      GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver)
+      GenerateStage::new(link_stage_output, options, &self.plugin_driver, &self.fs)
         .generate()
         .await; // Notice we don't use `?` to break the control flow here.
 
     if let Err(errors) = &bundle_output {
       debug_assert!(errors.iter().all(|e| e.severity() == Severity::Error));
-      self
-        .plugin_driver
-        .render_error(&HookRenderErrorArgs { errors, cwd: &self.options.cwd })
-        .await?;
+      self.plugin_driver.render_error(&HookRenderErrorArgs { errors, cwd: &options.cwd }).await?;
     }
 
     let mut output = bundle_output?;
@@ -260,15 +372,13 @@ impl Bundle {
 
     self
       .plugin_driver
-      .generate_bundle(&mut output.assets, is_write, &self.options, &mut output.warnings)
+      .generate_bundle(&mut output.assets, is_write, options, &mut output.warnings)
       .await?;
 
-    if let Some(invalidate_js_side_cache) = &self.options.invalidate_js_side_cache {
+    if let Some(invalidate_js_side_cache) = &options.invalidate_js_side_cache {
       invalidate_js_side_cache.call().await?;
     }
 
-    self.merge_immutable_fields_for_cache(link_stage_output.symbol_db);
-
     Ok(output)
   }
 
@@ -277,14 +387,7 @@ impl Bundle {
       return;
     }
     let snapshot = self.cache.get_snapshot_mut();
-    for (idx, symbol_ref_db) in symbol_db.into_inner().into_iter_enumerated() {
-      let Some(db_for_module) = symbol_ref_db else {
-        continue;
-      };
-      let cache_db = snapshot.symbol_ref_db.local_db_mut(idx);
-      let (scoping, _) = db_for_module.ast_scopes.into_inner();
-      cache_db.ast_scopes.set_scoping(scoping);
-    }
+    restore_scoping(&mut snapshot.symbol_ref_db, symbol_db);
   }
 
   fn trace_action_module_graph_ready(scan_stage_output: &NormalizedScanStageOutput) {
@@ -364,3 +467,39 @@ impl Bundle {
     })
   }
 }
+
+/// Groups the indexes of `outputs_options` whose outputs can be generated from the same link
+/// output.
+fn group_by_link_compatibility(outputs_options: &[SharedOptions]) -> Vec<Vec<usize>> {
+  let mut groups: Vec<Vec<usize>> = vec![];
+  for (index, options) in outputs_options.iter().enumerate() {
+    match groups.iter_mut().find(|group| is_link_compatible(&outputs_options[group[0]], options)) {
+      Some(group) => group.push(index),
+      None => groups.push(vec![index]),
+    }
+  }
+  groups
+}
+
+/// Whether the options of two outputs link the modules the same way. Among the output options,
+/// linking depends on whether the format is ESM, like for wrapping CJS entries or binding imports
+/// from externals, and on the few options below.
+fn is_link_compatible(a: &NormalizedBundlerOptions, b: &NormalizedBundlerOptions) -> bool {
+  a.format.is_esm() == b.format.is_esm()
+    && a.inline_dynamic_imports == b.inline_dynamic_imports
+    && a.generated_code == b.generated_code
+    && a.polyfill_require == b.polyfill_require
+}
+
+/// Moves the scoping of `symbol_db` back into `target`, which is a copy made by
+/// `NormalizedScanStageOutput::make_copy` or `LinkStageOutput::make_copy`.
+fn restore_scoping(target: &mut SymbolRefDb, symbol_db: SymbolRefDb) {
+  for (idx, symbol_ref_db) in symbol_db.into_inner().into_iter_enumerated() {
+    let Some(db_for_module) = symbol_ref_db else {
+      continue;
+    };
+    let target_db = target.local_db_mut(idx);
+    let (scoping, _) = db_for_module.ast_scopes.into_inner();
+    target_db.ast_scopes.set_scoping(scoping);
+  }
+}
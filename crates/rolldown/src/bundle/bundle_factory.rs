@@ -1,8 +1,8 @@
 use std::{any::Any, sync::Arc};
 
 use rolldown_common::{
-  BundleMode, BundlerOptions, FileEmitter, NormalizedBundlerOptions, SharedFileEmitter,
-  SharedModuleInfoDashMap,
+  BundleMode, BundlerOptions, FileEmitter, NormalizedBundlerOptions, OutputOptions,
+  SharedFileEmitter, SharedModuleInfoDashMap,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult, EventKindSwitcher};
 use rolldown_fs::OsFileSystem;
@@ -14,7 +14,7 @@ use crate::{
   types::scan_stage_cache::ScanStageCache,
   utils::{
     apply_inner_plugins::apply_inner_plugins,
-    prepare_build_context::{PrepareBuildContext, prepare_build_context},
+    prepare_build_context::{PrepareBuildContext, normalize_output_options, prepare_build_context},
   },
 };
 
@@ -41,6 +41,9 @@ pub struct BundleFactory {
   pub(crate) _log_guard: Option<Box<dyn Any + Send>>,
   pub last_bundle_handle: Option<BundleHandle>,
 
+  // Used to derive the options of additional outputs that share a single scan
+  raw_options: BundlerOptions,
+
   // Used to share module info across multiple plugin drivers for incremental builds
   module_infos_for_incremental_build: SharedModuleInfoDashMap,
 
@@ -55,12 +58,13 @@ impl BundleFactory {
     let maybe_guard =
       if opts.disable_tracing_setup { None } else { rolldown_tracing::try_init_tracing() };
 
+    let raw_options = opts.bundler_options.clone();
     let PrepareBuildContext { fs, resolver, options, mut warnings } =
       prepare_build_context(opts.bundler_options)?;
 
     Self::check_prefer_builtin_feature(opts.plugins.as_slice(), &options, &mut warnings);
 
-    apply_inner_plugins(&options, &mut opts.plugins);
+    apply_inner_plugins(&options, &fs, &mut opts.plugins);
 
     let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
 
@@ -77,10 +81,20 @@ impl BundleFactory {
       session,
       bundle_id_seed: 0,
       last_bundle_handle: None,
+      raw_options,
       module_infos_for_incremental_build: Arc::default(),
     })
   }
 
+  /// Normalizes the options of an additional output by overriding the output-related fields of
+  /// the options this factory was created with.
+  pub fn normalize_output_options(&mut self, output: OutputOptions) -> BuildResult<SharedOptions> {
+    let (options, mut warnings) =
+      normalize_output_options(&self.options, &self.raw_options, output)?;
+    self.warnings.append(&mut warnings);
+    Ok(Arc::new(options))
+  }
+
   fn generate_unique_bundle_span(&mut self) -> Arc<tracing::Span> {
     let bundle_id = rolldown_devtools::generate_build_id(self.bundle_id_seed);
     self.bundle_id_seed += 1;
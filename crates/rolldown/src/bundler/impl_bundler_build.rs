@@ -1,6 +1,6 @@
 use crate::types::bundle_output::BundleOutput;
 use anyhow::Result;
-use rolldown_common::{BundleMode, ScanMode};
+use rolldown_common::{BundleMode, OutputOptions, ScanMode};
 use rolldown_error::BuildResult;
 
 use super::bundler::Bundler;
@@ -29,6 +29,45 @@ impl Bundler {
     }
   }
 
+  /// Scans the modules once and writes an output for each of `outputs`.
+  #[tracing::instrument(level = "debug", skip_all, parent = &self.session.span)]
+  pub async fn write_outputs(
+    &mut self,
+    outputs: Vec<OutputOptions>,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    self.generate_multiple(outputs, /* is_write */ true).await
+  }
+
+  /// Scans the modules once and generates an output for each of `outputs`.
+  #[tracing::instrument(level = "debug", skip_all, parent = &self.session.span)]
+  pub async fn generate_outputs(
+    &mut self,
+    outputs: Vec<OutputOptions>,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    self.generate_multiple(outputs, /* is_write */ false).await
+  }
+
+  async fn generate_multiple(
+    &mut self,
+    outputs: Vec<OutputOptions>,
+    is_write: bool,
+  ) -> BuildResult<Vec<BundleOutput>> {
+    self.create_error_if_closed()?;
+    // Incremental builds keep the scanned modules in a cache, and lend their scoping to the link
+    // output of the build, which hands it back to the cache once the output is generated. With
+    // multiple outputs, the scoping is handed from one output to the next instead, and rebuilds
+    // only regenerate the output of `self.options`, so the cache would not match the outputs.
+    if self.options.experimental.is_incremental_build_enabled() {
+      Err(anyhow::anyhow!("Generating multiple outputs is not supported with incremental build"))?;
+    }
+    let outputs_options = outputs
+      .into_iter()
+      .map(|output| self.bundle_factory.normalize_output_options(output))
+      .collect::<BuildResult<Vec<_>>>()?;
+    let bundle = self.bundle_factory.create_bundle(BundleMode::FullBuild, None)?;
+    bundle.generate_multiple(outputs_options, is_write).await
+  }
+
   #[tracing::instrument(target = "devtool", level = "debug", skip_all)]
   #[cfg(feature = "experimental")]
   pub async fn scan(&mut self) -> BuildResult<()> {
@@ -8,7 +8,7 @@ use crate::{
 use anyhow::Result;
 use rolldown_common::{
   AddonRenderContext, EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx,
-  OutputFormat, RenderedModule,
+  NormalModule, OutputFormat, RenderedModule,
 };
 use rolldown_error::BuildResult;
 use rolldown_plugin::HookAddonArgs;
@@ -18,7 +18,9 @@ use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 
-use super::format::{cjs::render_cjs, esm::render_esm, iife::render_iife, umd::render_umd};
+use super::format::{
+  amd::render_amd, cjs::render_cjs, esm::render_esm, iife::render_iife, umd::render_umd,
+};
 
 pub type RenderedModuleSources = Vec<RenderedModuleSource>;
 
@@ -114,6 +116,20 @@ impl Generator for EcmaGenerator {
           .map(|range| &normal_module.source[range.start as usize..range.end as usize])
           .collect::<_>()
       })
+      .or_else(|| {
+        // Code splitting only puts modules with the same directives into a chunk, so the
+        // directives of any of them are the directives of the chunk.
+        ctx.options.experimental.is_preserve_directives_enabled().then(|| {
+          ctx
+            .chunk
+            .modules
+            .iter()
+            .filter(|idx| **idx != ctx.link_output.runtime.id())
+            .find_map(|idx| ctx.link_output.module_table[*idx].as_normal())
+            .map(NormalModule::custom_directives)
+            .unwrap_or_default()
+        })
+      })
       .unwrap_or_default();
 
     let banner = {
@@ -188,6 +204,9 @@ impl Generator for EcmaGenerator {
           Err(errors) => return Ok(Err(errors)),
         }
       }
+      OutputFormat::Amd => {
+        render_amd(ctx, addon_render_context, &rendered_module_sources, &mut warnings)
+      }
     };
 
     ctx.warnings.extend(warnings);
@@ -26,7 +26,7 @@ pub async fn create_ecma_view(
   args: CreateModuleViewArgs,
 ) -> BuildResult<CreateEcmaViewReturn> {
   let CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects } = args;
-  let ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings } =
+  let ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings, ts_enum_values } =
     parse_to_ecma_ast(ctx, source).await?;
 
   ctx.warnings.extend(warnings);
@@ -47,6 +47,7 @@ pub async fn create_ecma_view(
     ctx.options,
     ast.allocator(),
     ctx.flat_options,
+    ts_enum_values,
   );
 
   let ScanResult {
@@ -74,6 +75,10 @@ pub async fn create_ecma_view(
     directive_range,
     dummy_record_set,
     constant_export_map,
+    ts_enum_values,
+    removable_members,
+    member_reads,
+    convertible_commonjs,
     import_attribute_map,
   } = scanner.scan(ast.program())?;
   // If a export symbol in commonjs defined in multiple time, we just bailout treeshake it.
@@ -138,6 +143,10 @@ pub async fn create_ecma_view(
     directive_range,
     dummy_record_set,
     constant_export_map,
+    ts_enum_values,
+    removable_members,
+    member_reads,
+    convertible_commonjs,
     depended_runtime_helper: Box::default(),
     import_attribute_map,
     json_module_none_self_reference_included_symbol: None,
@@ -0,0 +1,190 @@
+//! This is the render function for AMD format.
+//! It wraps the chunk content in a `define` call, e.g.
+//!
+//! ```js
+//! define(['exports', 'external', './chunk'], function(exports, external, require_chunk) {
+//!   // chunk content
+//! });
+//! ```
+//!
+//! Unlike IIFE and UMD, AMD supports code splitting. Imports from other chunks are passed to the
+//! factory as dependencies, and `import()` of other chunks is rendered as `require([...])` of the
+//! loader's local `require`.
+
+use crate::utils::chunk::namespace_marker::render_namespace_markers;
+use crate::utils::chunk::render_chunk_exports::{
+  get_chunk_export_names_with_ctx, render_chunk_exports, render_wrapped_entry_chunk,
+};
+use crate::{ecmascript::ecma_generator::RenderedModuleSources, types::generator::GenerateContext};
+use rolldown_common::{AddonRenderContext, ChunkIdx, ExternalModule, OutputExports};
+use rolldown_error::BuildDiagnostic;
+use rolldown_sourcemap::SourceJoiner;
+use rolldown_utils::concat_string;
+
+use super::utils::{
+  is_async_wrapped_chunk, render_chunk_directives, render_chunk_external_imports,
+  render_modules_with_peek_runtime_module_at_first,
+};
+
+#[expect(clippy::needless_pass_by_value)]
+pub fn render_amd<'code>(
+  ctx: &GenerateContext<'_>,
+  addon_render_context: AddonRenderContext<'code>,
+  module_sources: &'code RenderedModuleSources,
+  _warnings: &mut Vec<BuildDiagnostic>,
+) -> SourceJoiner<'code> {
+  let mut source_joiner = SourceJoiner::default();
+  let AddonRenderContext { hashbang, banner, intro, outro, footer, directives } =
+    addon_render_context;
+  if let Some(hashbang) = hashbang {
+    source_joiner.append_source(hashbang);
+  }
+
+  if let Some(banner) = banner {
+    source_joiner.append_source(banner);
+  }
+
+  if !directives.is_empty() {
+    let rendered_chunk_directives = render_chunk_directives(directives.iter());
+    if !rendered_chunk_directives.is_empty() {
+      source_joiner.append_source(rendered_chunk_directives);
+    }
+  }
+
+  // amd wrapper start
+
+  // Use pre-computed output_exports from the chunk
+  let export_mode = ctx.chunk.output_exports;
+
+  let export_names = get_chunk_export_names_with_ctx(ctx);
+  let has_exports = !export_names.is_empty();
+  let has_default_export = export_names.iter().any(|name| name.as_str() == "default");
+
+  let named_exports = matches!(&export_mode, OutputExports::Named);
+
+  let (import_code, externals) = render_chunk_external_imports(ctx);
+
+  let dependencies = render_amd_dependencies(ctx, &externals, has_exports && named_exports);
+  let factory_parameters =
+    render_amd_factory_parameters(ctx, &externals, has_exports && named_exports);
+  // `experimental.topLevelAwaitWrapper` makes the factory itself async.
+  let factory_keyword =
+    if is_async_wrapped_chunk(ctx, ctx.chunk) { "async function(" } else { "function(" };
+  let id = ctx.options.amd.id.as_ref().map(|id| concat_string!("'", id, "', ")).unwrap_or_default();
+  source_joiner.append_source(concat_string!(
+    ctx.options.amd.define_function_name(),
+    "(",
+    id,
+    if dependencies.is_empty() { String::new() } else { concat_string!("[", dependencies, "], ") },
+    factory_keyword,
+    factory_parameters,
+    ") {\n"
+  ));
+
+  if let Some(intro) = intro {
+    source_joiner.append_source(intro);
+  }
+
+  if let (Some(entry_module), OutputExports::Named) =
+    (ctx.chunk.user_defined_entry_module(&ctx.link_output.module_table), export_mode)
+  {
+    // Symbol.toStringTag should only be added to module facades (chunks that represent a specific module)
+    if entry_module.exports_kind.is_esm()
+      && let Some(marker) = render_namespace_markers(
+        ctx.options.es_module,
+        has_default_export,
+        &ctx.options.generated_code,
+        ctx.chunk.is_entry_point(),
+      )
+    {
+      source_joiner.append_source(marker);
+    }
+  }
+
+  render_modules_with_peek_runtime_module_at_first(
+    ctx,
+    &mut source_joiner,
+    module_sources,
+    import_code,
+  );
+
+  if let Some(source) = render_wrapped_entry_chunk(ctx, Some(&export_mode)) {
+    source_joiner.append_source(source);
+  }
+
+  if let Some(exports) = render_chunk_exports(ctx, Some(&export_mode)) {
+    source_joiner.append_source(exports);
+  }
+
+  if let Some(outro) = outro {
+    source_joiner.append_source(outro);
+  }
+
+  // amd wrapper end
+  source_joiner.append_source("});");
+
+  if let Some(footer) = footer {
+    source_joiner.append_source(footer);
+  }
+
+  source_joiner
+}
+
+/// Renders the dependency list of `define([...], factory)`. It's shared with the AMD branch of the
+/// UMD format, which never has imports from other chunks.
+///
+/// Dependencies that are passed to the factory come first, in the same order as
+/// [`render_amd_factory_parameters`]. Chunks only imported for their side effects come last.
+pub fn render_amd_dependencies(
+  ctx: &GenerateContext<'_>,
+  externals: &[&ExternalModule],
+  has_exports: bool,
+) -> String {
+  let mut dependencies = Vec::with_capacity(externals.len());
+  if has_exports {
+    dependencies.reserve(1);
+    dependencies.push("'exports'".to_string());
+  }
+  externals.iter().for_each(|external| {
+    dependencies.push(concat_string!(
+      "'",
+      external.get_import_path(ctx.chunk, ctx.options.paths.as_ref()),
+      "'"
+    ));
+  });
+  let render_chunk_dependency = |exporter_idx: &ChunkIdx| {
+    let importee_chunk = &ctx.chunk_graph.chunk_table[*exporter_idx];
+    concat_string!("'", ctx.chunk.amd_import_path_for(importee_chunk), "'")
+  };
+  let (chunks_with_bindings, side_effect_only_chunks): (Vec<_>, Vec<_>) =
+    ctx.chunk.imports_from_other_chunks.iter().partition(|(_, items)| !items.is_empty());
+  dependencies
+    .extend(chunks_with_bindings.into_iter().map(|(idx, _)| render_chunk_dependency(idx)));
+  if !ctx.chunk.cross_chunk_dynamic_imports.is_empty() {
+    dependencies.push("'require'".to_string());
+  }
+  dependencies
+    .extend(side_effect_only_chunks.into_iter().map(|(idx, _)| render_chunk_dependency(idx)));
+  dependencies.join(", ")
+}
+
+fn render_amd_factory_parameters(
+  ctx: &GenerateContext<'_>,
+  externals: &[&ExternalModule],
+  has_exports: bool,
+) -> String {
+  let mut parameters = if has_exports { vec!["exports"] } else { vec![] };
+  externals.iter().for_each(|external| {
+    parameters.push(ctx.chunk.canonical_names[&external.namespace_ref].as_str());
+  });
+  ctx.chunk.imports_from_other_chunks.iter().filter(|(_, items)| !items.is_empty()).for_each(
+    |(exporter_idx, _)| {
+      parameters.push(&ctx.chunk.require_binding_names_for_other_chunks[exporter_idx]);
+    },
+  );
+  if !ctx.chunk.cross_chunk_dynamic_imports.is_empty() {
+    // `import()` of other chunks is rendered as `require([...])` of the loader's local `require`.
+    parameters.push("require");
+  }
+  parameters.join(", ")
+}
@@ -12,7 +12,9 @@ use rolldown_error::BuildDiagnostic;
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::concat_string;
 
-use super::utils::{render_chunk_directives, render_modules_with_peek_runtime_module_at_first};
+use super::utils::{
+  is_async_wrapped_chunk, render_chunk_directives, render_modules_with_peek_runtime_module_at_first,
+};
 
 #[expect(clippy::needless_pass_by_value)]
 pub fn render_cjs<'code>(
@@ -39,6 +41,13 @@ pub fn render_cjs<'code>(
     }
   }
 
+  let is_async_wrapped = is_async_wrapped_chunk(ctx, ctx.chunk);
+  if is_async_wrapped {
+    // `module.exports` is replaced by a promise of the exports, so the chunk works on its own
+    // `module` and `exports`.
+    source_joiner.append_source("module.exports = (async (module, exports = module.exports) => {");
+  }
+
   if let Some(intro) = intro {
     source_joiner.append_source(intro);
   }
@@ -88,6 +97,10 @@ pub fn render_cjs<'code>(
     source_joiner.append_source(outro);
   }
 
+  if is_async_wrapped {
+    source_joiner.append_source("return module.exports;\n})({ exports: {} });");
+  }
+
   if let Some(footer) = footer {
     source_joiner.append_source(footer);
   }
@@ -102,8 +115,13 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
   // render imports from other chunks
   ctx.chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
     let importee_chunk = &ctx.chunk_graph.chunk_table[*exporter_id];
-    let require_path_str =
-      concat_string!("require('", ctx.chunk.import_path_for(importee_chunk), "');\n");
+    let require_path_str = concat_string!(
+      // Chunks wrapped for TLA export a promise.
+      if is_async_wrapped_chunk(ctx, importee_chunk) { "await " } else { "" },
+      "require('",
+      ctx.chunk.import_path_for(importee_chunk),
+      "');\n"
+    );
     if items.is_empty() {
       s.push_str(&require_path_str);
     } else {
@@ -37,8 +37,8 @@ use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::concat_string;
 
 use super::utils::{
-  render_chunk_directives, render_chunk_external_imports, render_factory_parameters,
-  render_modules_with_peek_runtime_module_at_first,
+  is_async_wrapped_chunk, render_chunk_directives, render_chunk_external_imports,
+  render_factory_parameters, render_modules_with_peek_runtime_module_at_first,
 };
 
 /// The main function for rendering the IIFE format chunks.
@@ -118,7 +118,8 @@ pub async fn render_iife<'code>(
     } else {
       concat_string!(assignment, " = ")
     },
-    "(function(",
+    // With TLA, the result is a promise of the exports.
+    if is_async_wrapped_chunk(ctx, ctx.chunk) { "(async function(" } else { "(function(" },
     factory_parameters,
     ") {\n"
   ));
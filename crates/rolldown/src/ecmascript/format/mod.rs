@@ -1,3 +1,4 @@
+pub mod amd;
 pub mod cjs;
 pub mod esm;
 pub mod iife;
@@ -16,9 +16,11 @@ use crate::{
   },
 };
 
+use super::amd::render_amd_dependencies;
 use super::utils::{
-  namespace::render_property_access, render_chunk_directives, render_chunk_external_imports,
-  render_factory_parameters, render_modules_with_peek_runtime_module_at_first,
+  is_async_wrapped_chunk, namespace::render_property_access, render_chunk_directives,
+  render_chunk_external_imports, render_factory_parameters,
+  render_modules_with_peek_runtime_module_at_first,
 };
 
 pub async fn render_umd<'code>(
@@ -65,6 +67,9 @@ pub async fn render_umd<'code>(
   let amd_dependencies = render_amd_dependencies(ctx, &externals, has_exports && named_exports);
   let global_argument = if need_global { "this, " } else { "" };
   let factory_parameters = render_factory_parameters(ctx, &externals, has_exports && named_exports);
+  // With TLA, the factory returns a promise, which resolves once the exports are ready.
+  let factory_keyword =
+    if is_async_wrapped_chunk(ctx, ctx.chunk) { "async function" } else { "function" };
   let cjs_intro = if need_global {
     let cjs_export = if has_exports && !named_exports { "module.exports = " } else { "" };
     let cjs_dependencies = render_cjs_dependencies(ctx, &externals, has_exports && named_exports);
@@ -87,7 +92,7 @@ pub async fn render_umd<'code>(
   {cjs_intro}
   typeof define === 'function' && define.amd ? define([{amd_dependencies}], factory) :
   {iife_start}{iife_export}{iife_end};
-}})({global_argument}function({factory_parameters}) {{",
+}})({global_argument}{factory_keyword}({factory_parameters}) {{",
   ));
 
   if let Some(intro) = intro {
@@ -136,26 +141,6 @@ pub async fn render_umd<'code>(
   Ok(source_joiner)
 }
 
-fn render_amd_dependencies(
-  ctx: &GenerateContext<'_>,
-  externals: &[&ExternalModule],
-  has_exports: bool,
-) -> String {
-  let mut dependencies = Vec::with_capacity(externals.len());
-  if has_exports {
-    dependencies.reserve(1);
-    dependencies.push("'exports'".to_string());
-  }
-  externals.iter().for_each(|external| {
-    dependencies.push(concat_string!(
-      "'",
-      external.get_import_path(ctx.chunk, ctx.options.paths.as_ref()),
-      "'"
-    ));
-  });
-  dependencies.join(", ")
-}
-
 fn render_cjs_dependencies(
   ctx: &GenerateContext<'_>,
   externals: &[&ExternalModule],
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use rolldown_common::ExternalModule;
+use rolldown_common::{Chunk, ExternalModule};
 use rolldown_sourcemap::SourceJoiner;
 
 use crate::{
@@ -10,6 +10,14 @@ use crate::{
 
 pub mod namespace;
 
+/// Whether `experimental.topLevelAwaitWrapper` wraps the chunk in an async function, because some
+/// of its modules use TLA or wait for modules that do.
+pub fn is_async_wrapped_chunk(ctx: &GenerateContext<'_>, chunk: &Chunk) -> bool {
+  ctx.options.experimental.is_top_level_await_wrapper_enabled()
+    && !ctx.options.format.keep_esm_import_export_syntax()
+    && chunk.modules.iter().any(|idx| ctx.link_output.metas[*idx].is_tla_or_contains_tla_dependency)
+}
+
 pub fn render_factory_parameters(
   ctx: &GenerateContext<'_>,
   externals: &[&ExternalModule],
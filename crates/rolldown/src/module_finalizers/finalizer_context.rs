@@ -1,7 +1,7 @@
 use rolldown_common::{
-  AstScopes, Chunk, ChunkIdx, ConstExportMeta, ImportRecordIdx, IndexModules, ModuleIdx,
-  ModuleType, NormalModule, RenderedConcatenatedModuleParts, RuntimeModuleBrief, SharedFileEmitter,
-  SymbolRef, SymbolRefDb,
+  AstScopes, Chunk, ChunkIdx, ConstExportMeta, EnumMemberValues, ImportRecordIdx, IndexModules,
+  ModuleIdx, ModuleType, NormalModule, RenderedConcatenatedModuleParts, RuntimeModuleBrief,
+  SharedFileEmitter, SymbolRef, SymbolRefDb,
 };
 
 pub type FinalizerMutableFields = (
@@ -45,6 +45,7 @@ pub struct ScopeHoistingFinalizerContext<'me> {
   pub options: &'me SharedOptions,
   pub file_emitter: &'me SharedFileEmitter,
   pub constant_value_map: &'me FxHashMap<SymbolRef, ConstExportMeta>,
+  pub ts_enum_values: &'me FxHashMap<SymbolRef, EnumMemberValues>,
   pub side_effect_free_function_symbols: &'me FxHashSet<SymbolRef>,
 }
 
@@ -79,6 +80,7 @@ impl<'me> ScopeHoistingFinalizerContext<'me> {
         rendered_concatenated_wrapped_module_parts: mutable_state
           .rendered_concatenated_wrapped_module_parts,
         json_module_inlined_prop: need_inline_json_prop.then(|| Box::new(FxHashMap::default())),
+        concurrent_init_calls: vec![],
       };
       finalizer.visit_program(oxc_program);
       (finalizer.transferred_import_record, finalizer.rendered_concatenated_wrapped_module_parts)
@@ -94,6 +94,7 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
     // init namespace_alias_symbol_id
 
     let last_import_stmt_idx = self.remove_unused_top_level_stmt(program);
+    self.insert_concurrent_init_calls(program);
 
     if self.ctx.options.is_hmr_enabled() {
       let hmr_header = if self.ctx.runtime.id() == self.ctx.module.idx {
@@ -441,18 +442,16 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
         }
       }
       ast::Expression::ChainExpression(chain_expr) => {
-        if let Some(new_expr) = chain_expr
-          .expression
-          .as_member_expression_mut()
-          .and_then(|expr| self.try_rewrite_member_expr(expr))
-        {
+        if let Some(new_expr) = chain_expr.expression.as_member_expression_mut().and_then(|expr| {
+          self.try_inline_ts_enum_member(expr).or_else(|| self.try_rewrite_member_expr(expr))
+        }) {
           *expr = new_expr;
         }
       }
       _ => {
-        if let Some(new_expr) =
-          expr.as_member_expression().and_then(|expr| self.try_rewrite_member_expr(expr))
-        {
+        if let Some(new_expr) = expr.as_member_expression().and_then(|expr| {
+          self.try_inline_ts_enum_member(expr).or_else(|| self.try_rewrite_member_expr(expr))
+        }) {
           *expr = new_expr;
         }
       }
@@ -627,6 +626,7 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
   }
 
   fn visit_declaration(&mut self, it: &mut ast::Declaration<'ast>) {
+    self.remove_unused_members(it);
     // keep_name transformation
     match it {
       ast::Declaration::VariableDeclaration(decl) => {
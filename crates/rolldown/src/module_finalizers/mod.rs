@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use bitflags::bitflags;
 use oxc::ast::ast::ObjectPropertyKind;
 use oxc::semantic::{ScopeFlags, SymbolId};
@@ -13,10 +15,10 @@ use oxc::{
   span::{Atom, GetSpan, GetSpanMut, SPAN},
 };
 use rolldown_common::{
-  AstScopes, ConcatenateWrappedModuleKind, ExportsKind, ImportRecordIdx, ImportRecordMeta,
-  InlineConstMode, MemberExprRefResolution, Module, ModuleIdx, ModuleNamespaceIncludedReason,
-  ModuleType, NamespaceAlias, OutputExports, OutputFormat, Platform,
-  RenderedConcatenatedModuleParts, Specifier, SymbolRef, WrapKind,
+  AstScopes, ChunkIdx, ConcatenateWrappedModuleKind, ConstExportMeta, EcmaViewMeta, ExportsKind,
+  ImportRecordIdx, ImportRecordMeta, InlineConstMode, MemberExprRefResolution, Module, ModuleIdx,
+  ModuleNamespaceIncludedReason, ModuleType, NamespaceAlias, OutputExports, OutputFormat, Platform,
+  RenderedConcatenatedModuleParts, RuntimeHelper, Specifier, StmtInfoIdx, SymbolRef, WrapKind,
 };
 use rolldown_ecmascript::ToSourceString;
 use rolldown_ecmascript_utils::{
@@ -32,6 +34,7 @@ use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
 use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
+use crate::ast_scanner::removable_members::{class_member_name, object_member_name};
 use crate::hmr::utils::HmrAstBuilder;
 use crate::utils::external_import_interop::import_record_needs_interop;
 
@@ -76,6 +79,11 @@ pub struct ScopeHoistingFinalizer<'me, 'ast: 'me> {
   pub transferred_import_record: FxIndexMap<ImportRecordIdx, String>,
   pub rendered_concatenated_wrapped_module_parts: RenderedConcatenatedModuleParts,
   pub json_module_inlined_prop: Option<Box<FxHashMap<SymbolId, ast::Expression<'ast>>>>,
+  /// With `experimental.concurrentTopLevelAwait`, the `init_foo()` calls of the imports, which
+  /// are awaited together by `await Promise.all([...])` in place of the first import of each
+  /// batch. A `require_foo()` import ends the batch, so it still runs after the imports before it
+  /// and before the imports after it.
+  pub concurrent_init_calls: Vec<Vec<ast::Expression<'ast>>>,
 }
 
 impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
@@ -150,6 +158,10 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
         // Replace the statement with something like `var import_foo = __toESM(require_foo())`
         // or `var import_foo = require_foo()` if only named imports are used
 
+        if self.concurrent_init_calls.last().is_some_and(|calls| !calls.is_empty()) {
+          self.concurrent_init_calls.push(vec![]);
+        }
+
         // `require_foo`
         let (importee_wrapper_ref_name, hint) = self.finalized_expr_for_symbol_ref(
           importee_linking_info.wrapper_ref.unwrap(),
@@ -224,7 +236,27 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
             false,
           ));
 
-        if self.ctx.linking_info.is_tla_or_contains_tla_dependency {
+        if self.ctx.linking_info.is_tla_or_contains_tla_dependency
+          && self.ctx.options.experimental.is_concurrent_top_level_await_enabled()
+          && !self.transferred_import_record.contains_key(&rec_id)
+        {
+          // Imports are started in order, and awaited together. `init_foo()` of a module without
+          // TLA runs it synchronously, just like the spec does.
+          if self.concurrent_init_calls.is_empty() {
+            self.concurrent_init_calls.push(vec![]);
+          }
+          let calls = self.concurrent_init_calls.last_mut().unwrap();
+          let is_first = calls.is_empty();
+          calls.push(init_call);
+          if is_first {
+            // A placeholder for `await Promise.all([...])`, see `insert_concurrent_init_calls`.
+            *stmt = self.snippet.builder.statement_expression(
+              stmt.span(),
+              self.snippet.builder.expression_null_literal(SPAN),
+            );
+          }
+          return !is_first;
+        } else if self.ctx.linking_info.is_tla_or_contains_tla_dependency {
           // `await init_foo()`
           *stmt = self.snippet.builder.statement_expression(
             SPAN,
@@ -289,7 +321,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       self.snippet.id_ref_expr(self.canonical_name_for(canonical_ref), SPAN)
     } else {
       match self.ctx.options.format {
-        rolldown_common::OutputFormat::Cjs => {
+        rolldown_common::OutputFormat::Cjs | rolldown_common::OutputFormat::Amd => {
           let chunk_idx_of_canonical_symbol =
             canonical_symbol.chunk_id.unwrap_or_else(|| {
               // Scoped symbols don't get assigned a `ChunkId`. There are skipped for performance reason, because they are surely
@@ -533,7 +565,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           });
           re_export_external_stmts = Some(stmts.collect::<Vec<_>>());
         }
-        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {
+        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
           let stmts = export_all_externals_rec_ids.iter().copied().map(|idx| {
             // Insert `__reExport(importer_exports, require('ext'))`
             let re_export_fn_ref = self.finalized_expr_for_runtime_symbol("__reExport");
@@ -734,6 +766,50 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     None
   }
 
+  /// Replaces `Enum.Member` with the value of the TypeScript enum member. Assignment targets aren't
+  /// visited here, since TypeScript doesn't allow writing to enum members anyway.
+  fn try_inline_ts_enum_member(
+    &self,
+    member_expr: &ast::MemberExpression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    if self.ctx.ts_enum_values.is_empty() {
+      return None;
+    }
+    let span = member_expr.span();
+    let (object_ref, props) = match self.ctx.linking_info.resolved_member_expr_refs.get(&span) {
+      // `ns.Enum.Member`
+      Some(resolution) => (resolution.resolved?, resolution.prop_and_related_span_list.as_slice()),
+      None => {
+        let Expression::Identifier(ident) = member_expr.object() else {
+          return None;
+        };
+        let symbol_id =
+          self.scope.scoping().get_reference(ident.reference_id.get()?).symbol_id()?;
+        let prop = member_expr.static_property_name()?;
+        let meta = self.inlinable_ts_enum_member((self.ctx.id, symbol_id).into(), prop)?;
+        return Some(meta.value.to_expression(AstBuilder::new(self.alloc)));
+      }
+    };
+    let (prop, _) = props.first()?;
+    let meta = self.inlinable_ts_enum_member(object_ref, prop)?;
+    Some(self.snippet.member_expr_or_ident_ref(
+      meta.value.to_expression(AstBuilder::new(self.alloc)),
+      &props[1..],
+      span,
+    ))
+  }
+
+  fn inlinable_ts_enum_member(
+    &self,
+    object_ref: SymbolRef,
+    prop: &str,
+  ) -> Option<&ConstExportMeta> {
+    let meta =
+      self.ctx.ts_enum_values.get(&self.ctx.symbol_db.canonical_ref_for(object_ref))?.get(prop)?;
+    (!self.ctx.options.optimization.is_inline_const_smart_mode() || meta.safe_to_inline)
+      .then_some(meta)
+  }
+
   /// try rewrite `foo_exports.bar` or `foo_exports['bar']`  to `bar` directly
   /// try rewrite `import.meta`
   fn try_rewrite_member_expr(
@@ -792,6 +868,45 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     (original_name != canonical_name.as_str()).then_some((original_name, canonical_name))
   }
 
+  /// Removes the members found unused by `treeshake.members`.
+  fn remove_unused_members(&self, decl: &mut ast::Declaration<'ast>) {
+    let unused_members = &self.ctx.linking_info.unused_members;
+    if unused_members.is_empty() {
+      return;
+    }
+    let is_unused = |symbol_id: Option<SymbolId>, name: Option<Cow<'_, str>>| {
+      symbol_id
+        .and_then(|symbol_id| unused_members.get(&symbol_id))
+        .zip(name)
+        .is_some_and(|(names, name)| names.contains(name.as_ref()))
+    };
+    match decl {
+      ast::Declaration::VariableDeclaration(decl) => {
+        for declarator in &mut decl.declarations {
+          let (
+            ast::BindingPatternKind::BindingIdentifier(id),
+            Some(Expression::ObjectExpression(obj)),
+          ) = (&declarator.id.kind, &mut declarator.init)
+          else {
+            continue;
+          };
+          let symbol_id = id.symbol_id.get();
+          obj.properties.retain(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(prop) => {
+              !is_unused(symbol_id, object_member_name(prop))
+            }
+            ObjectPropertyKind::SpreadProperty(_) => true,
+          });
+        }
+      }
+      ast::Declaration::ClassDeclaration(class) => {
+        let symbol_id = class.id.as_ref().and_then(|id| id.symbol_id.get());
+        class.body.body.retain(|element| !is_unused(symbol_id, class_member_name(element)));
+      }
+      _ => {}
+    }
+  }
+
   /// rewrite toplevel `class ClassName {}` to `var ClassName = class {}`
   fn get_transformed_class_decl(
     &self,
@@ -1058,28 +1173,22 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
         }
       }
     }
-    if matches!(self.ctx.options.format, OutputFormat::Cjs) {
-      // Convert `import('./foo.mjs')` to `Promise.resolve().then(function() { return require('foo.mjs') })`
+    if matches!(self.ctx.options.format, OutputFormat::Cjs | OutputFormat::Amd) {
+      // Convert `import('./foo.mjs')` to `Promise.resolve().then(function() { return require('foo.mjs') })`,
+      // or to `new Promise((resolve, reject) => require(['./foo'], resolve, reject))` in AMD output.
       match &self.ctx.modules[importee_id] {
         Module::Normal(importee) => {
           let importee_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee_id];
           let importee_chunk = &self.ctx.chunk_graph.chunk_table[importee_chunk_id];
-          let import_path = self.ctx.chunk.import_path_for(importee_chunk);
-
-          // require('foo.mjs')
-          let mut require_call_expr =
-            ast::Expression::CallExpression(self.snippet.builder.alloc_call_expression(
-              SPAN,
-              self.snippet.builder.expression_identifier(SPAN, "require"),
-              NONE,
-              self.snippet.builder.vec1(ast::Argument::StringLiteral(
-                self.snippet.alloc_string_literal(&import_path, import_expr.span),
-              )),
-              false,
-            ));
+          let is_amd = matches!(self.ctx.options.format, OutputFormat::Amd);
+          let import_path = if is_amd {
+            self.ctx.chunk.amd_import_path_for(importee_chunk)
+          } else {
+            self.ctx.chunk.import_path_for(importee_chunk)
+          };
 
-          if importee.exports_kind.is_commonjs() {
-            // __toDynamicImportESM
+          // __toDynamicImportESM(isNodeMode)
+          let to_dynamic_import_esm_fn_call = importee.exports_kind.is_commonjs().then(|| {
             let to_dynamic_import_esm_fn_name =
               self.finalized_expr_for_runtime_symbol("__toDynamicImportESM");
 
@@ -1092,16 +1201,54 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
                 NumberBase::Decimal,
               )));
             }
-            // __toDynamicImportESM(isNodeMode)
-            let to_dynamic_import_esm_fn_call =
-              ast::Expression::CallExpression(self.snippet.builder.alloc_call_expression(
+            ast::Expression::CallExpression(self.snippet.builder.alloc_call_expression(
+              SPAN,
+              to_dynamic_import_esm_fn_name,
+              NONE,
+              arguments,
+              false,
+            ))
+          });
+
+          if is_amd {
+            let promise_expr = self.snippet.amd_require_promise_expr(
+              self.snippet.alloc_string_literal(&import_path, import_expr.span),
+            );
+            let Some(to_dynamic_import_esm_fn_call) = to_dynamic_import_esm_fn_call else {
+              return Some(promise_expr);
+            };
+            // new Promise(...).then(__toDynamicImportESM(isNodeMode))
+            return Some(ast::Expression::CallExpression(
+              self.snippet.builder.alloc_call_expression(
                 SPAN,
-                to_dynamic_import_esm_fn_name,
+                ast::Expression::StaticMemberExpression(
+                  self.snippet.builder.alloc_static_member_expression(
+                    SPAN,
+                    promise_expr,
+                    self.snippet.id_name("then", SPAN),
+                    false,
+                  ),
+                ),
                 NONE,
-                arguments,
+                self.snippet.builder.vec1(ast::Argument::from(to_dynamic_import_esm_fn_call)),
                 false,
-              ));
+              ),
+            ));
+          }
 
+          // require('foo.mjs')
+          let mut require_call_expr =
+            ast::Expression::CallExpression(self.snippet.builder.alloc_call_expression(
+              SPAN,
+              self.snippet.builder.expression_identifier(SPAN, "require"),
+              NONE,
+              self.snippet.builder.vec1(ast::Argument::StringLiteral(
+                self.snippet.alloc_string_literal(&import_path, import_expr.span),
+              )),
+              false,
+            ));
+
+          if let Some(to_dynamic_import_esm_fn_call) = to_dynamic_import_esm_fn_call {
             // __toDynamicImportESM(isNodeMode)(require('foo.mjs'))
             require_call_expr =
               ast::Expression::CallExpression(self.snippet.builder.alloc_call_expression(
@@ -1125,6 +1272,31 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     None
   }
 
+  /// Replaces the placeholders left for the first import of each batch by
+  /// `transform_or_remove_import_export_stmt` with `await Promise.all([init_foo(), init_bar()])`.
+  fn insert_concurrent_init_calls(&mut self, program: &mut ast::Program<'ast>) {
+    for init_calls in std::mem::take(&mut self.concurrent_init_calls) {
+      let Some(first_call) = init_calls.first() else {
+        continue;
+      };
+      let placeholder_span = first_call.span();
+      let Some(placeholder) = program.body.iter_mut().find(|stmt| {
+        matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.span == placeholder_span)
+      }) else {
+        continue;
+      };
+      *placeholder = self.snippet.builder.statement_expression(
+        SPAN,
+        ast::Expression::AwaitExpression(
+          self
+            .snippet
+            .builder
+            .alloc_await_expression(SPAN, self.snippet.promise_all_call_expr(init_calls)),
+        ),
+      );
+    }
+  }
+
   #[expect(clippy::too_many_lines)]
   fn remove_unused_top_level_stmt(&mut self, program: &mut ast::Program<'ast>) -> usize {
     let mut last_import_stmt_idx = None;
@@ -1133,7 +1305,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     // the first statement info is the namespace variable declaration
     // skip first statement info to make sure `program.body` has same index as `stmt_infos`
     old_body.into_iter().enumerate().zip(self.ctx.module.stmt_infos.iter().skip(1)).for_each(
-      |((_top_stmt_idx, mut top_stmt), stmt_info)| {
+      |((top_stmt_idx, mut top_stmt), stmt_info)| {
         if !stmt_info.is_included {
           return;
         }
@@ -1273,6 +1445,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
                   rolldown_common::OutputFormat::Esm
                   | rolldown_common::OutputFormat::Iife
                   | rolldown_common::OutputFormat::Umd
+                  | rolldown_common::OutputFormat::Amd
                   | rolldown_common::OutputFormat::Cjs => {
                     // Just remove the statement
                     return;
@@ -1399,6 +1572,25 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
               return;
             }
           }
+        } else if self.ctx.module.meta.contains(EcmaViewMeta::ConvertedFromCommonjs)
+          && matches!(top_stmt, Statement::ExpressionStatement(_))
+        {
+          // `0` is reserved for Module Namespace Object stmt info
+          let stmt_info_idx = StmtInfoIdx::new(top_stmt_idx + 1);
+          if self
+            .ctx
+            .module
+            .convertible_commonjs
+            .as_ref()
+            .is_some_and(|es_module_flag_stmts| es_module_flag_stmts.contains(&stmt_info_idx))
+          {
+            // `exports.__esModule = true`
+            return;
+          }
+          if !self.convert_commonjs_export_stmt(&mut top_stmt) {
+            // `module.exports = {}`
+            return;
+          }
         } else if self.ctx.options.top_level_var {
           // Here we should find if it's a "VariableDeclaration" and switch it to "Var."
           if let Statement::VariableDeclaration(var_decl) = &mut top_stmt {
@@ -1422,6 +1614,87 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     last_import_stmt_idx.unwrap_or(0)
   }
 
+  /// Turns the export assignments of a module converted by `experimental.commonjsToEsm` into
+  /// declarations of its exports:
+  /// - `exports.foo = value` => `var foo = value`
+  /// - `module.exports = { foo, bar: value }` => `var foo$1 = foo, bar = value`
+  ///
+  /// Returns `false` if the statement declares nothing and should be removed.
+  fn convert_commonjs_export_stmt(&self, stmt: &mut Statement<'ast>) -> bool {
+    let Statement::ExpressionStatement(expr_stmt) = stmt else {
+      return true;
+    };
+    let Expression::AssignmentExpression(assignment) = &mut expr_stmt.expression else {
+      return true;
+    };
+    let assignment = &mut **assignment;
+    let Some(member_expr) = assignment.left.as_member_expression() else {
+      return true;
+    };
+    let (Expression::Identifier(object), Some(property)) =
+      (member_expr.object(), member_expr.static_property_name())
+    else {
+      return true;
+    };
+    // Only the statements recognized by `AstScanner::analyze_convertible_commonjs` are converted,
+    // a top-level `module` or `exports` binding shadows the global one.
+    if !self.is_global_identifier_reference(object) {
+      return true;
+    }
+    let named_exports = &self.ctx.module.named_exports;
+    let declarations = match (object.name.as_str(), property) {
+      ("module", "exports") => {
+        let Expression::ObjectExpression(obj) = &mut assignment.right else {
+          return true;
+        };
+        let declarations = obj.properties.iter_mut().filter_map(|prop| {
+          let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+            return None;
+          };
+          let export = named_exports.get(object_member_name(prop)?.as_ref())?;
+          Some((export.referenced, prop.value.take_in(self.alloc)))
+        });
+        declarations.collect::<Vec<_>>()
+      }
+      ("exports", name) => {
+        let Some(export) = named_exports.get(name) else {
+          return true;
+        };
+        vec![(export.referenced, assignment.right.take_in(self.alloc))]
+      }
+      _ => return true,
+    };
+    if declarations.is_empty() {
+      return false;
+    }
+
+    let builder = self.builder();
+    let declarators = declarations.into_iter().map(|(symbol_ref, init)| {
+      builder.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        builder.binding_pattern(
+          builder.binding_pattern_kind_binding_identifier(
+            SPAN,
+            builder.atom(self.canonical_name_for(symbol_ref)),
+          ),
+          NONE,
+          false,
+        ),
+        Some(init),
+        false,
+      )
+    });
+    let span = expr_stmt.span;
+    *stmt = Statement::VariableDeclaration(builder.alloc_variable_declaration(
+      span,
+      VariableDeclarationKind::Var,
+      builder.vec_from_iter(declarators),
+      false,
+    ));
+    true
+  }
+
   fn process_fn(
     &self,
     symbol_binding_id: Option<&BindingIdentifier<'ast>>,
@@ -1509,6 +1782,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
         // Make sure the import expression is in correct form. If it's not, we should leave it as it is.
         if let Some(str) = expr.source.as_static_module_request() {
           let mut needs_to_esm_helper = false;
+          let mut preload_paths = vec![];
           let rec = &self.ctx.module.import_records[*rec_id];
           let importee_id = rec.resolved_module;
           match &self.ctx.modules[importee_id] {
@@ -1524,6 +1798,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
                     self.snippet.alloc_string_literal(&import_path, expr.source.span()),
                   );
                   needs_to_esm_helper = importee.exports_kind.is_commonjs();
+                  preload_paths = self.dynamic_import_preload_paths(importee_chunk_id);
                 }
               } else {
                 // TODO: probably we should add the reason why it is replaced with `void 0` when upstream support codegen with specific operation
@@ -1591,6 +1866,42 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
             *node = ast::Expression::CallExpression(call_expr);
           }
+          if !preload_paths.is_empty() {
+            // Turn `import('./foo.js')` into `__preload(() => import('./foo.js'), ['./dep.js'], import.meta.url)`
+            let load_fn = self.snippet.only_return_arrow_expr(node.take_in(self.alloc));
+            let deps =
+              ast::Expression::ArrayExpression(self.snippet.builder.alloc_array_expression(
+                SPAN,
+                self.snippet.builder.vec_from_iter(preload_paths.iter().map(|path| {
+                  ast::ArrayExpressionElement::StringLiteral(
+                    self.snippet.alloc_string_literal(path, SPAN),
+                  )
+                })),
+              ));
+            let import_meta_url = ast::Expression::StaticMemberExpression(
+              self.snippet.builder.alloc_static_member_expression(
+                SPAN,
+                self.snippet.builder.expression_meta_property(
+                  SPAN,
+                  self.snippet.builder.identifier_name(SPAN, "import"),
+                  self.snippet.builder.identifier_name(SPAN, "meta"),
+                ),
+                self.snippet.builder.identifier_name(SPAN, "url"),
+                false,
+              ),
+            );
+            *node = ast::Expression::CallExpression(self.snippet.builder.alloc_call_expression(
+              SPAN,
+              self.finalized_expr_for_runtime_symbol("__preload"),
+              NONE,
+              self.snippet.builder.vec_from_array([
+                ast::Argument::from(load_fn),
+                ast::Argument::from(deps),
+                ast::Argument::from(import_meta_url),
+              ]),
+              false,
+            ));
+          }
           return true;
         }
       }
@@ -1598,6 +1909,37 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     false
   }
 
+  /// Paths of the files to preload with `import()` of the given chunk, relative to the current chunk.
+  /// See `Chunk#dynamic_import_preloads`.
+  fn dynamic_import_preload_paths(&self, importee_chunk_idx: ChunkIdx) -> Vec<String> {
+    // The link stage only includes `__preload` for dynamic imports of modules that may have
+    // dependencies to preload.
+    let depended_runtime_helper =
+      self.ctx.linking_infos[self.ctx.module.idx].depended_runtime_helper;
+    if !depended_runtime_helper.contains(RuntimeHelper::Preload) {
+      return vec![];
+    }
+    let Some(preloads) = self.ctx.chunk.dynamic_import_preloads.get(&importee_chunk_idx) else {
+      return vec![];
+    };
+    let mut paths = vec![];
+    for &chunk_idx in preloads {
+      let chunk = &self.ctx.chunk_graph.chunk_table[chunk_idx];
+      // The imported chunk itself is loaded by `import()`.
+      if chunk_idx != importee_chunk_idx {
+        paths.push(self.ctx.chunk.import_path_for(chunk));
+      }
+      let has_css = chunk.modules.iter().any(|module_idx| {
+        self.ctx.modules[*module_idx].as_normal().is_some_and(|module| module.css_view.is_some())
+      });
+      if has_css && let Some(css_filename) = &chunk.css_absolute_preliminary_filename {
+        let css_path = self.ctx.chunk.relative_path_for(css_filename.as_path());
+        paths.push(if css_path.starts_with("../") { css_path } else { format!("./{css_path}") });
+      }
+    }
+    paths
+  }
+
   /// if the json module prop needs to inline, we would just rewrite the inlined prop to
   /// `EmptyStatement`
   fn try_inline_json_module_prop(&mut self, it: &mut Statement<'ast>) -> Option<()> {
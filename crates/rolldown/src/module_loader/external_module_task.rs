@@ -1,15 +1,20 @@
 use std::{path::Path, sync::Arc};
 
 use arcstr::ArcStr;
+use oxc::span::CompactStr;
 use rolldown_common::{
-  ExternalModuleTaskResult, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ResolvedExternal,
-  ResolvedId,
+  ExternalModuleTaskResult, ImportKind, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg,
+  OutputFormat, ResolvedExternal, ResolvedId,
 };
 use rolldown_error::BuildResult;
 use rolldown_utils::{ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
+use rustc_hash::FxHashSet;
 use sugar_path::SugarPath;
 
-use crate::ecmascript::ecma_module_view_factory::normalize_side_effects;
+use crate::{
+  ecmascript::ecma_module_view_factory::normalize_side_effects,
+  utils::commonjs_exports_lexer::detect_commonjs_exports,
+};
 
 use super::task_context::TaskContext;
 
@@ -18,6 +23,9 @@ pub struct ExternalModuleTask {
   ctx: Arc<TaskContext>,
   module_idx: ModuleIdx,
   resolved_id: ResolvedId,
+  /// The stable id of the module that first imported this external. Each external is only loaded
+  /// once, so bare specifiers are resolved from this importer when detecting CommonJS exports.
+  importer_id: Option<CompactStr>,
   user_defined_entries: Arc<Vec<(Option<ArcStr>, ResolvedId)>>,
   // The module is asserted to be this specific module type.
 }
@@ -28,9 +36,10 @@ impl ExternalModuleTask {
     ctx: Arc<TaskContext>,
     idx: ModuleIdx,
     resolved_id: ResolvedId,
+    importer_id: Option<CompactStr>,
     user_defined_entries: Arc<Vec<(Option<ArcStr>, ResolvedId)>>,
   ) -> Self {
-    Self { ctx, module_idx: idx, resolved_id, user_defined_entries }
+    Self { ctx, module_idx: idx, resolved_id, importer_id, user_defined_entries }
   }
 
   #[tracing::instrument(name="ExternalModuleTask::run", level = "trace", skip_all, fields(module_id = ?self.resolved_id.id))]
@@ -97,10 +106,46 @@ impl ExternalModuleTask {
       identifier_name: legitimized_identifier_name.into(),
       side_effects: external_module_side_effects,
       need_renormalize_render_path,
+      commonjs_exports: self.detect_commonjs_exports(),
     }));
     self.ctx.tx.send(msg).await.expect(
       "ModuleLoader channel closed while sending external module completion - main thread terminated unexpectedly"
     );
     Ok(())
   }
+
+  /// Finds the named exports of the file that Node.js loads for this module, if it's CommonJS.
+  /// Returns `None` if they can't be known, such as for ES modules and Node.js builtins.
+  fn detect_commonjs_exports(&self) -> Option<FxHashSet<CompactStr>> {
+    let options = &self.ctx.options;
+    if !options.experimental.is_detect_external_commonjs_exports_enabled()
+      || !matches!(options.format, OutputFormat::Esm)
+    {
+      return None;
+    }
+    let resolved_id = &self.resolved_id;
+    let (path, module_def_format) = if Path::new(resolved_id.id.as_str()).is_absolute() {
+      (resolved_id.id.clone(), resolved_id.module_def_format)
+    } else {
+      // Bare specifiers are marked as external before being resolved, so resolve them the way
+      // Node.js does for `import`, starting from the importer. The importer is known by its stable
+      // id, which is relative to the cwd. Virtual importers have no location on disk, so those
+      // fall back to resolving from the cwd.
+      let importer = self
+        .importer_id
+        .as_deref()
+        .filter(|importer| !importer.starts_with('\0'))
+        .map(|importer| options.cwd.join(importer));
+      let resolved = self
+        .ctx
+        .resolver
+        .resolve(importer.as_deref(), &resolved_id.id, ImportKind::Import, false)
+        .ok()?;
+      (resolved.path, resolved.module_def_format)
+    };
+    if module_def_format.is_esm() {
+      return None;
+    }
+    detect_commonjs_exports(&self.ctx.fs, &self.ctx.resolver, Path::new(path.as_str()))
+  }
 }
@@ -249,7 +249,8 @@ impl<'a> ModuleLoader<'a> {
       }
     };
     if resolved_id.external.is_external() {
-      let task = ExternalModuleTask::new(ctx, idx, resolved_id, user_defined_entries);
+      let importer_id = owner.as_ref().map(|owner| owner.importer_id().clone());
+      let task = ExternalModuleTask::new(ctx, idx, resolved_id, importer_id, user_defined_entries);
       tokio::spawn(task.run().instrument(tracing::info_span!("external_module_task")));
     } else {
       let task = ModuleTask::new(
@@ -289,6 +290,7 @@ impl<'a> ModuleLoader<'a> {
       ScanMode::Full => {
         resolve_user_defined_entries(
           &self.options,
+          &self.shared_context.fs,
           &self.shared_context.resolver,
           &self.shared_context.plugin_driver,
         )
@@ -482,6 +484,7 @@ impl<'a> ModuleLoader<'a> {
             identifier_name,
             side_effects,
             need_renormalize_render_path,
+            commonjs_exports,
           } = *task_result;
 
           self.symbol_ref_db.store_local_db(
@@ -489,7 +492,7 @@ impl<'a> ModuleLoader<'a> {
             SymbolRefDbForModule::new(Scoping::default(), task_result.idx, ScopeId::new(0)),
           );
           let symbol_ref = self.symbol_ref_db.create_facade_root_symbol_ref(idx, &identifier_name);
-          let ext = ExternalModule::new(
+          let mut ext = ExternalModule::new(
             idx,
             id,
             name,
@@ -498,6 +501,7 @@ impl<'a> ModuleLoader<'a> {
             symbol_ref,
             need_renormalize_render_path,
           );
+          ext.commonjs_exports = commonjs_exports;
           *self.intermediate_normal_modules.modules.get_mut(task_result.idx) = Some(ext.into());
 
           self.remaining -= 1;
@@ -36,6 +36,10 @@ impl ModuleTaskOwner {
   pub fn new(source: ArcStr, importer_id: CompactStr, importee_span: Span) -> Self {
     ModuleTaskOwner { source, importer_id, importee_span }
   }
+
+  pub fn importer_id(&self) -> &CompactStr {
+    &self.importer_id
+  }
 }
 
 pub struct ModuleTask {
@@ -1,16 +1,22 @@
-use std::sync::Arc;
+use std::{
+  borrow::Cow,
+  path::{MAIN_SEPARATOR_STR, Path},
+  sync::Arc,
+};
 
 use arcstr::ArcStr;
 use futures::future::join_all;
 use oxc_index::IndexVec;
 use rolldown_common::{
-  ImportKind, ImportRecordIdx, ImportRecordMeta, ModuleDefFormat, ModuleType,
-  NormalizedBundlerOptions, RUNTIME_MODULE_KEY, RawImportRecord, ResolvedId,
+  ImportKind, ImportMap, ImportRecordIdx, ImportRecordMeta, ModuleDefFormat, ModuleType,
+  NodePolyfill, NormalizedBundlerOptions, RUNTIME_MODULE_KEY, RawImportRecord, ResolvedId,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult, DiagnosableArcstr, EventKind};
 use rolldown_plugin::{__inner::resolve_id_check_external, PluginDriver, SharedPluginDriver};
 use rolldown_resolver::{ResolveError, Resolver};
 use rolldown_utils::ecmascript::{self};
+use rustc_hash::FxHashMap;
+use sugar_path::SugarPath;
 
 use crate::{SharedOptions, SharedResolver};
 
@@ -32,10 +38,20 @@ pub async fn resolve_id(
     }));
   }
 
+  let specifier = match map_specifier(bundle_options, importer, specifier) {
+    // Urls are loaded by the runtime, e.g. `https://esm.sh/react`, unless remote imports are
+    // bundled.
+    Some(ImportMapResolution::Url(url)) if bundle_options.experimental.remote_imports.is_none() => {
+      return Ok(Ok(ResolvedId { id: url.into(), external: true.into(), ..Default::default() }));
+    }
+    Some(ImportMapResolution::Url(url) | ImportMapResolution::Specifier(url)) => Cow::Owned(url),
+    None => Cow::Borrowed(specifier),
+  };
+
   resolve_id_check_external(
     resolver,
     plugin_driver,
-    specifier,
+    &specifier,
     Some(importer),
     false,
     kind,
@@ -47,6 +63,41 @@ pub async fn resolve_id(
   .await
 }
 
+fn map_specifier(
+  bundle_options: &NormalizedBundlerOptions,
+  importer: &str,
+  specifier: &str,
+) -> Option<ImportMapResolution> {
+  bundle_options.experimental.import_map.as_ref().and_then(|import_map| {
+    resolve_with_import_map(import_map, &bundle_options.cwd, importer, specifier)
+  })
+}
+
+/// Collects the trace of a dependency that couldn't be resolved, see `experimental.resolveTrace`.
+/// The specifier is rewritten the same way as in `resolve_id` first, since that's what the resolver
+/// actually saw.
+fn trace_failed_dependency(
+  bundle_options: &NormalizedBundlerOptions,
+  resolver: &Resolver,
+  importer: &str,
+  specifier: &str,
+  kind: ImportKind,
+) -> Option<String> {
+  if !resolver.is_trace_enabled() {
+    return None;
+  }
+  let specifier = match map_specifier(bundle_options, importer, specifier) {
+    Some(ImportMapResolution::Url(url) | ImportMapResolution::Specifier(url)) => Cow::Owned(url),
+    None => Cow::Borrowed(specifier),
+  };
+  let specifier = match bundle_options.node_polyfill(&specifier) {
+    Some(NodePolyfill::Package(polyfill)) => polyfill,
+    _ => &*specifier,
+  };
+  let trace = resolver.trace_failed_resolve(Some(Path::new(importer)), specifier, kind, false)?;
+  Some(trace.to_string())
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn resolve_dependencies(
   self_resolved_id: &ResolvedId,
@@ -81,6 +132,8 @@ pub async fn resolve_dependencies(
       Err(e) => {
         let dep = &dependencies[idx];
         let specifier = &dep.module_request;
+        let trace =
+          trace_failed_dependency(options, resolver, &self_resolved_id.id, specifier, dep.kind);
         match &e {
           ResolveError::NotFound(..) => {
             // NOTE: IN_TRY_CATCH_BLOCK meta if it is a `require` import
@@ -99,7 +152,7 @@ pub async fn resolve_dependencies(
                   },
                   "Module not found.".into(),
                   EventKind::UnresolvedImport,
-                  None,
+                  trace,
                 ));
               } else {
                 let help = matches!(options.platform, rolldown_common::Platform::Neutral).then(|| {
@@ -116,7 +169,7 @@ pub async fn resolve_dependencies(
                     },
                     "Module not found, treating it as an external dependency".into(),
                     EventKind::UnresolvedImport,
-                    help,
+                    join_help(help, trace),
                   )
                   .with_severity_warning(),
                 );
@@ -139,7 +192,7 @@ pub async fn resolve_dependencies(
                 },
                 format!("Matched alias not found for '{specifier}'"),
                     EventKind::ResolveError,
-                Some("May be you expected `resolve.alias` to call other plugins resolveId hook? see the docs https://rolldown.rs/apis/config-options#resolve-alias for more details".to_string()),
+                join_help(Some("May be you expected `resolve.alias` to call other plugins resolveId hook? see the docs https://rolldown.rs/apis/config-options#resolve-alias for more details".to_string()), trace),
               ));
           }
           e => {
@@ -153,7 +206,7 @@ pub async fn resolve_dependencies(
               },
               rolldown_resolver::error::resolve_error_to_message(e),
               EventKind::ResolveError,
-              None,
+              trace,
             ));
           }
         }
@@ -163,3 +216,114 @@ pub async fn resolve_dependencies(
 
   if build_errors.is_empty() { Ok(ret) } else { Err(build_errors.into()) }
 }
+
+enum ImportMapResolution {
+  Url(String),
+  /// A path or a bare specifier, which is resolved as usual.
+  Specifier(String),
+}
+
+/// Resolves `specifier` with an import map as browsers do, see
+/// https://html.spec.whatwg.org/multipage/webappapis.html#resolving-a-module-specifier.
+///
+/// Paths in the import map are relative to `cwd`, and `/` refers to `cwd` itself.
+fn resolve_with_import_map(
+  import_map: &ImportMap,
+  cwd: &Path,
+  importer: &str,
+  specifier: &str,
+) -> Option<ImportMapResolution> {
+  // Path-like specifiers are matched by the path they point to.
+  let normalized_specifier = if ecmascript::is_relative_specifier(specifier) {
+    let importer_dir = Path::new(importer).parent().unwrap_or(cwd);
+    Cow::Owned(importer_dir.join(specifier).normalize().to_string_lossy().into_owned())
+  } else {
+    Cow::Borrowed(specifier)
+  };
+
+  let mut scopes = import_map
+    .scopes
+    .iter()
+    .flatten()
+    .map(|(scope, imports)| (import_map_address_to_path(cwd, scope), imports))
+    .filter(|(scope, _)| {
+      if scope.ends_with(MAIN_SEPARATOR_STR) {
+        importer.starts_with(scope)
+      } else {
+        importer == scope.as_str()
+      }
+    })
+    .collect::<Vec<_>>();
+  // The most specific scope wins.
+  scopes.sort_by_key(|(scope, _)| std::cmp::Reverse(scope.len()));
+
+  let address = scopes
+    .into_iter()
+    .map(|(_, imports)| imports)
+    .chain(import_map.imports.as_ref())
+    .find_map(|imports| match_specifier_map(imports, cwd, &normalized_specifier))?;
+
+  if is_url(&address) {
+    Some(ImportMapResolution::Url(address))
+  } else if ecmascript::is_path_like_specifier(&address) {
+    Some(ImportMapResolution::Specifier(import_map_address_to_path(cwd, &address)))
+  } else {
+    Some(ImportMapResolution::Specifier(address))
+  }
+}
+
+/// Keys ending with `/` match any specifier they prefix, the longest one wins over the others.
+fn match_specifier_map(
+  imports: &FxHashMap<String, String>,
+  cwd: &Path,
+  specifier: &str,
+) -> Option<String> {
+  imports
+    .iter()
+    .filter_map(|(key, address)| {
+      let key = if ecmascript::is_path_like_specifier(key) {
+        Cow::Owned(import_map_address_to_path(cwd, key))
+      } else {
+        Cow::Borrowed(key.as_str())
+      };
+      if key == specifier {
+        Some((key.len(), address.clone()))
+      } else if (key.ends_with('/') || key.ends_with(MAIN_SEPARATOR_STR))
+        && specifier.starts_with(&*key)
+        && address.ends_with('/')
+      {
+        Some((key.len(), rolldown_utils::concat_string!(address, &specifier[key.len()..])))
+      } else {
+        None
+      }
+    })
+    .max_by_key(|(key_len, _)| *key_len)
+    .map(|(_, address)| address)
+}
+
+/// Turns a path in the import map into an absolute path, keeping the trailing separator.
+fn import_map_address_to_path(cwd: &Path, address: &str) -> String {
+  if is_url(address) || (Path::new(address).is_absolute() && !address.starts_with('/')) {
+    return address.to_string();
+  }
+  let path = cwd.join(address.trim_start_matches('/')).normalize();
+  let mut path = path.to_string_lossy().into_owned();
+  if address.ends_with('/') {
+    path.push_str(MAIN_SEPARATOR_STR);
+  }
+  path
+}
+
+fn is_url(address: &str) -> bool {
+  address.starts_with("data:")
+    || address
+      .find("://")
+      .is_some_and(|i| i > 0 && address[..i].bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+fn join_help(help: Option<String>, trace: Option<String>) -> Option<String> {
+  match (help, trace) {
+    (Some(help), Some(trace)) => Some(format!("{help}\n{trace}")),
+    (help, trace) => help.or(trace),
+  }
+}
@@ -5,8 +5,8 @@ use oxc::ast_visit::VisitMut;
 use oxc::span::SourceType;
 use oxc_index::IndexVec;
 use rolldown_common::{
-  EcmaView, ExportsKind, FlatOptions, ModuleDefFormat, ModuleIdx, ModuleType, NormalModule,
-  side_effects::DeterminedSideEffects,
+  EcmaView, ExportsKind, FlatOptions, MemberReads, ModuleDefFormat, ModuleIdx, ModuleType,
+  NormalModule, side_effects::DeterminedSideEffects,
 };
 use rolldown_common::{
   ModuleLoaderMsg, RUNTIME_MODULE_ID, RUNTIME_MODULE_KEY, ResolvedId, RuntimeModuleBrief,
@@ -149,6 +149,12 @@ impl RuntimeModuleTask {
         directive_range: vec![],
         dummy_record_set,
         constant_export_map: FxHashMap::default(),
+        ts_enum_values: FxHashMap::default(),
+        removable_members: FxHashMap::default(),
+        // The runtime only reads the own properties of module exports, which never include the
+        // instance methods of a class.
+        member_reads: MemberReads::default(),
+        convertible_commonjs: None,
         depended_runtime_helper: Box::default(),
         import_attribute_map: FxHashMap::default(),
         json_module_none_self_reference_included_symbol: None,
@@ -198,6 +204,7 @@ impl RuntimeModuleTask {
       &self.ctx.options,
       ast.allocator(),
       self.flat_options,
+      FxHashMap::default(),
     );
     let scan_result = scanner.scan(ast.program())?;
 
@@ -95,3 +95,30 @@ export var __toBinary = /* @__PURE__ */ (() => {
 // Rolldown uses this to convert the return value of `import('./some-cjs-module.js')` to a more sensible ESM module namespace.
 export var __toDynamicImportESM = (isNodeMode) => (mod) =>
   __toESM(mod.default, isNodeMode);
+
+// Rolldown uses this to load the static dependencies of `import('./some-chunk.js')` in parallel with it. `deps` are relative to `base`, the `import.meta.url` of the importer.
+export var __preload = /* @__PURE__ */ (() => {
+  var seen = {};
+  return (load, deps, base) => {
+    if (typeof document === 'undefined') return load();
+    return Promise.all(
+      deps.map((dep) => {
+        dep = new URL(dep, base).href;
+        if (dep in seen) return seen[dep];
+        var isCss = dep.endsWith('.css');
+        var link = document.createElement('link');
+        link.rel = isCss ? 'stylesheet' : 'modulepreload';
+        link.href = dep;
+        // Wait for stylesheets to avoid rendering the content without its styles.
+        seen[dep] = isCss
+          ? new Promise((resolve) => {
+            link.addEventListener('load', resolve);
+            link.addEventListener('error', resolve);
+          })
+          : undefined;
+        document.head.appendChild(link);
+        return seen[dep];
+      }),
+    ).then(load);
+  };
+})();
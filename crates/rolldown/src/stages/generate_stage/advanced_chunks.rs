@@ -3,7 +3,8 @@ use std::{cmp::Reverse, sync::Arc};
 use arcstr::ArcStr;
 use oxc_index::IndexVec;
 use rolldown_common::{
-  Chunk, ChunkKind, ChunkingContext, MatchGroupTest, Module, ModuleIdx, ModuleTable,
+  Chunk, ChunkKind, ChunkSizeMetric, ChunkSplitStrategy, ChunkingContext, MatchGroupTest, Module,
+  ModuleIdx, ModuleTable,
 };
 use rolldown_error::BuildResult;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -14,6 +15,7 @@ use super::{
   GenerateStage,
   chunk_ext::{ChunkCreationReason, ChunkDebugExt},
   code_splitting::IndexSplittingInfo,
+  module_sizes::ModuleSizes,
 };
 
 // `ModuleGroup` is a temporary representation of `Chunk`. A valid `ModuleGroup` would be converted to a `Chunk` in the end.
@@ -31,29 +33,64 @@ oxc_index::define_index_type! {
 }
 
 impl ModuleGroup {
-  #[expect(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
-  pub fn add_module(&mut self, module_idx: ModuleIdx, module_table: &ModuleTable) {
+  pub fn add_module(&mut self, module_idx: ModuleIdx, module_sizes: &ModuleSizes) {
     if self.modules.insert(module_idx) {
-      self.sizes += module_table[module_idx].size() as f64;
+      self.sizes += module_sizes[module_idx];
     }
   }
 
-  #[expect(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
-  pub fn remove_module(&mut self, module_idx: ModuleIdx, module_table: &ModuleTable) {
+  pub fn remove_module(&mut self, module_idx: ModuleIdx, module_sizes: &ModuleSizes) {
     if self.modules.remove(&module_idx) {
-      self.sizes -= module_table[module_idx].size() as f64;
+      self.sizes -= module_sizes[module_idx];
       self.sizes = f64::max(self.sizes, 0.0);
     }
   }
 }
 
 impl GenerateStage<'_> {
-  #[expect(
-    clippy::too_many_lines,
-    clippy::cast_precision_loss,
-    clippy::cast_sign_loss,
-    clippy::cast_possible_wrap
-  )] // TODO(hyf0): refactor
+  /// Puts the module `rolldown:runtime` into a standalone chunk named `rolldown-runtime`.
+  ///
+  /// Returns `true` if the runtime module lives in the standalone chunk.
+  pub(super) fn create_runtime_chunk(
+    &self,
+    index_splitting_info: &IndexSplittingInfo,
+    module_to_assigned: &mut IndexVec<ModuleIdx, bool>,
+    chunk_graph: &mut ChunkGraph,
+    input_base: &ArcStr,
+  ) -> bool {
+    let runtime_module_idx = self.link_output.runtime.id();
+    let Module::Normal(runtime_module) = &self.link_output.module_table[runtime_module_idx] else {
+      unreachable!("`rolldown:runtime` is always a normal module");
+    };
+
+    if !runtime_module.meta.is_included() {
+      return false;
+    }
+    if module_to_assigned[runtime_module_idx] {
+      // Already created by `experimental.standaloneRuntimeChunk`.
+      return true;
+    }
+
+    let runtime_chunk = Chunk::new(
+      Some("rolldown-runtime".into()),
+      None,
+      index_splitting_info[runtime_module_idx].bits.clone(),
+      vec![],
+      ChunkKind::Common,
+      input_base.clone(),
+      None,
+    );
+    let chunk_idx = chunk_graph.add_chunk(runtime_chunk);
+    chunk_graph.add_module_to_chunk(
+      runtime_module_idx,
+      chunk_idx,
+      self.link_output.metas[runtime_module_idx].depended_runtime_helper,
+    );
+    module_to_assigned[runtime_module_idx] = true;
+    true
+  }
+
+  #[expect(clippy::too_many_lines, clippy::cast_sign_loss, clippy::cast_possible_wrap)] // TODO(hyf0): refactor
   pub async fn apply_advanced_chunks(
     &self,
     index_splitting_info: &IndexSplittingInfo,
@@ -75,8 +112,32 @@ impl GenerateStage<'_> {
       return Ok(());
     }
 
+    // Sizes are only compared with the size options, so measuring them is skipped without any.
+    let is_size_limited = [
+      chunking_options.min_size,
+      chunking_options.max_size,
+      chunking_options.min_module_size,
+      chunking_options.max_module_size,
+    ]
+    .iter()
+    .chain(match_groups.iter().flat_map(|group| {
+      [&group.min_size, &group.max_size, &group.min_module_size, &group.max_module_size]
+    }))
+    .any(Option::is_some);
+    let size_metric = if is_size_limited {
+      chunking_options.size_metric.unwrap_or_default()
+    } else {
+      ChunkSizeMetric::Source
+    };
+    let module_sizes = self.compute_module_sizes(size_metric);
+    let split_strategy = chunking_options.split_strategy.unwrap_or_default();
+
     let mut index_module_groups: IndexVec<ModuleGroupIdx, ModuleGroup> = IndexVec::new();
-    let mut name_to_module_group: FxHashMap<(usize, ArcStr), ModuleGroupIdx> = FxHashMap::default();
+    // With `experimental.preserveDirectives`, a group only takes modules with the same directives,
+    // so `'use client'` modules never end up in a chunk with server modules.
+    let split_by_directives = self.splits_by_directives();
+    let mut name_to_module_group: FxHashMap<(usize, ArcStr, Vec<&str>), ModuleGroupIdx> =
+      FxHashMap::default();
 
     for normal_module in self.link_output.module_table.modules.iter().filter_map(Module::as_normal)
     {
@@ -109,9 +170,9 @@ impl GenerateStage<'_> {
           match_group.max_module_size.map_or(chunking_options.max_module_size, Some);
 
         let is_min_module_size_satisfied = allow_min_module_size
-          .is_none_or(|min_module_size| normal_module.size() >= min_module_size);
+          .is_none_or(|min_module_size| module_sizes[normal_module.idx] >= min_module_size);
         let is_max_module_size_satisfied = allow_max_module_size
-          .is_none_or(|max_module_size| normal_module.size() <= max_module_size);
+          .is_none_or(|max_module_size| module_sizes[normal_module.idx] <= max_module_size);
 
         if !is_min_module_size_satisfied || !is_max_module_size_satisfied {
           continue;
@@ -133,7 +194,12 @@ impl GenerateStage<'_> {
         };
         let group_name = ArcStr::from(group_name);
 
-        let unique_key = (match_group_index, group_name.clone());
+        let directives = if split_by_directives {
+          self.normalized_directives_of(normal_module.idx)
+        } else {
+          vec![]
+        };
+        let unique_key = (match_group_index, group_name.clone(), directives.clone());
 
         let module_group_idx = name_to_module_group.entry(unique_key).or_insert_with(|| {
           index_module_groups.push(ModuleGroup {
@@ -153,8 +219,12 @@ impl GenerateStage<'_> {
           normal_module.idx,
           &self.link_output.metas,
           &self.link_output.module_table,
+          &module_sizes,
           &mut FxHashSet::default(),
           include_dependencies_recursively,
+          &|module_idx| {
+            !split_by_directives || self.normalized_directives_of(module_idx) == directives
+          },
         );
       }
     }
@@ -175,32 +245,12 @@ impl GenerateStage<'_> {
     }
 
     // Manually pull out the module `rolldown:runtime` into a standalone chunk.
-    let runtime_module_idx = self.link_output.runtime.id();
-    let Module::Normal(runtime_module) = &self.link_output.module_table[runtime_module_idx] else {
-      unreachable!("`rolldown:runtime` is always a normal module");
-    };
-
-    if runtime_module.meta.is_included() {
-      let runtime_chunk = Chunk::new(
-        Some("rolldown-runtime".into()),
-        None,
-        index_splitting_info[runtime_module_idx].bits.clone(),
-        vec![],
-        ChunkKind::Common,
-        input_base.clone(),
-        None,
-      );
-      let chunk_idx = chunk_graph.add_chunk(runtime_chunk);
+    if self.create_runtime_chunk(index_splitting_info, module_to_assigned, chunk_graph, input_base)
+    {
+      let runtime_module_idx = self.link_output.runtime.id();
       module_groups.iter_mut().for_each(|group| {
-        group.remove_module(runtime_module_idx, &self.link_output.module_table);
+        group.remove_module(runtime_module_idx, &module_sizes);
       });
-      chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[runtime_module_idx].bits);
-      chunk_graph.add_module_to_chunk(
-        runtime_module_idx,
-        chunk_idx,
-        self.link_output.metas[runtime_module_idx].depended_runtime_helper,
-      );
-      module_to_assigned[runtime_module_idx] = true;
     }
 
     while let Some(this_module_group) = module_groups.pop() {
@@ -223,83 +273,94 @@ impl GenerateStage<'_> {
       {
         if this_module_group.sizes > allow_max_size {
           // If the size of the group is larger than the max size, we should split the group into smaller groups.
-          let mut modules = this_module_group.modules.iter().copied().collect::<Vec<_>>();
-          modules.sort_by_key(|module_idx| {
-            (
-              // smaller size goes first
-              self.link_output.module_table[*module_idx].size(),
-              self.link_output.module_table[*module_idx].stable_id(),
-              self.link_output.module_table[*module_idx].exec_order(),
-            )
-          });
-          // Make sure we sort the modules based on size in the end. Since we compute new group size from left to right, if a giant
-          // module is at the most left, it may cause a split-able group can't be split.
-
-          let mut left_size = 0f64;
-          let mut next_left_index = 0isize;
-          let mut right_size = 0f64;
-          let mut next_right_index = (modules.len() - 1) as isize;
-          let modules_len = modules.len() as isize;
-
-          while left_size < allow_min_size && next_left_index < modules_len {
-            left_size +=
-              self.link_output.module_table[modules[next_left_index as usize]].size() as f64;
-            next_left_index += 1;
-          }
-
-          while right_size < allow_min_size && next_right_index >= 0 {
-            right_size +=
-              self.link_output.module_table[modules[next_right_index as usize]].size() as f64;
-            next_right_index -= 1;
-          }
-          if next_right_index + 1 < next_left_index {
-            // For example:
-            // [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
-            //          r^    l^
-            // Left contains [0, 1, 2, 3, 4].
-            // Right contains [4, 5, 6, 7, 8, 9].
-            // There's a overlap [4] in both groups.
-            // That is the group can't be split into two groups with both satisfied the min size requirement.
-            // In this case, we just ignore the max size requirement and keep the group as a whole.
+          if split_strategy == ChunkSplitStrategy::Locality {
+            if let Some(parts) =
+              self.split_module_group_by_locality(&this_module_group, allow_min_size, &module_sizes)
+            {
+              module_groups.extend(parts);
+              continue;
+            }
           } else {
-            // TODO: Though, [0..next_left_index] is a valid group, we want to find a best split index that makes files in left group are in the same disk location.
-            let mut split_size = left_size;
-            loop {
-              if next_left_index <= next_right_index && split_size < allow_max_size {
-                split_size += self.link_output.module_table.modules
-                  [modules[next_left_index as usize]]
-                  .size() as f64;
-                next_left_index += 1;
-              } else {
-                break;
-              }
+            let mut modules = this_module_group.modules.iter().copied().collect::<Vec<_>>();
+            modules.sort_by(|a, b| {
+              // smaller size goes first
+              module_sizes[*a]
+                .total_cmp(&module_sizes[*b])
+                .then_with(|| {
+                  self.link_output.module_table[*a]
+                    .stable_id()
+                    .cmp(self.link_output.module_table[*b].stable_id())
+                })
+                .then_with(|| {
+                  self.link_output.module_table[*a]
+                    .exec_order()
+                    .cmp(&self.link_output.module_table[*b].exec_order())
+                })
+            });
+            // Make sure we sort the modules based on size in the end. Since we compute new group size from left to right, if a giant
+            // module is at the most left, it may cause a split-able group can't be split.
+
+            let mut left_size = 0f64;
+            let mut next_left_index = 0isize;
+            let mut right_size = 0f64;
+            let mut next_right_index = (modules.len() - 1) as isize;
+            let modules_len = modules.len() as isize;
+
+            while left_size < allow_min_size && next_left_index < modules_len {
+              left_size += module_sizes[modules[next_left_index as usize]];
+              next_left_index += 1;
             }
-            while next_left_index <= next_right_index && next_right_index >= 0 {
-              right_size += self.link_output.module_table.modules
-                [modules[next_right_index as usize]]
-                .size() as f64;
+
+            while right_size < allow_min_size && next_right_index >= 0 {
+              right_size += module_sizes[modules[next_right_index as usize]];
               next_right_index -= 1;
             }
+            if next_right_index + 1 < next_left_index {
+              // For example:
+              // [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+              //          r^    l^
+              // Left contains [0, 1, 2, 3, 4].
+              // Right contains [4, 5, 6, 7, 8, 9].
+              // There's a overlap [4] in both groups.
+              // That is the group can't be split into two groups with both satisfied the min size requirement.
+              // In this case, we just ignore the max size requirement and keep the group as a whole.
+            } else {
+              // Though, [0..next_left_index] is a valid group, files in it could come from anywhere on the disk.
+              // Use `splitStrategy: 'locality'` to keep files in the same disk location together.
+              let mut split_size = left_size;
+              loop {
+                if next_left_index <= next_right_index && split_size < allow_max_size {
+                  split_size += module_sizes[modules[next_left_index as usize]];
+                  next_left_index += 1;
+                } else {
+                  break;
+                }
+              }
+              while next_left_index <= next_right_index && next_right_index >= 0 {
+                right_size += module_sizes[modules[next_right_index as usize]];
+                next_right_index -= 1;
+              }
 
-            if next_right_index != -1 && next_left_index != modules_len {
-              // - next_right_index == -1
-              // - next_left_index == modules.len()
-              // They mean that either left or right group is empty, which is not allowed.
-              module_groups.push(ModuleGroup {
-                name: this_module_group.name.clone(),
-                match_group_index: this_module_group.match_group_index,
-                modules: modules[..next_left_index as usize].iter().copied().collect(),
-                priority: this_module_group.priority,
-                sizes: split_size,
-              });
-              module_groups.push(ModuleGroup {
-                name: this_module_group.name.clone(),
-                match_group_index: this_module_group.match_group_index,
-                modules: modules[next_left_index as usize..].iter().copied().collect(),
-                priority: this_module_group.priority,
-                sizes: right_size,
-              });
-              continue;
+              if next_right_index != -1 && next_left_index != modules_len {
+                // - next_right_index == -1
+                // - next_left_index == modules.len()
+                // They mean that either left or right group is empty, which is not allowed.
+                module_groups.push(ModuleGroup {
+                  name: this_module_group.name.clone(),
+                  match_group_index: this_module_group.match_group_index,
+                  modules: modules[..next_left_index as usize].iter().copied().collect(),
+                  priority: this_module_group.priority,
+                  sizes: split_size,
+                });
+                module_groups.push(ModuleGroup {
+                  name: this_module_group.name.clone(),
+                  match_group_index: this_module_group.match_group_index,
+                  modules: modules[next_left_index as usize..].iter().copied().collect(),
+                  priority: this_module_group.priority,
+                  sizes: right_size,
+                });
+                continue;
+              }
             }
           }
         }
@@ -328,7 +389,7 @@ impl GenerateStage<'_> {
 
       this_module_group.modules.iter().copied().for_each(|module_idx| {
         module_groups.iter_mut().for_each(|group| {
-          group.remove_module(module_idx, &self.link_output.module_table);
+          group.remove_module(module_idx, &module_sizes);
         });
         chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[module_idx].bits);
         chunk_graph.add_module_to_chunk(
@@ -341,15 +402,68 @@ impl GenerateStage<'_> {
     }
     Ok(())
   }
+
+  /// Splits a group that exceeds `maxSize` into two parts. Modules are sorted by path and the group is
+  /// split between the two neighboring paths that are least similar, so modules from the same package
+  /// or directory stay together. The split position only depends on paths and sizes, so editing a
+  /// module doesn't move other modules between parts unless it changes the sizes around a boundary.
+  ///
+  /// Returns `None` if the group can't be split into two parts that both satisfy the min size.
+  fn split_module_group_by_locality(
+    &self,
+    module_group: &ModuleGroup,
+    allow_min_size: f64,
+    module_sizes: &ModuleSizes,
+  ) -> Option<[ModuleGroup; 2]> {
+    let mut modules = module_group
+      .modules
+      .iter()
+      .map(|&module_idx| {
+        (locality_key(self.link_output.module_table[module_idx].stable_id()), module_idx)
+      })
+      .collect::<Vec<_>>();
+    modules.sort_unstable();
+
+    let total_size = modules.iter().map(|(_, module_idx)| module_sizes[*module_idx]).sum::<f64>();
+    // (similarity, split index, size of left part)
+    let mut best_split: Option<(usize, usize, f64)> = None;
+    let mut left_size = 0f64;
+    for split_index in 1..modules.len() {
+      left_size += module_sizes[modules[split_index - 1].1];
+      if left_size < allow_min_size || total_size - left_size < allow_min_size {
+        continue;
+      }
+      let similarity = path_similarity(&modules[split_index - 1].0, &modules[split_index].0);
+      if best_split.is_none_or(|(best_similarity, _, _)| similarity < best_similarity) {
+        best_split = Some((similarity, split_index, left_size));
+      }
+    }
+
+    let (_, split_index, left_size) = best_split?;
+    let create_part = |modules: &[(String, ModuleIdx)], sizes: f64| ModuleGroup {
+      name: module_group.name.clone(),
+      match_group_index: module_group.match_group_index,
+      modules: modules.iter().map(|(_, module_idx)| *module_idx).collect(),
+      priority: module_group.priority,
+      sizes,
+    };
+    Some([
+      create_part(&modules[..split_index], left_size),
+      create_part(&modules[split_index..], total_size - left_size),
+    ])
+  }
 }
 
+#[expect(clippy::too_many_arguments)]
 fn add_module_and_dependencies_to_group_recursively(
   module_group: &mut ModuleGroup,
   module_idx: ModuleIdx,
   module_metas: &LinkingMetadataVec,
   module_table: &ModuleTable,
+  module_sizes: &ModuleSizes,
   visited: &mut FxHashSet<ModuleIdx>,
   recursively: bool,
+  can_join_group: &impl Fn(ModuleIdx) -> bool,
 ) {
   let is_visited = !visited.insert(module_idx);
 
@@ -361,13 +475,13 @@ fn add_module_and_dependencies_to_group_recursively(
     return;
   };
 
-  if !module.ecma_view.meta.is_included() {
+  if !module.ecma_view.meta.is_included() || !can_join_group(module_idx) {
     return;
   }
 
   visited.insert(module_idx);
 
-  module_group.add_module(module_idx, module_table);
+  module_group.add_module(module_idx, module_sizes);
   if recursively {
     for dep in &module_metas[module_idx].dependencies {
       add_module_and_dependencies_to_group_recursively(
@@ -375,9 +489,21 @@ fn add_module_and_dependencies_to_group_recursively(
         *dep,
         module_metas,
         module_table,
+        module_sizes,
         visited,
         recursively,
+        can_join_group,
       );
     }
   }
 }
+
+fn locality_key(stable_id: &str) -> String {
+  stable_id.replace('\\', "/")
+}
+
+/// Same measure as webpack's `deterministicGrouping`. Every pair of characters at the same position
+/// contributes more the closer they are, so paths sharing a long prefix are the most similar.
+fn path_similarity(a: &str, b: &str) -> usize {
+  a.bytes().zip(b.bytes()).map(|(a, b)| 10usize.saturating_sub(usize::from(a.abs_diff(b)))).sum()
+}
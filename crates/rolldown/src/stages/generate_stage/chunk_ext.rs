@@ -16,6 +16,7 @@ pub enum ChunkCreationReason<'a> {
   PreserveModules { is_user_defined_entry: bool, module_stable_id: &'a str },
   Entry { is_user_defined_entry: bool, entry_module_id: &'a str, name: Option<&'a ArcStr> },
   CommonChunk { bits: &'a BitSet, link_output: &'a LinkStageOutput },
+  MergedSmallChunk { bits: &'a BitSet, size: f64, link_output: &'a LinkStageOutput },
 }
 
 impl ChunkDebugExt for Chunk {
@@ -53,22 +54,33 @@ impl ChunkDebugExt for Chunk {
       }
       ChunkCreationReason::CommonChunk { bits, link_output } => {
         self.chunk_reason_type = Box::new(ChunkReasonType::Common);
-        let entries = link_output
-          .entries
-          .iter()
-          .enumerate()
-          .filter_map(|(index, entry_point)| {
-            if bits.has_bit(index.try_into().unwrap()) {
-              let entry_module = &link_output.module_table[entry_point.idx];
-              Some(entry_module.stable_id().to_string())
-            } else {
-              None
-            }
-          })
-          .join(", ");
-        format!("Common Chunk: [Shared-By: {entries}]")
+        format!("Common Chunk: [Shared-By: {}]", shared_by_entries(bits, link_output))
+      }
+      ChunkCreationReason::MergedSmallChunk { bits, size, link_output } => {
+        // A small common chunk merged into this chunk by `experimental.minChunkSize`. The chunk keeps
+        // the reason type it was created with.
+        format!(
+          "Merged Small Chunk: [Shared-By: {}] [Size: {size}]",
+          shared_by_entries(bits, link_output)
+        )
       }
     };
     self.create_reasons.push(reason);
   }
 }
+
+fn shared_by_entries(bits: &BitSet, link_output: &LinkStageOutput) -> String {
+  link_output
+    .entries
+    .iter()
+    .enumerate()
+    .filter_map(|(index, entry_point)| {
+      if bits.has_bit(index.try_into().unwrap()) {
+        let entry_module = &link_output.module_table[entry_point.idx];
+        Some(entry_module.stable_id().to_string())
+      } else {
+        None
+      }
+    })
+    .join(", ")
+}
@@ -2,8 +2,10 @@ use std::collections::VecDeque;
 
 use arcstr::ArcStr;
 use itertools::Itertools;
+use oxc_index::IndexVec;
 use rolldown_common::{
-  Chunk, ChunkIdx, ChunkKind, ChunkMeta, Module, ModuleIdx, ModuleTable, PreserveEntrySignatures,
+  Chunk, ChunkIdx, ChunkKind, ChunkMeta, ChunkSizeMetric, Module, ModuleIdx, ModuleTable,
+  PreserveEntrySignatures,
 };
 use rolldown_utils::{BitSet, indexmap::FxIndexMap};
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -12,6 +14,21 @@ use crate::chunk_graph::ChunkGraph;
 
 use super::{GenerateStage, chunk_ext::ChunkCreationReason, chunk_ext::ChunkDebugExt};
 
+/// A pending common chunk that was merged into another one by `experimental.minChunkSize`.
+pub(super) struct MergedSmallChunk {
+  bits: BitSet,
+  size: f64,
+  /// Any module of the chunk it was merged into. Used to find the chunk once it's created.
+  target_module: ModuleIdx,
+}
+
+struct PendingCommonChunk {
+  bits: BitSet,
+  modules: Vec<ModuleIdx>,
+  size: f64,
+  has_side_effects: bool,
+}
+
 impl GenerateStage<'_> {
   /// Constructs a mapping from static entry chunks to the dynamic entry chunks they can reach.
   ///
@@ -323,4 +340,185 @@ impl GenerateStage<'_> {
       can_merge.then_some(*chunk_idx)
     })
   }
+
+  /// Merges pending common chunks that are smaller than `experimental.minChunkSize` into the
+  /// smallest pending common chunk they are compatible with. Two chunks are compatible if:
+  /// 1. Entries that didn't load a chunk with side effects still don't run them after the merge.
+  /// 2. Each entry that loaded only one of them loads at most `tolerance` bytes of extra code.
+  /// 3. Neither chunk imports the other through a third chunk. Otherwise the merged chunk and the
+  ///    third chunk would import each other, which changes the execution order.
+  pub(super) fn merge_small_common_chunks(
+    &self,
+    chunk_graph: &ChunkGraph,
+    pending_common_chunks: &mut FxIndexMap<BitSet, Vec<ModuleIdx>>,
+  ) -> Vec<MergedSmallChunk> {
+    let Some(min_chunk_size) = self.options.experimental.min_chunk_size else {
+      return vec![];
+    };
+    let tolerance = min_chunk_size.tolerance.unwrap_or(min_chunk_size.min_size);
+    let module_sizes = self.compute_module_sizes(ChunkSizeMetric::Rendered);
+    let module_table = &self.link_output.module_table;
+
+    let mut chunks = pending_common_chunks
+      .drain(..)
+      .map(|(bits, modules)| PendingCommonChunk {
+        size: modules.iter().map(|module_idx| module_sizes[*module_idx]).sum(),
+        has_side_effects: modules
+          .iter()
+          .any(|module_idx| module_table[*module_idx].side_effects().has_side_effects()),
+        bits,
+        modules,
+      })
+      .map(Some)
+      .collect_vec();
+
+    // Pending chunks and existing chunks are both nodes of the chunk dependency graph. Pending
+    // chunk `i` is node `i`, existing chunk `idx` is node `chunks.len() + idx`.
+    let mut module_to_node: IndexVec<ModuleIdx, Option<usize>> = chunk_graph
+      .module_to_chunk
+      .iter()
+      .map(|chunk_idx| chunk_idx.map(|chunk_idx| chunks.len() + chunk_idx.index()))
+      .collect();
+    for (node, chunk) in chunks.iter().enumerate() {
+      for module_idx in &chunk.as_ref().expect("not merged yet").modules {
+        module_to_node[*module_idx] = Some(node);
+      }
+    }
+    let mut dependencies = vec![FxHashSet::default(); chunks.len() + chunk_graph.chunk_table.len()];
+    for (module_idx, node) in module_to_node.iter_enumerated() {
+      let Some(node) = *node else {
+        continue;
+      };
+      for dep in &self.link_output.metas[module_idx].dependencies {
+        if let Some(dep_node) = module_to_node[*dep]
+          && dep_node != node
+        {
+          dependencies[node].insert(dep_node);
+        }
+      }
+    }
+
+    let mut merged_small_chunks = vec![];
+    let visit_order = (0..chunks.len())
+      .sorted_by(|a, b| {
+        let size = |idx: usize| chunks[idx].as_ref().map_or(0.0, |chunk| chunk.size);
+        size(*a).total_cmp(&size(*b)).then_with(|| a.cmp(b))
+      })
+      .collect_vec();
+    for small in visit_order {
+      let Some(small_chunk) = &chunks[small] else {
+        continue;
+      };
+      if small_chunk.size >= min_chunk_size.min_size {
+        continue;
+      }
+
+      let target = chunks
+        .iter()
+        .enumerate()
+        .filter_map(|(target, chunk)| Some((target, chunk.as_ref()?)))
+        .filter(|(target, target_chunk)| {
+          *target != small
+            && can_merge_pending_chunks(small_chunk, target_chunk, tolerance)
+            && !has_indirect_dependency(&dependencies, small, *target)
+            && !has_indirect_dependency(&dependencies, *target, small)
+        })
+        .filter(|(target, target_chunk)| {
+          // Keep bits of pending chunks unique, so they stay separate chunks.
+          let mut bits = target_chunk.bits.clone();
+          bits.union(&small_chunk.bits);
+          !chunks.iter().enumerate().any(|(other, chunk)| {
+            other != small
+              && other != *target
+              && chunk.as_ref().is_some_and(|chunk| chunk.bits == bits)
+          })
+        })
+        .min_by(|(a, a_chunk), (b, b_chunk)| a_chunk.size.total_cmp(&b_chunk.size).then(a.cmp(b)))
+        .map(|(target, _)| target);
+      let Some(target) = target else {
+        continue;
+      };
+
+      let small_chunk = chunks[small].take().expect("checked above");
+      let target_chunk = chunks[target].as_mut().expect("checked above");
+      target_chunk.bits.union(&small_chunk.bits);
+      target_chunk.size += small_chunk.size;
+      target_chunk.has_side_effects |= small_chunk.has_side_effects;
+      target_chunk.modules.extend(small_chunk.modules);
+      merged_small_chunks.push(MergedSmallChunk {
+        bits: small_chunk.bits,
+        size: small_chunk.size,
+        target_module: target_chunk.modules[0],
+      });
+
+      let small_dependencies = std::mem::take(&mut dependencies[small]);
+      dependencies[target].extend(small_dependencies);
+      for deps in &mut dependencies {
+        if deps.remove(&small) {
+          deps.insert(target);
+        }
+      }
+      dependencies[target].remove(&target);
+    }
+
+    for chunk in chunks.into_iter().flatten() {
+      pending_common_chunks.entry(chunk.bits).or_default().extend(chunk.modules);
+    }
+    merged_small_chunks
+  }
+
+  /// Records merges made by `merge_small_common_chunks` on the chunks that the modules ended up in.
+  pub(super) fn add_merged_small_chunk_reasons(
+    &self,
+    chunk_graph: &mut ChunkGraph,
+    merged_small_chunks: Vec<MergedSmallChunk>,
+  ) {
+    for merged in merged_small_chunks {
+      let Some(chunk_idx) = chunk_graph.module_to_chunk[merged.target_module] else {
+        continue;
+      };
+      chunk_graph.chunk_table[chunk_idx].add_creation_reason(
+        ChunkCreationReason::MergedSmallChunk {
+          bits: &merged.bits,
+          size: merged.size,
+          link_output: self.link_output,
+        },
+        self.options,
+      );
+    }
+  }
+}
+
+fn can_merge_pending_chunks(
+  small: &PendingCommonChunk,
+  target: &PendingCommonChunk,
+  tolerance: f64,
+) -> bool {
+  let small_loaders_load_target = small.bits.is_subset_of(&target.bits);
+  let target_loaders_load_small = target.bits.is_subset_of(&small.bits);
+  if (small.has_side_effects && !target_loaders_load_small)
+    || (target.has_side_effects && !small_loaders_load_target)
+  {
+    return false;
+  }
+  (target_loaders_load_small || small.size <= tolerance)
+    && (small_loaders_load_target || target.size <= tolerance)
+}
+
+/// Whether `from` depends on `to` through another node.
+fn has_indirect_dependency(dependencies: &[FxHashSet<usize>], from: usize, to: usize) -> bool {
+  let mut visited = FxHashSet::from_iter([from, to]);
+  let mut queue = dependencies[from].iter().copied().filter(|node| *node != to).collect_vec();
+  while let Some(node) = queue.pop() {
+    if !visited.insert(node) {
+      continue;
+    }
+    for dep in &dependencies[node] {
+      if *dep == to {
+        return true;
+      }
+      queue.push(*dep);
+    }
+  }
+  false
 }
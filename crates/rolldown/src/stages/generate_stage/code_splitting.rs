@@ -9,7 +9,7 @@ use itertools::Itertools;
 use oxc_index::{IndexVec, index_vec};
 use rolldown_common::{
   Chunk, ChunkIdx, ChunkKind, ChunkMeta, ExportsKind, ImportKind, ImportRecordIdx,
-  ImportRecordMeta, IndexModules, Module, ModuleIdx, ModuleNamespaceIncludedReason,
+  ImportRecordMeta, IndexModules, Module, ModuleIdx, ModuleNamespaceIncludedReason, OutputFormat,
   PreserveEntrySignatures, SymbolRef, WrapKind,
 };
 use rolldown_error::BuildResult;
@@ -733,6 +733,19 @@ impl GenerateStage<'_> {
     let mut module_to_assigned: IndexVec<ModuleIdx, bool> =
       oxc_index::index_vec![false; self.link_output.module_table.modules.len()];
 
+    // Formats that emit a single chunk keep the runtime inlined.
+    if self.options.experimental.is_standalone_runtime_chunk_enabled()
+      && !self.options.inline_dynamic_imports
+      && !matches!(self.options.format, OutputFormat::Iife | OutputFormat::Umd)
+    {
+      self.create_runtime_chunk(
+        index_splitting_info,
+        &mut module_to_assigned,
+        chunk_graph,
+        input_base,
+      );
+    }
+
     self
       .apply_advanced_chunks(index_splitting_info, &mut module_to_assigned, chunk_graph, input_base)
       .await?;
@@ -741,8 +754,25 @@ impl GenerateStage<'_> {
     // If it is allow to allow that entry chunks have the different exports as the underlying entry module.
     // This is used to generate less chunks when possible.
     // TODO: maybe we could bailout peer chunk?
-    let allow_optimize_chunk =
+    let mut allow_optimize_chunk =
       !self.link_output.metas.iter().any(|meta| meta.is_tla_or_contains_tla_dependency);
+
+    // With `experimental.preserveDirectives`, modules only share a chunk if they have the same
+    // directives, so chunks are keyed by their directives as well.
+    let runtime_idx = self.link_output.runtime.id();
+    let mut directive_chunks: FxHashMap<(BitSet, Vec<&str>), ChunkIdx> = FxHashMap::default();
+    let split_by_directives = self.splits_by_directives();
+    if split_by_directives {
+      // Merging common chunks would mix modules with different directives.
+      allow_optimize_chunk = false;
+      for (bits, chunk_idx) in bits_to_chunk.iter() {
+        let directives = match chunk_graph.chunk_table[*chunk_idx].kind {
+          ChunkKind::EntryPoint { module, .. } => self.normalized_directives_of(module),
+          ChunkKind::Common => vec![],
+        };
+        directive_chunks.insert((bits.clone(), directives), *chunk_idx);
+      }
+    }
     // 1. Assign modules to corresponding chunks
     // 2. Create shared chunks to store modules that belong to multiple chunks.
     for idx in &self.link_output.sorted_modules {
@@ -765,7 +795,16 @@ impl GenerateStage<'_> {
         "Empty bits means the module is not reachable, so it should bail out with `is_included: false` {:?}",
         normal_module.stable_id
       );
-      if let Some(chunk_id) = bits_to_chunk.get(bits).copied() {
+      let directives = if split_by_directives && normal_module.idx != runtime_idx {
+        Some(self.normalized_directives_of(normal_module.idx))
+      } else {
+        None
+      };
+      let existing_chunk = match &directives {
+        Some(directives) => directive_chunks.get(&(bits.clone(), directives.clone())).copied(),
+        None => bits_to_chunk.get(bits).copied(),
+      };
+      if let Some(chunk_id) = existing_chunk {
         chunk_graph.add_module_to_chunk(
           normal_module.idx,
           chunk_id,
@@ -786,21 +825,56 @@ impl GenerateStage<'_> {
           chunk_id,
           self.link_output.metas[normal_module.idx].depended_runtime_helper,
         );
-        bits_to_chunk.insert(bits.clone(), chunk_id);
+        if let Some(directives) = directives {
+          directive_chunks.insert((bits.clone(), directives), chunk_id);
+          bits_to_chunk.entry(bits.clone()).or_insert(chunk_id);
+        } else {
+          bits_to_chunk.insert(bits.clone(), chunk_id);
+        }
       }
     }
 
     if allow_optimize_chunk {
+      let merged_small_chunks =
+        self.merge_small_common_chunks(chunk_graph, &mut pending_common_chunks);
       self.try_insert_common_module_to_exist_chunk(
         chunk_graph,
         bits_to_chunk,
         input_base,
         pending_common_chunks,
       );
+      self.add_merged_small_chunk_reasons(chunk_graph, merged_small_chunks);
     }
     Ok(())
   }
 
+  /// Whether modules with different directives must be put into different chunks, see
+  /// `experimental.preserveDirectives`.
+  pub(super) fn splits_by_directives(&self) -> bool {
+    self.options.experimental.is_preserve_directives_enabled()
+      && !self.options.preserve_modules
+      && self
+        .link_output
+        .module_table
+        .modules
+        .iter()
+        .filter_map(Module::as_normal)
+        .any(|module| module.is_included() && !module.custom_directives().is_empty())
+  }
+
+  /// The custom directives of a module without quotes, such as `use client`.
+  pub(super) fn normalized_directives_of(&self, module_idx: ModuleIdx) -> Vec<&str> {
+    self.link_output.module_table[module_idx].as_normal().map_or_else(Vec::new, |module| {
+      module
+        .custom_directives()
+        .into_iter()
+        .map(|directive| {
+          directive.trim_start_matches(['\'', '"']).trim_end_matches(['\'', '"', ';'])
+        })
+        .collect()
+    })
+  }
+
   fn determine_reachable_modules_for_entry(
     &self,
     entry_module_idx: ModuleIdx,
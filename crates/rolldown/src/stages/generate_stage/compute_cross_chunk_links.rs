@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::cmp::Reverse;
 
 use super::GenerateStage;
+use super::name_cache::{chunk_key, symbol_key};
 use crate::chunk_graph::ChunkGraph;
 use crate::utils::chunk::normalize_preserve_entry_signature;
 use itertools::{Itertools, multizip};
@@ -146,6 +147,13 @@ impl GenerateStage<'_> {
         chunk.import_symbol_from_external_modules = chunk_indirect_imports_from_external_modules;
       },
     );
+
+    if self.options.experimental.is_preload_dynamic_imports_enabled()
+      && self.options.format.is_esm()
+      && !self.options.inline_dynamic_imports
+    {
+      compute_dynamic_import_preloads(chunk_graph);
+    }
   }
 
   /// - Assign each symbol to the chunk it belongs to
@@ -494,6 +502,10 @@ impl GenerateStage<'_> {
         // Reference: https://github.com/rollup/rollup/blob/f76339428586620ff3e4c32fce48f923e7be7b05/src/utils/exportNames.ts#L5
         let mut named_index = 0;
         let mut used_names = FxHashSet::default();
+        let cached_names = self.name_cache.as_ref().and_then(|cache| {
+          cache.internal_exports.get(&chunk_key(chunk, &self.link_output.module_table)?)
+        });
+        let mut pending_exports = vec![];
 
         let mut processed_entry_exports = FxHashSet::default();
         if let Some(entry_module_idx) = chunk.entry_module_idx() {
@@ -528,7 +540,21 @@ impl GenerateStage<'_> {
           if processed_entry_exports.contains(&export_ref) {
             continue;
           }
-
+          // Reuse the name from the previous build, so the chunks importing it don't change either.
+          let cached_name = cached_names.and_then(|names| {
+            let key =
+              symbol_key(export_ref, &self.link_output.module_table, &self.link_output.symbol_db);
+            names.get(&key).map(|name| CompactStr::new(name))
+          });
+          if let Some(name) = cached_name
+            && used_names.insert(name.clone())
+          {
+            chunk.exports_to_other_chunks.entry(export_ref).or_default().push(name);
+            continue;
+          }
+          pending_exports.push(export_ref);
+        }
+        for export_ref in pending_exports {
           let mut export_name: CompactStr;
           loop {
             named_index += 1;
@@ -612,7 +638,7 @@ const REST_BASE: u32 = 64;
 const FREQUENT_CHARS: &[u8; REST_BASE as usize] =
   b"etnriaoscludfpmhg_vybxSCwTEDOkAjMNPFILRzBVHUWGKqJYXZQ$1024368579";
 
-fn generate_minified_names(mut value: u32) -> String {
+pub(super) fn generate_minified_names(mut value: u32) -> String {
   let mut buffer = vec![];
 
   // Base 54 at first because these are the usable first characters in JavaScript identifiers
@@ -628,3 +654,48 @@ fn generate_minified_names(mut value: u32) -> String {
   // SAFETY: `buffer` is base64 characters, it is valid utf8 characters
   unsafe { String::from_utf8_unchecked(buffer) }
 }
+
+/// Fills `Chunk#dynamic_import_preloads` from the imports between chunks computed above.
+fn compute_dynamic_import_preloads(chunk_graph: &mut ChunkGraph) {
+  let static_import_closures = chunk_graph
+    .chunk_table
+    .iter_enumerated()
+    .map(|(chunk_idx, _)| {
+      let mut closure = FxIndexSet::default();
+      let mut stack = vec![chunk_idx];
+      while let Some(cur) = stack.pop() {
+        for &importee in &chunk_graph.chunk_table[cur].cross_chunk_imports {
+          if importee != chunk_idx && closure.insert(importee) {
+            stack.push(importee);
+          }
+        }
+      }
+      closure
+    })
+    .collect::<IndexVec<ChunkIdx, _>>();
+
+  let index_dynamic_import_preloads = chunk_graph
+    .chunk_table
+    .iter_enumerated()
+    .map(|(chunk_idx, chunk)| {
+      chunk
+        .cross_chunk_dynamic_imports
+        .iter()
+        .map(|&importee| {
+          // Chunks that the importer imports statically are loaded already.
+          let preloads = std::iter::once(importee)
+            .chain(static_import_closures[importee].iter().copied())
+            .filter(|dep| *dep != chunk_idx && !static_import_closures[chunk_idx].contains(dep))
+            .collect_vec();
+          (importee, preloads)
+        })
+        .collect::<FxHashMap<_, _>>()
+    })
+    .collect_vec();
+
+  for (chunk, dynamic_import_preloads) in
+    chunk_graph.chunk_table.iter_mut().zip(index_dynamic_import_preloads)
+  {
+    chunk.dynamic_import_preloads = dynamic_import_preloads;
+  }
+}
@@ -0,0 +1,369 @@
+use std::path::Path;
+
+use anyhow::Context;
+use oxc::{
+  allocator::{Allocator, CloneIn, IntoIn},
+  ast::{
+    AstBuilder,
+    ast::{self, Expression, PropertyKey},
+  },
+  ast_visit::{Visit, VisitMut, walk, walk_mut},
+  span::{Atom, CompactStr, SPAN},
+  syntax::operator::BinaryOperator,
+};
+use rolldown_common::{ManglePropsOptions, ModuleIdx};
+use rolldown_error::BuildResult;
+use rolldown_fs::FileSystem;
+use rolldown_utils::{
+  index_vec_ext::{IndexVecExt, IndexVecRefExt},
+  indexmap::FxIndexMap,
+  rayon::ParallelIterator,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::{Map, Value};
+
+use super::{GenerateStage, compute_cross_chunk_links::generate_minified_names};
+
+/// Property name -> mangled name, or `None` if it must be kept.
+type ManglePropsCache = FxIndexMap<CompactStr, Option<CompactStr>>;
+
+impl GenerateStage<'_> {
+  /// Renames the properties selected by `experimental.mangleProps` in the finalized modules. Names
+  /// are picked for the whole bundle at once, so a property gets the same name in every chunk.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn mangle_props(&mut self) -> BuildResult<()> {
+    let Some(options) = &self.options.experimental.mangle_props else {
+      return Ok(());
+    };
+    let cache_path = options.cache.as_ref().map(|path| self.options.cwd.join(path));
+    let mut cache = match &cache_path {
+      Some(path) => read_cache(self.fs, path)?,
+      None => ManglePropsCache::default(),
+    };
+    let runtime_idx = self.link_output.runtime.id();
+    let module_table = &self.link_output.module_table;
+    let is_included =
+      |idx: ModuleIdx| module_table[idx].as_normal().is_some_and(|m| m.meta.is_included());
+
+    let collectors = self
+      .link_output
+      .ast_table
+      .par_iter_enumerated()
+      .filter(|(idx, _)| is_included(*idx))
+      .filter_map(|(idx, ast)| Some((idx, ast.as_ref()?)))
+      .map(|(idx, ast)| {
+        let mut collector = PropertyCollector {
+          options,
+          counts: FxHashMap::default(),
+          unmangled: FxHashSet::default(),
+        };
+        collector.visit_program(ast.program());
+        (idx, collector)
+      })
+      .collect::<Vec<_>>();
+    let mut counts = FxHashMap::default();
+    let mut unmangled = FxHashSet::default();
+    for (idx, collector) in collectors {
+      // The runtime is never renamed, so the properties it uses are kept, and mangled names must
+      // not collide with them.
+      if idx == runtime_idx {
+        unmangled.extend(collector.counts.into_keys());
+      } else {
+        for (name, count) in collector.counts {
+          *counts.entry(name).or_insert(0) += count;
+        }
+      }
+      unmangled.extend(collector.unmangled);
+    }
+
+    let renames = assign_names(counts, &unmangled, &mut cache);
+    if !renames.is_empty() {
+      self
+        .link_output
+        .ast_table
+        .par_iter_mut_enumerated()
+        .filter(|(idx, _)| *idx != runtime_idx && is_included(*idx))
+        .filter_map(|(_, ast)| ast.as_mut())
+        .for_each(|ast| {
+          ast.program.with_mut(|fields| {
+            let mut renamer = PropertyRenamer {
+              quoted: options.is_quoted_enabled(),
+              renames: &renames,
+              alloc: fields.allocator,
+              builder: AstBuilder::new(fields.allocator),
+            };
+            renamer.visit_program(fields.program);
+          });
+        });
+    }
+
+    if let Some(path) = &cache_path {
+      write_cache(self.fs, path, &cache)?;
+    }
+    Ok(())
+  }
+}
+
+/// Picks a name for each property in `counts`, reusing the names from `cache` and giving the
+/// shortest names to the most used properties. Properties in `unmangled` are also accessed with a
+/// quoted name somewhere, so they keep their name everywhere. `cache` is updated with the new names.
+fn assign_names(
+  counts: FxHashMap<CompactStr, u32>,
+  unmangled: &FxHashSet<CompactStr>,
+  cache: &mut ManglePropsCache,
+) -> FxHashMap<CompactStr, CompactStr> {
+  let mut used_names = unmangled.clone();
+  used_names.extend(cache.iter().filter_map(|(name, mangled)| match mangled {
+    Some(mangled) if !unmangled.contains(mangled) => Some(mangled.clone()),
+    // A property that must be kept can't be used as a new name either.
+    None => Some(name.clone()),
+    Some(_) => None,
+  }));
+
+  let mut renames = FxHashMap::default();
+  let mut pending = vec![];
+  for (name, count) in counts {
+    if unmangled.contains(&name) {
+      continue;
+    }
+    match cache.get(&name) {
+      Some(None) => {}
+      Some(Some(mangled)) if !unmangled.contains(mangled) => {
+        renames.insert(name, mangled.clone());
+      }
+      _ => pending.push((name, count)),
+    }
+  }
+  pending.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+  let mut named_index = 0;
+  for (name, _) in pending {
+    let mangled = loop {
+      let mangled = CompactStr::from(generate_minified_names(named_index));
+      named_index += 1;
+      if !used_names.contains(&mangled) {
+        break mangled;
+      }
+    };
+    used_names.insert(mangled.clone());
+    cache.insert(name.clone(), Some(mangled.clone()));
+    renames.insert(name, mangled);
+  }
+  renames
+}
+
+fn read_cache(fs: &impl FileSystem, path: &Path) -> anyhow::Result<ManglePropsCache> {
+  let content = match fs.read_to_string(path) {
+    Ok(content) => content,
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+      return Ok(ManglePropsCache::default());
+    }
+    Err(err) => {
+      return Err(err)
+        .with_context(|| format!("Failed to read mangle props cache {}", path.display()));
+    }
+  };
+  let Value::Object(entries) = serde_json::from_str(&content)
+    .with_context(|| format!("Failed to parse mangle props cache {}", path.display()))?
+  else {
+    anyhow::bail!("Mangle props cache {} should contain an object", path.display());
+  };
+  entries
+    .into_iter()
+    .map(|(name, mangled)| match mangled {
+      Value::String(mangled) => Ok((name.into(), Some(mangled.into()))),
+      Value::Bool(false) => Ok((name.into(), None)),
+      _ => anyhow::bail!(
+        "Invalid value for `{name}` in mangle props cache {}. Expected a string or `false`",
+        path.display()
+      ),
+    })
+    .collect()
+}
+
+fn write_cache(fs: &impl FileSystem, path: &Path, cache: &ManglePropsCache) -> anyhow::Result<()> {
+  let mut entries = cache.iter().collect::<Vec<_>>();
+  entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+  let value = entries
+    .into_iter()
+    .map(|(name, mangled)| {
+      let mangled = mangled.as_ref().map_or(Value::Bool(false), |m| Value::String(m.to_string()));
+      (name.to_string(), mangled)
+    })
+    .collect::<Map<_, _>>();
+  let mut content = serde_json::to_string_pretty(&Value::Object(value))?;
+  content.push('\n');
+  fs.write_atomically(path, content.as_bytes())
+    .with_context(|| format!("Failed to write mangle props cache {}", path.display()))
+}
+
+struct PropertyCollector<'a> {
+  options: &'a ManglePropsOptions,
+  counts: FxHashMap<CompactStr, u32>,
+  /// Property names that are kept as is, which mangled names must not collide with.
+  unmangled: FxHashSet<CompactStr>,
+}
+
+impl PropertyCollector<'_> {
+  fn add(&mut self, name: &str, quoted: bool) {
+    if (!quoted || self.options.is_quoted_enabled()) && self.options.should_mangle(name) {
+      *self.counts.entry(name.into()).or_insert(0) += 1;
+    } else if !self.unmangled.contains(name) {
+      self.unmangled.insert(name.into());
+    }
+  }
+}
+
+impl<'ast> Visit<'ast> for PropertyCollector<'_> {
+  fn visit_property_key(&mut self, key: &PropertyKey<'ast>) {
+    match key {
+      PropertyKey::StaticIdentifier(ident) => self.add(&ident.name, false),
+      PropertyKey::StringLiteral(lit) => self.add(&lit.value, true),
+      _ => walk::walk_property_key(self, key),
+    }
+  }
+
+  fn visit_static_member_expression(&mut self, expr: &ast::StaticMemberExpression<'ast>) {
+    self.add(&expr.property.name, false);
+    walk::walk_static_member_expression(self, expr);
+  }
+
+  fn visit_computed_member_expression(&mut self, expr: &ast::ComputedMemberExpression<'ast>) {
+    if let Expression::StringLiteral(lit) = &expr.expression {
+      self.add(&lit.value, true);
+    }
+    walk::walk_computed_member_expression(self, expr);
+  }
+
+  fn visit_assignment_target_property_identifier(
+    &mut self,
+    prop: &ast::AssignmentTargetPropertyIdentifier<'ast>,
+  ) {
+    self.add(&prop.binding.name, false);
+    walk::walk_assignment_target_property_identifier(self, prop);
+  }
+
+  fn visit_binary_expression(&mut self, expr: &ast::BinaryExpression<'ast>) {
+    // `'_x' in obj`
+    if expr.operator == BinaryOperator::In
+      && let Expression::StringLiteral(lit) = &expr.left
+    {
+      self.add(&lit.value, true);
+    }
+    walk::walk_binary_expression(self, expr);
+  }
+}
+
+struct PropertyRenamer<'a, 'ast> {
+  quoted: bool,
+  renames: &'a FxHashMap<CompactStr, CompactStr>,
+  alloc: &'ast Allocator,
+  builder: AstBuilder<'ast>,
+}
+
+impl<'ast> PropertyRenamer<'_, 'ast> {
+  fn rename(&self, name: &str) -> Option<Atom<'ast>> {
+    self.renames.get(name).map(|mangled| self.builder.atom(mangled))
+  }
+
+  fn rename_string_literal(&self, lit: &mut ast::StringLiteral<'ast>) {
+    if self.quoted
+      && let Some(mangled) = self.rename(&lit.value)
+    {
+      lit.value = mangled;
+      lit.raw = None;
+    }
+  }
+
+  fn is_renamed_key(&self, key: &PropertyKey<'ast>) -> bool {
+    matches!(key, PropertyKey::StaticIdentifier(ident) if self.renames.contains_key(ident.name.as_str()))
+  }
+}
+
+impl<'ast> VisitMut<'ast> for PropertyRenamer<'_, 'ast> {
+  fn visit_property_key(&mut self, key: &mut PropertyKey<'ast>) {
+    match key {
+      PropertyKey::StaticIdentifier(ident) => {
+        if let Some(mangled) = self.rename(&ident.name) {
+          ident.name = mangled;
+        }
+      }
+      PropertyKey::StringLiteral(lit) => self.rename_string_literal(lit),
+      _ => walk_mut::walk_property_key(self, key),
+    }
+  }
+
+  fn visit_object_property(&mut self, prop: &mut ast::ObjectProperty<'ast>) {
+    // `{ _x }` -> `{ a: _x }`
+    if prop.shorthand && self.is_renamed_key(&prop.key) {
+      prop.shorthand = false;
+    }
+    walk_mut::walk_object_property(self, prop);
+  }
+
+  fn visit_binding_property(&mut self, prop: &mut ast::BindingProperty<'ast>) {
+    // `const { _x } = obj` -> `const { a: _x } = obj`
+    if prop.shorthand && self.is_renamed_key(&prop.key) {
+      prop.shorthand = false;
+    }
+    walk_mut::walk_binding_property(self, prop);
+  }
+
+  fn visit_assignment_target_property(
+    &mut self,
+    property: &mut ast::AssignmentTargetProperty<'ast>,
+  ) {
+    // `({ _x } = obj)` -> `({ a: _x } = obj)`
+    if let ast::AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = property
+      && let Some(mangled) = self.rename(&prop.binding.name)
+    {
+      let target = ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(
+        prop.binding.clone_in(self.alloc).into_in(self.alloc),
+      );
+      *property = ast::AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+        ast::AssignmentTargetPropertyProperty {
+          name: PropertyKey::StaticIdentifier(self.builder.alloc_identifier_name(SPAN, mangled)),
+          binding: if let Some(init) = prop.init.take() {
+            ast::AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+              ast::AssignmentTargetWithDefault {
+                binding: ast::AssignmentTarget::from(target),
+                init,
+                span: SPAN,
+              }
+              .into_in(self.alloc),
+            )
+          } else {
+            ast::AssignmentTargetMaybeDefault::from(target)
+          },
+          span: prop.span,
+          computed: false,
+        }
+        .into_in(self.alloc),
+      );
+    }
+    walk_mut::walk_assignment_target_property(self, property);
+  }
+
+  fn visit_static_member_expression(&mut self, expr: &mut ast::StaticMemberExpression<'ast>) {
+    if let Some(mangled) = self.rename(&expr.property.name) {
+      expr.property.name = mangled;
+    }
+    walk_mut::walk_static_member_expression(self, expr);
+  }
+
+  fn visit_computed_member_expression(&mut self, expr: &mut ast::ComputedMemberExpression<'ast>) {
+    if let Expression::StringLiteral(lit) = &mut expr.expression {
+      self.rename_string_literal(lit);
+    }
+    walk_mut::walk_computed_member_expression(self, expr);
+  }
+
+  fn visit_binary_expression(&mut self, expr: &mut ast::BinaryExpression<'ast>) {
+    if expr.operator == BinaryOperator::In
+      && let Expression::StringLiteral(lit) = &mut expr.left
+    {
+      self.rename_string_literal(lit);
+    }
+    walk_mut::walk_binary_expression(self, expr);
+  }
+}
@@ -3,24 +3,35 @@ use oxc::{
   minifier::{CompressOptions, MinifierOptions, TreeShakeOptions},
 };
 use oxc_allocator::AllocatorPool;
-use rolldown_common::{LegalComments, MinifyOptions, NormalizedBundlerOptions};
+use rolldown_common::{ChunkIdx, LegalComments, MinifyOptions, NormalizedBundlerOptions};
 use rolldown_ecmascript::EcmaCompiler;
 use rolldown_error::BuildResult;
 use rolldown_sourcemap::collapse_sourcemaps;
 use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+use rustc_hash::FxHashMap;
 
 use crate::type_alias::IndexInstantiatedChunks;
 
-use super::GenerateStage;
+use super::{
+  GenerateStage,
+  name_cache::{NameCache, rename_top_level_symbols},
+};
+
+/// The top-level names picked for each chunk, keyed by the chunk key of `experimental.nameCache`.
+pub type MangledTopLevelNames = FxHashMap<String, FxHashMap<String, String>>;
 
 impl GenerateStage<'_> {
+  /// With `name_cache`, top-level names are picked from the cache instead of by the mangler, and
+  /// the names used are returned for `chunk_keys` so they can be written back.
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn minify_chunks(
     options: &NormalizedBundlerOptions,
     chunks: &mut IndexInstantiatedChunks,
-  ) -> BuildResult<()> {
+    name_cache: Option<&NameCache>,
+    chunk_keys: &FxHashMap<ChunkIdx, String>,
+  ) -> BuildResult<MangledTopLevelNames> {
     let (compress, minify_option, remove_whitespace) = match &options.minify {
-      MinifyOptions::Disabled => return Ok(()),
+      MinifyOptions::Disabled => return Ok(MangledTopLevelNames::default()),
       MinifyOptions::DeadCodeEliminationOnly => (
         false,
         &MinifierOptions {
@@ -34,60 +45,88 @@ impl GenerateStage<'_> {
       ),
       MinifyOptions::Enabled((options, remove_whitespace)) => (true, options, *remove_whitespace),
     };
+    // The mangler only renames top-level symbols with `topLevel`, so there is nothing to cache
+    // without it.
+    let name_cache = name_cache
+      .filter(|_| minify_option.mangle.as_ref().is_some_and(|mangle| mangle.top_level) && compress);
     let allocator_pool = AllocatorPool::new(rayon::current_num_threads());
-    chunks.par_iter_mut().try_for_each(|chunk| -> anyhow::Result<()> {
-      if test_d_ts_pattern(chunk.preliminary_filename.as_str()) {
-        return Ok(());
-      }
-      match chunk.kind {
-        rolldown_common::InstantiationKind::Ecma(_) => {
-          let codegen_options = CodegenOptions {
-            minify: remove_whitespace,
-            comments: CommentOptions {
-              normal: false,
-              jsdoc: false,
-              annotation: !remove_whitespace,
-              legal: if matches!(options.legal_comments, LegalComments::Inline)
-                || !remove_whitespace
-              {
-                codegen::LegalComment::Inline
-              } else {
-                codegen::LegalComment::None
+    let mangled_names = chunks
+      .par_iter_mut()
+      .map(|chunk| -> anyhow::Result<Option<(String, FxHashMap<String, String>)>> {
+        if test_d_ts_pattern(chunk.preliminary_filename.as_str()) {
+          return Ok(None);
+        }
+        let mut mangled_names = None;
+        match chunk.kind {
+          rolldown_common::InstantiationKind::Ecma(_) => {
+            let codegen_options = CodegenOptions {
+              minify: remove_whitespace,
+              comments: CommentOptions {
+                normal: false,
+                jsdoc: false,
+                annotation: !remove_whitespace,
+                legal: if matches!(options.legal_comments, LegalComments::Inline)
+                  || !remove_whitespace
+                {
+                  codegen::LegalComment::Inline
+                } else {
+                  codegen::LegalComment::None
+                },
               },
-            },
-            ..CodegenOptions::default()
-          };
+              ..CodegenOptions::default()
+            };
 
-          let allocator_guard = allocator_pool.get();
-          // TODO: Do we need to ensure `chunk.preliminary_filename` to be absolute path?
-          let (minified_content, new_map) = EcmaCompiler::dce_or_minify(
-            &allocator_guard,
-            chunk.content.try_as_inner_str()?,
-            options.format.source_type().with_jsx(true),
-            chunk.map.is_some(),
-            chunk.preliminary_filename.as_str(),
-            compress,
-            minify_option.clone(),
-            codegen_options,
-          );
-          chunk.content = minified_content.into();
-          match (&chunk.map, &new_map) {
-            (Some(origin_map), Some(new_map)) => {
-              chunk.map = Some(collapse_sourcemaps(&[origin_map, new_map]));
-            }
-            _ => {
-              // TODO: Map is dirty. Should we reset the `chunk.map` to `None`?
+            let allocator_guard = allocator_pool.get();
+            // TODO: Do we need to ensure `chunk.preliminary_filename` to be absolute path?
+            let source_type = options.format.source_type().with_jsx(true);
+            let (minified_content, new_map) = if let Some(name_cache) = name_cache
+              && let Some(key) = chunk_keys.get(&chunk.originate_from)
+            {
+              EcmaCompiler::minify_with_top_level_renamer(
+                &allocator_guard,
+                chunk.content.try_as_inner_str()?,
+                source_type,
+                chunk.map.is_some(),
+                chunk.preliminary_filename.as_str(),
+                minify_option.clone(),
+                codegen_options,
+                |program, scoping| {
+                  let names =
+                    rename_top_level_symbols(program, scoping, name_cache.chunks.get(key));
+                  mangled_names = Some((key.clone(), names));
+                },
+              )
+            } else {
+              EcmaCompiler::dce_or_minify(
+                &allocator_guard,
+                chunk.content.try_as_inner_str()?,
+                source_type,
+                chunk.map.is_some(),
+                chunk.preliminary_filename.as_str(),
+                compress,
+                minify_option.clone(),
+                codegen_options,
+              )
+            };
+            chunk.content = minified_content.into();
+            match (&chunk.map, &new_map) {
+              (Some(origin_map), Some(new_map)) => {
+                chunk.map = Some(collapse_sourcemaps(&[origin_map, new_map]));
+              }
+              _ => {
+                // TODO: Map is dirty. Should we reset the `chunk.map` to `None`?
+              }
             }
           }
+          rolldown_common::InstantiationKind::Css(_)
+          | rolldown_common::InstantiationKind::None
+          | rolldown_common::InstantiationKind::Sourcemap(_) => {}
         }
-        rolldown_common::InstantiationKind::Css(_)
-        | rolldown_common::InstantiationKind::None
-        | rolldown_common::InstantiationKind::Sourcemap(_) => {}
-      }
-      Ok(())
-    })?;
+        Ok(mangled_names)
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
 
-    Ok(())
+    Ok(mangled_names.into_iter().flatten().collect())
   }
 }
 
@@ -10,12 +10,14 @@ use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_std_utils::OptionExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use name_cache::{NameCache, output_key};
 use rolldown_common::{
   ChunkIdx, ChunkKind, ConcatenateWrappedModuleKind, CssAssetNameReplacer, EcmaViewMeta,
   ImportMetaRolldownAssetReplacer, ImportRecordIdx, Module, ModuleIdx, OutputExports,
   PreliminaryFilename, PrependRenderedImport, RenderedConcatenatedModuleParts,
   RollupPreRenderedAsset, SymbolRef, SymbolRefFlags,
 };
+use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_std_utils::{PathBufExt, PathExt, representative_file_name_for_preserve_modules};
 use rolldown_utils::{
@@ -62,7 +64,10 @@ mod chunk_ext;
 mod chunk_optimizer;
 mod code_splitting;
 mod compute_cross_chunk_links;
+mod mangle_props;
 mod minify_chunks;
+mod module_sizes;
+mod name_cache;
 mod on_demand_wrapping;
 mod render_chunk_to_assets;
 
@@ -70,6 +75,8 @@ pub struct GenerateStage<'a> {
   link_output: &'a mut LinkStageOutput,
   options: &'a SharedOptions,
   plugin_driver: &'a SharedPluginDriver,
+  fs: &'a OsFileSystem,
+  name_cache: Option<NameCache>,
 }
 
 impl<'a> GenerateStage<'a> {
@@ -77,13 +84,18 @@ impl<'a> GenerateStage<'a> {
     link_output: &'a mut LinkStageOutput,
     options: &'a SharedOptions,
     plugin_driver: &'a SharedPluginDriver,
+    fs: &'a OsFileSystem,
   ) -> Self {
-    Self { link_output, options, plugin_driver }
+    Self { link_output, options, plugin_driver, fs, name_cache: None }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> BuildResult<BundleOutput> {
     self.plugin_driver.render_start(self.options).await?;
+    if let Some(path) = &self.options.experimental.name_cache {
+      self.name_cache =
+        Some(NameCache::read(self.fs, &self.options.cwd.join(path), &output_key(self.options))?);
+    }
     let mut chunk_graph = self.generate_chunks().await?;
 
     if chunk_graph.chunk_table.len() > 1 {
@@ -200,6 +212,7 @@ impl<'a> GenerateStage<'a> {
             options: self.options,
             file_emitter: &self.plugin_driver.file_emitter,
             constant_value_map: &self.link_output.global_constant_symbol_map,
+            ts_enum_values: &self.link_output.global_ts_enum_values,
             side_effect_free_function_symbols: &side_effect_free_function_symbols,
           };
           let mutable_state = FinalizerMutableState {
@@ -233,6 +246,7 @@ impl<'a> GenerateStage<'a> {
     });
 
     self.apply_transfer_parts_mutation(&mut chunk_graph, transfer_parts_rendered_maps);
+    self.mangle_props()?;
     self.render_chunk_to_assets(&chunk_graph).await
   }
 
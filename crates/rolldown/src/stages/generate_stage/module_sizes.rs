@@ -0,0 +1,107 @@
+use std::io::Write;
+
+use flate2::{Compression, write::GzEncoder};
+use oxc::{
+  ast::ast::Statement,
+  codegen::{Codegen, CodegenOptions, Context, Gen},
+  span::GetSpan,
+};
+use oxc_index::IndexVec;
+use rolldown_common::{ChunkSizeMetric, Module, ModuleIdx, NormalModule, StmtInfoIdx};
+use rolldown_ecmascript::EcmaAst;
+use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+
+use super::GenerateStage;
+
+/// Size of each module measured by a [`ChunkSizeMetric`].
+pub type ModuleSizes = IndexVec<ModuleIdx, f64>;
+
+impl GenerateStage<'_> {
+  #[expect(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+  pub(super) fn compute_module_sizes(&self, metric: ChunkSizeMetric) -> ModuleSizes {
+    let sizes = self
+      .link_output
+      .module_table
+      .modules
+      .raw
+      .par_iter()
+      .map(|module| {
+        let Module::Normal(module) = module else {
+          return 0.0;
+        };
+        let ast = self.link_output.ast_table[module.idx].as_ref();
+        match (metric, ast) {
+          (ChunkSizeMetric::Source, _) | (_, None) => module.size(),
+          (_, Some(_)) if !module.meta.is_included() => 0.0,
+          (ChunkSizeMetric::Rendered, Some(ast)) => rendered_size(module, ast) as f64,
+          (ChunkSizeMetric::Minified | ChunkSizeMetric::Gzip, Some(ast)) => {
+            minified_code(module, ast).len() as f64
+          }
+        }
+      })
+      .collect::<Vec<_>>();
+    let sizes = IndexVec::from_vec(sizes);
+    if matches!(metric, ChunkSizeMetric::Gzip) { self.compute_gzip_sizes(sizes) } else { sizes }
+  }
+
+  /// Compresses the minified code of all modules as one stream in execution order, and gives each
+  /// module the number of compressed bytes it adds to the stream.
+  ///
+  /// Compressing each module separately would miss the repetitions between modules, which often
+  /// make up most of the savings of gzip. Since a group mostly contains modules that are close in
+  /// execution order, its size is close to the compressed size of its modules together.
+  #[expect(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+  fn compute_gzip_sizes(&self, mut sizes: ModuleSizes) -> ModuleSizes {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for &module_idx in &self.link_output.sorted_modules {
+      let Module::Normal(module) = &self.link_output.module_table[module_idx] else {
+        continue;
+      };
+      let Some(ast) = self.link_output.ast_table[module_idx].as_ref() else { continue };
+      if !module.meta.is_included() {
+        continue;
+      }
+      let before = encoder.get_ref().len();
+      // A sync flush makes the bytes of the module available, at the cost of a few bytes.
+      if encoder.write_all(minified_code(module, ast).as_bytes()).is_err()
+        || encoder.flush().is_err()
+      {
+        // Keep the minified sizes.
+        continue;
+      }
+      sizes[module_idx] = (encoder.get_ref().len() - before) as f64;
+    }
+    sizes
+  }
+}
+
+/// Iterates the top-level statements that survive tree shaking.
+fn included_statements<'a>(
+  module: &'a NormalModule,
+  ast: &'a EcmaAst,
+) -> impl Iterator<Item = &'a Statement<'a>> + 'a {
+  // `stmt_infos[0]` is the namespace statement, so `program.body[i]` matches `stmt_infos[i + 1]`.
+  ast
+    .program()
+    .body
+    .iter()
+    .enumerate()
+    .filter(|(idx, _)| module.stmt_infos.get(StmtInfoIdx::new(idx + 1)).is_included)
+    .map(|(_, stmt)| stmt)
+}
+
+/// Size of the code after tree shaking, before minification.
+fn rendered_size(module: &NormalModule, ast: &EcmaAst) -> usize {
+  included_statements(module, ast).map(|stmt| stmt.span().size() as usize).sum()
+}
+
+/// Prints the included statements without whitespace and comments. Names are not mangled and
+/// the code is not compressed, so this overestimates the output of a minifier.
+fn minified_code(module: &NormalModule, ast: &EcmaAst) -> String {
+  let mut codegen =
+    Codegen::new().with_options(CodegenOptions::minify()).with_source_text(ast.source());
+  for stmt in included_statements(module, ast) {
+    stmt.print(&mut codegen, Context::default());
+  }
+  codegen.into_source_text()
+}
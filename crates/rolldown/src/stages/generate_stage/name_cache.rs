@@ -0,0 +1,340 @@
+use std::path::Path;
+
+use std::cell::Cell;
+
+use anyhow::Context;
+use oxc::{
+  ast::ast::{
+    BindingIdentifier, Declaration, ExportDefaultDeclarationKind, IdentifierReference,
+    ModuleExportName, Program, Statement,
+  },
+  ast_visit::Visit,
+  semantic::{ScopeFlags, ScopeId, Scoping, SymbolId},
+};
+use rolldown_common::{
+  Chunk, ChunkFilenamesOutputOption, ChunkIdx, ModuleTable, NormalizedBundlerOptions, SymbolRef,
+  SymbolRefDb,
+};
+use rolldown_ecmascript_utils::BindingPatternExt;
+use rolldown_fs::FileSystem;
+use rolldown_utils::{concat_string, ecmascript::none_preserved_keyword_or_global_object_ext};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::{Map, Value};
+
+use super::compute_cross_chunk_links::generate_minified_names;
+
+type NameMap = FxHashMap<String, String>;
+
+/// Names picked for an output in a previous build, read from and written to the entry of
+/// [`output_key`] in `experimental.nameCache`.
+///
+/// Chunks are keyed by [`chunk_key`], so the cache survives changes to chunk hashes.
+#[derive(Debug, Default)]
+pub struct NameCache {
+  /// Chunk key -> top-level name in the rendered chunk -> mangled name.
+  pub chunks: FxHashMap<String, NameMap>,
+  /// Chunk key -> [`symbol_key`] -> name picked by `output.minifyInternalExports`.
+  pub internal_exports: FxHashMap<String, NameMap>,
+}
+
+impl NameCache {
+  /// Returns an empty cache if the file or the entry of the output doesn't exist yet.
+  pub fn read(fs: &impl FileSystem, path: &Path, output_key: &str) -> anyhow::Result<Self> {
+    let outputs = read_outputs(fs, path)?;
+    let value = outputs.get(output_key);
+    Ok(Self {
+      chunks: name_maps_from_json(value.and_then(|value| value.get("chunks"))),
+      internal_exports: name_maps_from_json(value.and_then(|value| value.get("internalExports"))),
+    })
+  }
+
+  /// Replaces the entry of the output in the file, keeping the entries of other outputs.
+  pub fn write(&self, fs: &impl FileSystem, path: &Path, output_key: &str) -> anyhow::Result<()> {
+    let mut value = Map::new();
+    value.insert("chunks".to_string(), name_maps_to_json(&self.chunks));
+    value.insert("internalExports".to_string(), name_maps_to_json(&self.internal_exports));
+    // The file is read again, since other outputs of the same build may have written to it since.
+    let mut outputs = read_outputs(fs, path)?.into_iter().collect::<Vec<_>>();
+    outputs.retain(|(key, _)| key != output_key);
+    outputs.push((output_key.to_string(), Value::Object(value)));
+    outputs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    let mut content = serde_json::to_string_pretty(&Value::Object(Map::from_iter([(
+      "outputs".to_string(),
+      Value::Object(outputs.into_iter().collect()),
+    )])))?;
+    content.push('\n');
+    fs.write_atomically(path, content.as_bytes())
+      .with_context(|| format!("Failed to write name cache {}", path.display()))
+  }
+}
+
+/// Identifies an output in the name cache by its format and by the path of its `file`, or by its
+/// `dir` and `entryFileNames` pattern.
+pub fn output_key(options: &NormalizedBundlerOptions) -> String {
+  let path = match (&options.file, &options.entry_filenames) {
+    (Some(file), _) => file.clone(),
+    (None, ChunkFilenamesOutputOption::String(pattern)) => {
+      concat_string!(options.out_dir, "/", pattern)
+    }
+    (None, ChunkFilenamesOutputOption::Fn(_)) => options.out_dir.clone(),
+  };
+  concat_string!(options.format.as_str(), ":", path)
+}
+
+fn read_outputs(fs: &impl FileSystem, path: &Path) -> anyhow::Result<Map<String, Value>> {
+  let content = match fs.read_to_string(path) {
+    Ok(content) => content,
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Map::new()),
+    Err(err) => {
+      return Err(err).with_context(|| format!("Failed to read name cache {}", path.display()));
+    }
+  };
+  let value: Value = serde_json::from_str(&content)
+    .with_context(|| format!("Failed to parse name cache {}", path.display()))?;
+  match value {
+    Value::Object(mut value) => match value.remove("outputs") {
+      Some(Value::Object(outputs)) => Ok(outputs),
+      _ => Ok(Map::new()),
+    },
+    _ => Ok(Map::new()),
+  }
+}
+
+fn name_maps_from_json(value: Option<&Value>) -> FxHashMap<String, NameMap> {
+  let Some(Value::Object(maps)) = value else {
+    return FxHashMap::default();
+  };
+  maps
+    .iter()
+    .filter_map(|(key, names)| {
+      let names = names
+        .as_object()?
+        .iter()
+        .filter_map(|(original, name)| Some((original.clone(), name.as_str()?.to_string())))
+        .collect();
+      Some((key.clone(), names))
+    })
+    .collect()
+}
+
+fn name_maps_to_json(maps: &FxHashMap<String, NameMap>) -> Value {
+  let mut keys = maps.keys().collect::<Vec<_>>();
+  keys.sort_unstable();
+  let mut value = Map::new();
+  for key in keys {
+    let mut names = maps[key].iter().collect::<Vec<_>>();
+    names.sort_unstable();
+    value.insert(
+      key.clone(),
+      Value::Object(
+        names.into_iter().map(|(original, name)| (original.clone(), name.clone().into())).collect(),
+      ),
+    );
+  }
+  Value::Object(value)
+}
+
+/// Identifies a chunk across builds by the stable id of its entry module, or of its first module
+/// for common chunks.
+pub fn chunk_key(chunk: &Chunk, module_table: &ModuleTable) -> Option<String> {
+  chunk
+    .entry_module_idx()
+    .or_else(|| chunk.modules.first().copied())
+    .map(|idx| module_table[idx].stable_id().to_string())
+}
+
+pub fn chunk_keys<'a>(
+  chunks: impl Iterator<Item = (ChunkIdx, &'a Chunk)>,
+  module_table: &ModuleTable,
+) -> FxHashMap<ChunkIdx, String> {
+  chunks.filter_map(|(idx, chunk)| Some((idx, chunk_key(chunk, module_table)?))).collect()
+}
+
+/// Identifies an exported symbol across builds by its module and its name in that module.
+pub fn symbol_key(
+  symbol: SymbolRef,
+  module_table: &ModuleTable,
+  symbol_db: &SymbolRefDb,
+) -> String {
+  concat_string!(module_table[symbol.owner].stable_id(), ":", symbol.name(symbol_db))
+}
+
+/// Renames the root scope symbols of a minified chunk, reusing the names in `cached` when they are
+/// still free. Returns the names used, keyed by the original names.
+pub fn rename_top_level_symbols(
+  program: &Program<'_>,
+  scoping: &mut Scoping,
+  cached: Option<&NameMap>,
+) -> NameMap {
+  let root_scope_id = scoping.root_scope_id();
+  let exported = collect_exported_symbols(program, scoping);
+  let shadowing_names = collect_shadowing_names(program, scoping);
+  let mut renamable = vec![];
+  let mut reserved =
+    scoping.root_unresolved_references().keys().map(ToString::to_string).collect::<FxHashSet<_>>();
+  for symbol_id in scoping.symbol_ids() {
+    if scoping.symbol_scope_id(symbol_id) != root_scope_id {
+      continue;
+    }
+    if exported.contains(&symbol_id) {
+      reserved.insert(scoping.symbol_name(symbol_id).to_string());
+    } else {
+      renamable.push(symbol_id);
+    }
+  }
+  let is_shadowed = |symbol_id: SymbolId, name: &str| {
+    shadowing_names.get(&symbol_id).is_some_and(|names| names.contains(name))
+  };
+
+  let mut names = NameMap::default();
+  let mut pending = vec![];
+  for symbol_id in renamable {
+    let original = scoping.symbol_name(symbol_id).to_string();
+    match cached.and_then(|cached| cached.get(&original)) {
+      Some(name) if !reserved.contains(name) && !is_shadowed(symbol_id, name) => {
+        reserved.insert(name.clone());
+        scoping.set_symbol_name(symbol_id, name);
+        names.insert(original, name.clone());
+      }
+      _ => pending.push((symbol_id, original)),
+    }
+  }
+
+  let mut named_index = 0;
+  // Names skipped because they would be shadowed for a symbol, which are still free for others.
+  let mut skipped: Vec<String> = vec![];
+  for (symbol_id, original) in pending {
+    let name = if let Some(index) = skipped.iter().position(|name| !is_shadowed(symbol_id, name)) {
+      skipped.remove(index)
+    } else {
+      loop {
+        let name = generate_minified_names(named_index);
+        named_index += 1;
+        if reserved.contains(&name) || !none_preserved_keyword_or_global_object_ext(&name) {
+          continue;
+        }
+        if is_shadowed(symbol_id, &name) {
+          skipped.push(name);
+          continue;
+        }
+        break name;
+      }
+    };
+    reserved.insert(name.clone());
+    scoping.set_symbol_name(symbol_id, &name);
+    names.insert(original, name);
+  }
+  names
+}
+
+/// The names bound by the nested scopes that contain references to each root scope symbol, which
+/// the symbol can't be renamed to without being shadowed. Nested names are already mangled, so
+/// most of them are short.
+fn collect_shadowing_names(
+  program: &Program<'_>,
+  scoping: &Scoping,
+) -> FxHashMap<SymbolId, FxHashSet<String>> {
+  let mut collector = ReferenceScopeCollector {
+    scoping,
+    scope_stack: vec![],
+    reference_scopes: FxHashMap::default(),
+  };
+  collector.visit_program(program);
+
+  let root_scope_id = scoping.root_scope_id();
+  collector
+    .reference_scopes
+    .into_iter()
+    .map(|(symbol_id, scope_ids)| {
+      let scope_ids = scope_ids
+        .into_iter()
+        .flat_map(|scope_id| {
+          scoping.scope_ancestors(scope_id).take_while(|scope_id| *scope_id != root_scope_id)
+        })
+        .collect::<FxHashSet<_>>();
+      let names = scope_ids
+        .into_iter()
+        .flat_map(|scope_id| scoping.get_bindings(scope_id).keys())
+        .map(ToString::to_string)
+        .collect();
+      (symbol_id, names)
+    })
+    .collect()
+}
+
+/// Collects the innermost nested scopes that reference root scope symbols.
+struct ReferenceScopeCollector<'a> {
+  scoping: &'a Scoping,
+  scope_stack: Vec<Option<ScopeId>>,
+  reference_scopes: FxHashMap<SymbolId, FxHashSet<ScopeId>>,
+}
+
+impl<'ast> Visit<'ast> for ReferenceScopeCollector<'_> {
+  fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+    self.scope_stack.push(scope_id.get());
+  }
+
+  fn leave_scope(&mut self) {
+    self.scope_stack.pop();
+  }
+
+  fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'ast>) {
+    let root_scope_id = self.scoping.root_scope_id();
+    if let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id()
+      && self.scoping.symbol_scope_id(symbol_id) == root_scope_id
+      && let Some(Some(scope_id)) = self.scope_stack.last()
+      && *scope_id != root_scope_id
+    {
+      self.reference_scopes.entry(symbol_id).or_default().insert(*scope_id);
+    }
+  }
+}
+
+/// Root scope symbols whose names are visible to importers of the chunk.
+fn collect_exported_symbols(program: &Program<'_>, scoping: &Scoping) -> FxHashSet<SymbolId> {
+  let mut exported = FxHashSet::default();
+  for stmt in &program.body {
+    match stmt {
+      Statement::ExportNamedDeclaration(decl) => {
+        match &decl.declaration {
+          Some(Declaration::VariableDeclaration(var_decl)) => {
+            for declarator in &var_decl.declarations {
+              exported.extend(declarator.id.binding_identifiers().iter().map(|id| id.symbol_id()));
+            }
+          }
+          Some(Declaration::FunctionDeclaration(func)) => {
+            exported.extend(func.id.as_ref().map(BindingIdentifier::symbol_id));
+          }
+          Some(Declaration::ClassDeclaration(class)) => {
+            exported.extend(class.id.as_ref().map(BindingIdentifier::symbol_id));
+          }
+          _ => {}
+        }
+        if decl.source.is_some() {
+          continue;
+        }
+        // `export { a as b }` is printed with the new local name, but `export { a }` would change
+        // the exported name.
+        for specifier in &decl.specifiers {
+          if let ModuleExportName::IdentifierReference(local) = &specifier.local
+            && specifier.local.name() == specifier.exported.name()
+            && let Some(symbol_id) = scoping.get_reference(local.reference_id()).symbol_id()
+          {
+            exported.insert(symbol_id);
+          }
+        }
+      }
+      Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+          exported.extend(func.id.as_ref().map(BindingIdentifier::symbol_id));
+        }
+        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+          exported.extend(class.id.as_ref().map(BindingIdentifier::symbol_id));
+        }
+        _ => {}
+      },
+      _ => {}
+    }
+  }
+  exported
+}
@@ -14,6 +14,7 @@ use rolldown_utils::{
   indexmap::{FxIndexMap, FxIndexSet},
   rayon::{IntoParallelRefIterator, ParallelIterator},
 };
+use rustc_hash::FxHashMap;
 
 use crate::{
   BundleOutput,
@@ -30,7 +31,11 @@ use crate::{
   },
 };
 
-use super::GenerateStage;
+use super::{
+  GenerateStage,
+  minify_chunks::MangledTopLevelNames,
+  name_cache::{NameCache, chunk_keys, output_key, symbol_key},
+};
 
 type ChunkGeneratorFuture<'a> = Pin<
   Box<
@@ -54,7 +59,18 @@ impl GenerateStage<'_> {
 
     augment_chunk_hash(self.plugin_driver, &mut instantiated_chunks).await?;
 
-    Self::minify_chunks(self.options, &mut instantiated_chunks)?;
+    let chunk_keys = if self.name_cache.is_some() {
+      chunk_keys(chunk_graph.chunk_table.iter_enumerated(), &self.link_output.module_table)
+    } else {
+      FxHashMap::default()
+    };
+    let mangled_names = Self::minify_chunks(
+      self.options,
+      &mut instantiated_chunks,
+      self.name_cache.as_ref(),
+      &chunk_keys,
+    )?;
+    self.write_name_cache(chunk_graph, &chunk_keys, mangled_names)?;
 
     let assets = finalize_assets(
       chunk_graph,
@@ -73,7 +89,7 @@ impl GenerateStage<'_> {
 
     let mut output = Vec::with_capacity(assets.len());
     let mut output_assets: Vec<Output> = vec![];
-    for Asset { map, meta: rendered_chunk, content: code, filename, .. } in assets {
+    for Asset { map, meta: rendered_chunk, content: code, filename, originate_from } in assets {
       match rendered_chunk {
         InstantiationKind::Ecma(ecma_meta) => {
           let code = code.try_into_string()?;
@@ -97,10 +113,22 @@ impl GenerateStage<'_> {
         }
         InstantiationKind::Css(_css_meta) => {
           let code = code.try_into_string()?;
+          // The css modules the file is made of, so that plugins can tell which chunk it belongs to.
+          let original_file_names = originate_from
+            .map(|chunk_idx| {
+              chunk_graph.chunk_table[chunk_idx]
+                .modules
+                .iter()
+                .filter_map(|&idx| self.link_output.module_table[idx].as_normal())
+                .filter(|module| module.css_view.is_some())
+                .map(|module| module.id.resource_id().to_string())
+                .collect()
+            })
+            .unwrap_or_default();
           output.push(Output::Asset(Arc::new(OutputAsset {
             filename: filename.clone(),
             source: code.into(),
-            original_file_names: vec![],
+            original_file_names,
             names: vec![],
           })));
         }
@@ -265,7 +293,9 @@ impl GenerateStage<'_> {
   ) -> Vec<Vec<Option<ModuleRenderOutput>>> {
     let needs_extra_indent = matches!(
       self.options.format,
-      rolldown_common::OutputFormat::Iife | rolldown_common::OutputFormat::Umd
+      rolldown_common::OutputFormat::Iife
+        | rolldown_common::OutputFormat::Umd
+        | rolldown_common::OutputFormat::Amd
     );
     chunk_graph
       .chunk_table
@@ -311,6 +341,55 @@ impl GenerateStage<'_> {
       trace_action!(assets_ready);
     }
   }
+
+  /// Writes the names picked in this build back to the entry of this output in
+  /// `experimental.nameCache`. Only current chunks and exports are kept, unless this build didn't
+  /// pick any names of that kind.
+  fn write_name_cache(
+    &self,
+    chunk_graph: &ChunkGraph,
+    chunk_keys: &FxHashMap<ChunkIdx, String>,
+    mangled_names: MangledTopLevelNames,
+  ) -> BuildResult<()> {
+    let (Some(path), Some(previous)) = (&self.options.experimental.name_cache, &self.name_cache)
+    else {
+      return Ok(());
+    };
+    let internal_exports = if self.options.minify_internal_exports && !self.options.preserve_modules
+    {
+      chunk_graph
+        .chunk_table
+        .iter_enumerated()
+        .filter_map(|(chunk_idx, chunk)| {
+          let names = chunk
+            .exports_to_other_chunks
+            .iter()
+            .filter_map(|(symbol_ref, names)| {
+              let key = symbol_key(
+                *symbol_ref,
+                &self.link_output.module_table,
+                &self.link_output.symbol_db,
+              );
+              Some((key, names.first()?.to_string()))
+            })
+            .collect::<FxHashMap<_, _>>();
+          if names.is_empty() {
+            return None;
+          }
+          Some((chunk_keys.get(&chunk_idx)?.clone(), names))
+        })
+        .collect()
+    } else {
+      previous.internal_exports.clone()
+    };
+    let chunks = if mangled_names.is_empty() { previous.chunks.clone() } else { mangled_names };
+    NameCache { chunks, internal_exports }.write(
+      self.fs,
+      &self.options.cwd.join(path),
+      &output_key(self.options),
+    )?;
+    Ok(())
+  }
 }
 
 enum SortingFileType {
@@ -639,6 +639,26 @@ impl BindImportsAndExportsContext<'_> {
           }
           Specifier::Literal(_) => {}
         }
+        // Node.js throws at load time for named imports that the CommonJS lexer doesn't find.
+        if let Module::External(external) = &self.index_modules[rec.resolved_module]
+          && let Some(commonjs_exports) = &external.commonjs_exports
+          && let Specifier::Literal(name) = &named_import.imported
+          && name.as_str() != "default"
+          && !commonjs_exports.contains(name)
+        {
+          self.errors.push(BuildDiagnostic::missing_export(
+            module.id.to_string(),
+            module.stable_id.clone(),
+            external.id.to_string(),
+            module.source.clone(),
+            name.to_string(),
+            named_import.span_imported,
+            Some(format!(
+              "'{}' is a CommonJS module, which may not support all module.exports as named exports. Use a default import instead (e.g. `import pkg from '{}'` and `pkg.{name}`).",
+              rec.module_request, rec.module_request
+            )),
+          ));
+        }
       }
       let ret = self.match_import_with_export(
         self.index_modules,
@@ -0,0 +1,96 @@
+use rolldown_common::{
+  EcmaModuleAstUsage, EcmaViewMeta, ExportsKind, ImportKind, Module, ModuleIdx, SideEffectDetail,
+  Specifier, TaggedSymbolRef,
+};
+use rolldown_utils::{
+  concat_string,
+  ecmascript::{is_validate_assignee_identifier_name, legitimize_identifier_name},
+};
+use rustc_hash::FxHashSet;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Turns the CommonJS modules found convertible by `AstScanner::analyze_convertible_commonjs`
+  /// into ES modules, so that they are scope hoisted and tree-shaken like any other ES module.
+  /// `exports.foo = ...` becomes a declaration of the `foo` export, see
+  /// `ScopeHoistingFinalizer::convert_commonjs_export_stmt`.
+  ///
+  /// A module is only converted if nothing observes the difference. It must not be an entry, and
+  /// must only be imported with `import` statements, since `require()` and `import()` execute it
+  /// lazily and return `module.exports` itself. Without `__esModule`, the default export of a
+  /// CommonJS module is `module.exports`, so default and namespace imports are not allowed either.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn convert_commonjs_to_esm(&mut self) {
+    if !self.options.experimental.is_commonjs_to_esm_enabled() || self.options.is_hmr_enabled() {
+      return;
+    }
+
+    let mut blocked = self.entries.iter().map(|entry| entry.idx).collect::<FxHashSet<_>>();
+    let mut imported_as_whole = FxHashSet::default();
+    for importer in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      for rec in &importer.import_records {
+        if rec.kind != ImportKind::Import {
+          blocked.insert(rec.resolved_module);
+        }
+      }
+      for named_import in importer.named_imports.values() {
+        let is_whole = match &named_import.imported {
+          Specifier::Star => true,
+          Specifier::Literal(name) => name.as_str() == "default",
+        };
+        if is_whole {
+          imported_as_whole.insert(importer.import_records[named_import.record_id].resolved_module);
+        }
+      }
+    }
+
+    let convertible = self
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .filter(|module| {
+        module.exports_kind == ExportsKind::CommonJs
+          && module.convertible_commonjs.is_some()
+          && !blocked.contains(&module.idx)
+          && (module.ast_usage.contains(EcmaModuleAstUsage::EsModuleFlag)
+            || !imported_as_whole.contains(&module.idx))
+      })
+      .map(|module| module.idx)
+      .collect::<Vec<ModuleIdx>>();
+
+    for idx in convertible {
+      let Some(module) = self.module_table[idx].as_normal_mut() else {
+        continue;
+      };
+      module.exports_kind = ExportsKind::Esm;
+      module.meta.insert(EcmaViewMeta::ConvertedFromCommonjs);
+
+      for stmt_info in &mut module.stmt_infos.infos {
+        // The export symbols are now declared by the statements assigning them.
+        for declared in &mut stmt_info.declared_symbols {
+          if let TaggedSymbolRef::LinkOnly(symbol_ref) = *declared {
+            *declared = TaggedSymbolRef::Normal(symbol_ref);
+          }
+        }
+        stmt_info.side_effect.remove(SideEffectDetail::PureCjs);
+      }
+      for stmt_idx in module.convertible_commonjs.clone().into_iter().flatten() {
+        module.stmt_infos.infos[stmt_idx].side_effect = SideEffectDetail::empty();
+      }
+
+      let repr_name = legitimize_identifier_name(&module.repr_name).into_owned();
+      for (name, local) in &mut module.named_exports {
+        local.came_from_commonjs = false;
+        // `exports.default = ...` can't become `var default = ...`.
+        if !is_validate_assignee_identifier_name(name) {
+          local.referenced.set_name(
+            &mut self.symbols,
+            &concat_string!(repr_name, "_", legitimize_identifier_name(name)),
+          );
+        }
+      }
+    }
+  }
+}
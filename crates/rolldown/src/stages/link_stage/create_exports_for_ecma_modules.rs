@@ -113,7 +113,7 @@ impl LinkStage<'_> {
                   ));
                 });
               }
-              OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {}
+              OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {}
             }
           }
           // Create a StmtInfo to represent the statement that declares and constructs the Module Namespace Object.
@@ -4,8 +4,8 @@ use oxc_index::IndexVec;
 #[cfg(debug_assertions)]
 use rolldown_common::common_debug_symbol_ref;
 use rolldown_common::{
-  ConstExportMeta, EntryPoint, EntryPointKind, FlatOptions, ImportKind, ModuleIdx, ModuleTable,
-  PreserveEntrySignatures, RuntimeModuleBrief, SymbolRef, SymbolRefDb,
+  ConstExportMeta, EntryPoint, EntryPointKind, EnumMemberValues, FlatOptions, ImportKind,
+  ModuleIdx, ModuleTable, PreserveEntrySignatures, RuntimeModuleBrief, SymbolRef, SymbolRefDb,
   dynamic_import_usage::DynamicImportExportsUsage,
 };
 use rolldown_error::BuildDiagnostic;
@@ -24,10 +24,11 @@ use crate::{
   types::linking_metadata::{LinkingMetadata, LinkingMetadataVec},
 };
 
-use super::scan_stage::NormalizedScanStageOutput;
+use super::scan_stage::{NormalizedScanStageOutput, copy_ecma_ast_table};
 
 mod bind_imports_and_exports;
 mod compute_tla;
+mod convert_commonjs_to_esm;
 mod create_exports_for_ecma_modules;
 mod cross_module_optimization;
 mod determine_module_exports_kind;
@@ -58,6 +59,36 @@ pub struct LinkStageOutput {
   pub overrode_preserve_entry_signature_map: FxHashMap<ModuleIdx, PreserveEntrySignatures>,
   pub entry_point_to_reference_ids: FxHashMap<EntryPoint, Vec<ArcStr>>,
   pub global_constant_symbol_map: FxHashMap<SymbolRef, ConstExportMeta>,
+  pub global_ts_enum_values: FxHashMap<SymbolRef, EnumMemberValues>,
+}
+
+impl LinkStageOutput {
+  /// Makes a copy to generate another output from, see `Bundle::generate_multiple`.
+  ///
+  /// Like `NormalizedScanStageOutput::make_copy`, the scoping isn't copied. The warnings aren't
+  /// either, so they are reported with the first output only.
+  pub fn make_copy(&self) -> Self {
+    Self {
+      module_table: self.module_table.clone(),
+      entries: self.entries.clone(),
+      ast_table: copy_ecma_ast_table(&self.ast_table),
+      sorted_modules: self.sorted_modules.clone(),
+      metas: self.metas.clone(),
+      symbol_db: self.symbol_db.clone_without_scoping(),
+      runtime: self.runtime.clone(),
+      warnings: vec![],
+      // Generating the first output fails if there are errors, so no copy is used then.
+      errors: vec![],
+      used_symbol_refs: self.used_symbol_refs.clone(),
+      dynamic_import_exports_usage_map: self.dynamic_import_exports_usage_map.clone(),
+      safely_merge_cjs_ns_map: self.safely_merge_cjs_ns_map.clone(),
+      external_import_namespace_merger: self.external_import_namespace_merger.clone(),
+      overrode_preserve_entry_signature_map: self.overrode_preserve_entry_signature_map.clone(),
+      entry_point_to_reference_ids: self.entry_point_to_reference_ids.clone(),
+      global_constant_symbol_map: self.global_constant_symbol_map.clone(),
+      global_ts_enum_values: self.global_ts_enum_values.clone(),
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -80,6 +111,7 @@ pub struct LinkStage<'a> {
   pub overrode_preserve_entry_signature_map: FxHashMap<ModuleIdx, PreserveEntrySignatures>,
   pub entry_point_to_reference_ids: FxHashMap<EntryPoint, Vec<ArcStr>>,
   pub global_constant_symbol_map: FxHashMap<SymbolRef, ConstExportMeta>,
+  pub global_ts_enum_values: FxHashMap<SymbolRef, EnumMemberValues>,
   pub flat_options: FlatOptions,
   pub side_effects_free_function_symbol_ref: FxHashSet<SymbolRef>,
 }
@@ -104,6 +136,19 @@ impl<'a> LinkStage<'a> {
     } else {
       FxHashMap::default()
     };
+    let ts_enum_values = scan_stage_output
+      .module_table
+      .modules
+      .par_iter_mut()
+      .filter_map(|m| {
+        let m = m.as_normal_mut()?;
+        Some(std::mem::take(&mut m.ts_enum_values).into_iter().map(|(symbol_id, v)| {
+          let symbol_ref = SymbolRef { owner: m.idx, symbol: symbol_id };
+          (symbol_ref, v)
+        }))
+      })
+      .flatten_iter()
+      .collect::<FxHashMap<SymbolRef, EnumMemberValues>>();
 
     // We need to preserve the original order of user defined entry points.
     let mut rest = scan_stage_output
@@ -120,6 +165,7 @@ impl<'a> LinkStage<'a> {
     Self {
       sorted_modules: Vec::new(),
       global_constant_symbol_map: constant_symbol_map,
+      global_ts_enum_values: ts_enum_values,
       metas: scan_stage_output
         .module_table
         .modules
@@ -169,6 +215,7 @@ impl<'a> LinkStage<'a> {
   pub fn link(mut self) -> LinkStageOutput {
     self.sort_modules();
     self.compute_tla();
+    self.convert_commonjs_to_esm();
     self.determine_module_exports_kind();
     self.wrap_modules();
     self.generate_lazy_export();
@@ -178,6 +225,7 @@ impl<'a> LinkStage<'a> {
     self.reference_needed_symbols();
     let unreachable_import_expression_addrs = self.cross_module_optimization();
     self.include_statements(&unreachable_import_expression_addrs);
+    self.compute_unused_members();
     self.patch_module_dependencies();
 
     tracing::trace!("meta {:#?}", self.metas.iter_enumerated().collect::<Vec<_>>());
@@ -199,6 +247,7 @@ impl<'a> LinkStage<'a> {
       overrode_preserve_entry_signature_map: self.overrode_preserve_entry_signature_map,
       entry_point_to_reference_ids: self.entry_point_to_reference_ids,
       global_constant_symbol_map: self.global_constant_symbol_map,
+      global_ts_enum_values: self.global_ts_enum_values,
     }
   }
 
@@ -98,7 +98,10 @@ impl LinkStage<'_> {
                       // import ... from 'external' or export ... from 'external'
                       if matches!(
                         self.options.format,
-                        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd
+                        OutputFormat::Cjs
+                          | OutputFormat::Iife
+                          | OutputFormat::Umd
+                          | OutputFormat::Amd
                       ) {
                         stmt_info.side_effect = true.into();
                         // Only reference __toESM if this import needs interop (namespace or default import)
@@ -284,6 +287,20 @@ impl LinkStage<'_> {
                         }
                         ExportsKind::Esm | ExportsKind::None => {}
                       }
+                      // `import('./foo.js')` might be converted to
+                      // `__preload(() => import('./foo.js'), ['./dep.js'], import.meta.url)`.
+                      // Chunks aren't known yet, but the chunk of a module that doesn't import
+                      // other bundled modules has nothing to preload.
+                      if self.options.experimental.is_preload_dynamic_imports_enabled()
+                        && self.options.format.is_esm()
+                        && importee.import_records.iter().any(|importee_rec| {
+                          !matches!(importee_rec.kind, ImportKind::DynamicImport)
+                            && self.module_table[importee_rec.resolved_module].is_normal()
+                        })
+                      {
+                        depended_runtime_helper_map[RuntimeHelper::Preload.bit_index()]
+                          .push(stmt_info_idx);
+                      }
                     }
                   }
                   ImportKind::AtImport => {
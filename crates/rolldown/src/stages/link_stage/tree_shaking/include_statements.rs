@@ -1,12 +1,13 @@
 use std::cmp::Reverse;
 
 use itertools::Itertools;
+use oxc::span::{CompactStr, Span};
 use oxc_allocator::Address;
 use oxc_index::IndexVec;
 use petgraph::prelude::DiGraphMap;
 use rolldown_common::{
-  ConstExportMeta, EcmaModuleAstUsage, EcmaViewMeta, EntryPoint, EntryPointKind, ExportsKind,
-  ImportKind, ImportRecordIdx, ImportRecordMeta, IndexModules, Module, ModuleIdx,
+  ConstExportMeta, EcmaModuleAstUsage, EcmaViewMeta, EntryPoint, EntryPointKind, EnumMemberValues,
+  ExportsKind, ImportKind, ImportRecordIdx, ImportRecordMeta, IndexModules, Module, ModuleIdx,
   ModuleNamespaceIncludedReason, ModuleType, NormalModule, NormalizedBundlerOptions,
   RUNTIME_HELPER_NAMES, RUNTIME_MODULE_ID, RuntimeHelper, SideEffectDetail, StmtInfoIdx,
   StmtInfoMeta, StmtInfos, SymbolIdExt, SymbolOrMemberExprRef, SymbolRef, SymbolRefDb,
@@ -55,6 +56,7 @@ struct Context<'a> {
   metas: &'a LinkingMetadataVec,
   used_symbol_refs: &'a mut FxHashSet<SymbolRef>,
   constant_symbol_map: &'a FxHashMap<SymbolRef, ConstExportMeta>,
+  ts_enum_values: &'a FxHashMap<SymbolRef, EnumMemberValues>,
   options: &'a NormalizedBundlerOptions,
   normal_symbol_exports_chain_map: &'a FxHashMap<SymbolRef, Vec<SymbolRef>>,
   /// It is necessary since we can't mutate `module.meta` during the tree shaking process.
@@ -109,6 +111,7 @@ impl LinkStage<'_> {
       metas: &self.metas,
       used_symbol_refs: &mut used_symbol_refs,
       constant_symbol_map: &self.global_constant_symbol_map,
+      ts_enum_values: &self.global_ts_enum_values,
       options: self.options,
       normal_symbol_exports_chain_map: &self.normal_symbol_exports_chain_map,
       bailout_cjs_tree_shaking_modules: FxHashSet::default(),
@@ -493,6 +496,7 @@ impl LinkStage<'_> {
       metas: &self.metas,
       used_symbol_refs,
       constant_symbol_map: &self.global_constant_symbol_map,
+      ts_enum_values: &self.global_ts_enum_values,
       options: self.options,
       normal_symbol_exports_chain_map: &self.normal_symbol_exports_chain_map,
       bailout_cjs_tree_shaking_modules: FxHashSet::default(),
@@ -607,6 +611,22 @@ fn include_module(ctx: &mut Context, module: &NormalModule) {
   }
 }
 
+/// Whether `object_ref.props[0]` is a TypeScript enum member that the finalizer inlines.
+fn is_inlined_ts_enum_member(
+  ctx: &Context,
+  object_ref: SymbolRef,
+  props: &[(CompactStr, Span)],
+) -> bool {
+  let Some((prop, _)) = props.first() else {
+    return false;
+  };
+  ctx
+    .ts_enum_values
+    .get(&ctx.symbols.canonical_ref_for(object_ref))
+    .and_then(|values| values.get(prop))
+    .is_some_and(|meta| !ctx.inline_const_smart || meta.safe_to_inline)
+}
+
 fn include_symbol(ctx: &mut Context, symbol_ref: SymbolRef, include_reason: SymbolIncludeReason) {
   let mut canonical_ref = ctx.symbols.canonical_ref_for(symbol_ref);
 
@@ -782,13 +802,25 @@ fn include_statement(ctx: &mut Context, module: &NormalModule, stmt_info_id: Stm
             );
           }
         });
-        include_symbol(ctx, resolved_ref, include_kind);
+        if !is_inlined_ts_enum_member(
+          ctx,
+          resolved_ref,
+          &member_expr_resolution.prop_and_related_span_list,
+        ) {
+          include_symbol(ctx, resolved_ref, include_kind);
+        }
         ctx.may_partial_namespace = pre;
       } else {
         // If it points to nothing, the expression will be rewritten as `void 0` and there's nothing we need to include
       }
     } else {
       let original_ref = reference_ref.symbol_ref();
+      if let SymbolOrMemberExprRef::MemberExpr(member_expr_ref) = reference_ref
+        && is_inlined_ts_enum_member(ctx, *original_ref, &member_expr_ref.prop_and_span_list)
+      {
+        // `Enum.Member` would be replaced with its value, so the enum object isn't needed.
+        return;
+      }
       std::iter::once(original_ref)
         .chain(
           ctx.normal_symbol_exports_chain_map.get(original_ref).map(Vec::as_slice).unwrap_or(&[]),
@@ -1,2 +1,3 @@
 pub(super) mod determine_side_effects;
 pub(super) mod include_statements;
+pub(super) mod unused_members;
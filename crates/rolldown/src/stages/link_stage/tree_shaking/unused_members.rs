@@ -0,0 +1,136 @@
+use oxc::span::CompactStr;
+use rolldown_common::{SymbolOrMemberExprRef, SymbolRef};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+  ast_scanner::removable_members::{IMPLICITLY_CALLED_METHODS, INSTANCE_METHOD_PREFIX},
+  stages::link_stage::LinkStage,
+};
+
+impl LinkStage<'_> {
+  /// Finds the members of object literals and classes collected by the scanner that are never read.
+  ///
+  /// Every reference to such an object in the included statements must be a static member access,
+  /// so that the accessed names are known. Any other reference lets the object escape, and all of
+  /// its members are kept.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn compute_unused_members(&mut self) {
+    if !self.options.treeshake.members() || self.options.is_hmr_enabled() {
+      return;
+    }
+    let modules = self.module_table.modules.iter().filter_map(|m| m.as_normal());
+    // `eval` could read any member.
+    if modules.clone().any(|m| m.meta.is_included() && m.meta.has_eval()) {
+      return;
+    }
+
+    let mut candidates = FxHashMap::<SymbolRef, &FxHashSet<CompactStr>>::default();
+    for module in modules.clone().filter(|m| m.meta.is_included()) {
+      for (symbol_id, names) in &module.removable_members {
+        candidates.insert(SymbolRef { owner: module.idx, symbol: *symbol_id }, names);
+      }
+    }
+    if candidates.is_empty() {
+      return;
+    }
+
+    // Only the reads in included statements are counted, so bare reads like `obj.key;` keep a member
+    // only if `treeshake.propertyReadSideEffects` keeps the statement.
+    let mut escaped = FxHashSet::<SymbolRef>::default();
+    let mut instantiated = FxHashSet::<SymbolRef>::default();
+    let mut used = FxHashMap::<SymbolRef, FxHashSet<&str>>::default();
+    let canonical_candidate = |symbol_ref: SymbolRef| {
+      let canonical_ref = self.symbols.canonical_ref_for(symbol_ref);
+      candidates.contains_key(&canonical_ref).then_some(canonical_ref)
+    };
+
+    for entry in &self.entries {
+      for export in self.metas[entry.idx].resolved_exports.values() {
+        escaped.extend(canonical_candidate(export.symbol_ref));
+      }
+    }
+    for meta in &self.metas {
+      for (symbol_ref, _) in &meta.referenced_symbols_by_entry_point_chunk {
+        escaped.extend(canonical_candidate(*symbol_ref));
+      }
+      for symbol_ref in &meta.included_commonjs_export_symbol {
+        escaped.extend(canonical_candidate(*symbol_ref));
+      }
+    }
+
+    // Instances could end up anywhere, so an instance method is kept if a property of the same name
+    // is read on any object.
+    let mut read_names = FxHashSet::<&str>::default();
+    let mut has_computed_read = false;
+    for module in modules.filter(|m| m.meta.is_included()) {
+      read_names.extend(module.member_reads.names.iter().map(CompactStr::as_str));
+      has_computed_read |= module.member_reads.has_computed_read;
+      let resolved_member_expr_refs = &self.metas[module.idx].resolved_member_expr_refs;
+      for stmt_info in module.stmt_infos.iter().filter(|stmt_info| stmt_info.is_included) {
+        for reference in &stmt_info.referenced_symbols {
+          match reference {
+            SymbolOrMemberExprRef::Symbol(symbol_ref) => {
+              escaped.extend(canonical_candidate(*symbol_ref));
+            }
+            SymbolOrMemberExprRef::MemberExpr(member_expr) => {
+              let (object_ref, props) = match member_expr.resolution(resolved_member_expr_refs) {
+                // `ns.obj.member`
+                Some(resolution) => {
+                  (resolution.resolved, resolution.prop_and_related_span_list.as_slice())
+                }
+                None => (Some(member_expr.object_ref), member_expr.prop_and_span_list.as_slice()),
+              };
+              let Some(object_ref) = object_ref.and_then(canonical_candidate) else {
+                continue;
+              };
+              match props.first() {
+                Some((prop, _)) => {
+                  used.entry(object_ref).or_default().insert(prop.as_str());
+                }
+                // `new X()`
+                None if module.member_reads.constructed.contains(&member_expr.span) => {
+                  instantiated.insert(object_ref);
+                }
+                None => {
+                  escaped.insert(object_ref);
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+
+    let mut unused_members = vec![];
+    for (symbol_ref, removable) in candidates {
+      if escaped.contains(&symbol_ref) {
+        continue;
+      }
+      let used = used.remove(&symbol_ref).unwrap_or_default();
+      // Instances could be constructed from `Class.bind()`.
+      let is_prototype_used = used.contains("prototype") || used.contains("bind");
+      let is_instantiated = instantiated.contains(&symbol_ref);
+      let unused = removable
+        .iter()
+        .filter(|name| match name.strip_prefix(INSTANCE_METHOD_PREFIX) {
+          Some(method) => {
+            !is_prototype_used
+              && (!is_instantiated
+                || (!has_computed_read
+                  && !read_names.contains(method)
+                  && !IMPLICITLY_CALLED_METHODS.contains(&method)))
+          }
+          None if name.as_str() == "prototype" => !is_prototype_used && !is_instantiated,
+          None => !used.contains(name.as_str()),
+        })
+        .cloned()
+        .collect::<FxHashSet<_>>();
+      if !unused.is_empty() {
+        unused_members.push((symbol_ref, unused));
+      }
+    }
+    for (symbol_ref, unused) in unused_members {
+      self.metas[symbol_ref.owner].unused_members.insert(symbol_ref.symbol, unused);
+    }
+  }
+}
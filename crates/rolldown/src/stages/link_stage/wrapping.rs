@@ -164,6 +164,30 @@ impl LinkStage<'_> {
         });
       }
     }
+    if self.options.experimental.is_concurrent_top_level_await_enabled()
+      && !self.options.is_hmr_enabled()
+    {
+      // Modules involving TLA become async initializers, so that their importers can start them
+      // together and await them with `Promise.all`. Entries call the initializers of their
+      // imports at the top level.
+      let entries = self.entries.iter().map(|entry| entry.idx).collect::<FxHashSet<_>>();
+      for module in self.module_table.modules.iter().filter_map(Module::as_normal) {
+        if self.metas[module.idx].is_tla_or_contains_tla_dependency
+          && !entries.contains(&module.idx)
+        {
+          wrap_module_recursively(
+            &mut Context {
+              visited_modules: &mut visited_modules_for_wrapping,
+              linking_infos: &mut self.metas,
+              modules: &self.module_table.modules,
+              runtime_idx: self.runtime.id(),
+              on_demand_wrapping,
+            },
+            module.idx,
+          );
+        }
+      }
+    }
     if is_strict_execution_order_enabled {
       // Override wrap_kind if `strictExecutionOrder` is enabled.
       for (idx, linking_info) in
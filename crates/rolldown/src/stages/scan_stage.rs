@@ -22,7 +22,7 @@ use crate::{
   module_loader::{ModuleLoader, module_loader::ModuleLoaderOutput},
   type_alias::IndexEcmaAst,
   types::scan_stage_cache::ScanStageCache,
-  utils::load_entry_module::load_entry_module,
+  utils::{expand_input_globs::expand_input_globs, load_entry_module::load_entry_module},
 };
 
 type SourcemapChannel = (
@@ -30,21 +30,23 @@ type SourcemapChannel = (
   Option<thread::JoinHandle<FxHashMap<ModuleIdx, Vec<SourcemapChainElement>>>>,
 );
 
-/// Resolve `InputOptions.input`
+/// Resolve `InputOptions.input`, after expanding its glob patterns
 #[tracing::instrument(target = "devtool", level = "debug", skip_all)]
 pub async fn resolve_user_defined_entries(
   options: &SharedOptions,
+  fs: &OsFileSystem,
   resolver: &SharedResolver,
   plugin_driver: &SharedPluginDriver,
 ) -> BuildResult<Vec<(Option<ArcStr>, ResolvedId)>> {
-  let resolved_ids = join_all(options.input.iter().map(|input_item| async move {
+  let input = expand_input_globs(fs, &options.cwd, &options.input)?;
+  let resolved_ids = join_all(input.iter().map(|input_item| async move {
     let resolved = load_entry_module(resolver, plugin_driver, &input_item.import, None).await;
 
     resolved.map(|info| (input_item.name.as_ref().map(Into::into), info))
   }))
   .await;
 
-  let mut ret = Vec::with_capacity(options.input.len());
+  let mut ret = Vec::with_capacity(input.len());
 
   let mut errors = vec![];
 
@@ -93,17 +95,7 @@ impl NormalizedScanStageOutput {
   pub fn make_copy(&self) -> Self {
     Self {
       module_table: self.module_table.clone(),
-      index_ecma_ast: {
-        #[cfg(not(target_os = "macos"))]
-        let iter = self.index_ecma_ast.raw.par_iter();
-        #[cfg(target_os = "macos")]
-        let iter = self.index_ecma_ast.raw.iter();
-
-        let index_ecma_ast = iter
-          .map(|ast| ast.as_ref().map(rolldown_ecmascript::EcmaAst::clone_with_another_arena))
-          .collect::<Vec<_>>();
-        IndexVec::from_vec(index_ecma_ast)
-      },
+      index_ecma_ast: copy_ecma_ast_table(&self.index_ecma_ast),
       entry_points: self.entry_points.clone(),
       symbol_ref_db: self.symbol_ref_db.clone_without_scoping(),
       runtime: self.runtime.clone(),
@@ -117,6 +109,19 @@ impl NormalizedScanStageOutput {
   }
 }
 
+/// Clones each AST of `table` into its own arena.
+pub fn copy_ecma_ast_table(table: &IndexEcmaAst) -> IndexEcmaAst {
+  #[cfg(not(target_os = "macos"))]
+  let iter = table.raw.par_iter();
+  #[cfg(target_os = "macos")]
+  let iter = table.raw.iter();
+
+  let index_ecma_ast = iter
+    .map(|ast| ast.as_ref().map(rolldown_ecmascript::EcmaAst::clone_with_another_arena))
+    .collect::<Vec<_>>();
+  IndexVec::from_vec(index_ecma_ast)
+}
+
 impl TryFrom<ScanStageOutput> for NormalizedScanStageOutput {
   type Error = &'static str;
 
@@ -59,7 +59,7 @@ impl GenerateContext<'_> {
     }
 
     match self.options.format {
-      rolldown_common::OutputFormat::Cjs => {
+      rolldown_common::OutputFormat::Cjs | rolldown_common::OutputFormat::Amd => {
         let chunk_idx_of_canonical_symbol = canonical_symbol.chunk_id.unwrap_or_else(|| {
           // Scoped symbols don't get assigned a `ChunkId`. There are skipped for performance reason, because they are surely
           // belong to the chunk they are declared in and won't link to other chunks.
@@ -1,4 +1,4 @@
-use oxc::span::CompactStr;
+use oxc::{semantic::SymbolId, span::CompactStr};
 use oxc_index::IndexVec;
 use rolldown_common::{
   ConcatenateWrappedModuleKind, EntryPointKind, ImportRecordIdx, MemberExprRefResolutionMap,
@@ -9,7 +9,7 @@ use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 /// Module metadata about linking
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct LinkingMetadata {
   /// A module could be wrapped for some reasons, eg. cjs module need to be wrapped with commonjs runtime function.
   /// The `wrap_ref` is the binding identifier that store return value of executed the wrapper function.
@@ -80,6 +80,9 @@ pub struct LinkingMetadata {
   pub included_commonjs_export_symbol: FxHashSet<SymbolRef>,
   pub depended_runtime_helper: RuntimeHelper,
   pub module_namespace_included_reason: ModuleNamespaceIncludedReason,
+  /// Object literals and classes declared in this module -> names of their members that are never
+  /// read and should be removed. Only populated with `treeshake.members`.
+  pub unused_members: FxHashMap<SymbolId, FxHashSet<CompactStr>>,
 }
 
 impl LinkingMetadata {
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use rolldown_common::NormalizedBundlerOptions;
+use rolldown_fs::OsFileSystem;
 use rolldown_plugin::__inner::SharedPluginable;
 
 /// Some builtin features of rolldown is implemented via builtin plugins. However, though these
@@ -12,6 +13,7 @@ use rolldown_plugin::__inner::SharedPluginable;
 /// - Control the order of plugins via `PluginHookMeta` mechanism.
 pub fn apply_inner_plugins(
   options: &NormalizedBundlerOptions,
+  fs: &OsFileSystem,
   user_plugins: &mut Vec<SharedPluginable>,
 ) {
   let mut before_user_plugins: Vec<SharedPluginable> =
@@ -29,6 +31,26 @@ pub fn apply_inner_plugins(
     }));
   }
 
+  if let Some(config) = &options.experimental.html_entries {
+    before_user_plugins.push(Arc::new(rolldown_plugin_html::HtmlPlugin::new(
+      config.inline_script_limit(),
+      fs.clone(),
+    )));
+  }
+
+  if let Some(config) = &options.experimental.remote_imports {
+    before_user_plugins.push(Arc::new(rolldown_plugin_remote_imports::RemoteImportsPlugin::new(
+      &options.cwd,
+      config,
+    )));
+  }
+
+  if let Some(config) = &options.experimental.module_federation {
+    before_user_plugins.push(Arc::new(
+      rolldown_plugin_module_federation::ModuleFederationPlugin::new(config.clone()),
+    ));
+  }
+
   if !before_user_plugins.is_empty() {
     user_plugins.splice(0..0, before_user_plugins);
   }
@@ -38,9 +38,11 @@ pub fn deconflict_chunk_symbols(
       renamer.reserve(CompactStr::new(name));
     });
 
-  if matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs) {
+  if matches!(
+    format,
+    OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs | OutputFormat::Amd
+  ) {
     // deconflict iife introduce symbols by external
-    // Also AMD, but we don't support them yet.
     chunk
       .direct_imports_from_external_modules
       .iter()
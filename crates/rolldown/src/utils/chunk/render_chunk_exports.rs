@@ -61,7 +61,7 @@ pub fn render_wrapped_entry_chunk(
               Some(concat_string!("module.exports = ", wrapper_ref_name, "();\n"))
             }
           }
-          OutputFormat::Iife | OutputFormat::Umd => {
+          OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
             if matches!(&export_mode, Some(OutputExports::Named)) {
               Some(render_object_define_property(
                 "default",
@@ -147,7 +147,7 @@ pub fn render_chunk_exports(
       s.push_str(&concat_string!("export { ", rendered_items.join(", "), " };"));
       Some(s)
     }
-    OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {
+    OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
       let mut s = String::new();
       match chunk.kind {
         ChunkKind::EntryPoint { module, .. } => {
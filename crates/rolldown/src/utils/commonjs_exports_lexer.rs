@@ -0,0 +1,249 @@
+use std::path::Path;
+
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{
+    self, Argument, AssignmentExpression, CallExpression, Expression, ObjectPropertyKind,
+  },
+  ast_visit::{Visit, walk},
+  parser::Parser,
+  span::{CompactStr, SourceType},
+};
+use rolldown_common::ImportKind;
+use rolldown_fs::{OsFileSystem, OxcResolverFileSystem as _};
+use rolldown_resolver::Resolver;
+use rustc_hash::FxHashSet;
+
+/// Re-exported files are followed up to this depth, which is plenty for real packages and keeps
+/// a cycle of re-exports from looping forever.
+const MAX_REEXPORT_DEPTH: usize = 16;
+
+/// Detects the names that Node.js makes importable from a CommonJS file with `import { name }`.
+///
+/// Node.js doesn't run the file to find them, but looks for these patterns with
+/// [cjs-module-lexer](https://github.com/nodejs/cjs-module-lexer), so this does the same:
+/// - `exports.foo = ...`, `module.exports.foo = ...`
+/// - `Object.defineProperty(exports, 'foo', ...)`
+/// - `module.exports = { foo, bar: ... }`, including esbuild's `0 && (module.exports = { ... })`
+/// - re-exports with `module.exports = require('./foo')`, `...require('./foo')` in
+///   `module.exports = { ... }`, and TypeScript's `__exportStar(require('./foo'), exports)`
+///
+/// Returns `None` if the file isn't CommonJS, or couldn't be read or parsed.
+pub fn detect_commonjs_exports(
+  fs: &OsFileSystem,
+  resolver: &Resolver<OsFileSystem>,
+  path: &Path,
+) -> Option<FxHashSet<CompactStr>> {
+  let mut exports = FxHashSet::default();
+  let mut visited = FxHashSet::default();
+  collect_exports(fs, resolver, path, &mut exports, &mut visited, 0)?;
+  Some(exports)
+}
+
+fn collect_exports(
+  fs: &OsFileSystem,
+  resolver: &Resolver<OsFileSystem>,
+  path: &Path,
+  exports: &mut FxHashSet<CompactStr>,
+  visited: &mut FxHashSet<CompactStr>,
+  depth: usize,
+) -> Option<()> {
+  let path_str = path.to_str()?;
+  if depth > MAX_REEXPORT_DEPTH || !visited.insert(path_str.into()) {
+    return Some(());
+  }
+  let source = fs.read_to_string(path).ok()?;
+  let allocator = Allocator::default();
+  let ret = Parser::new(&allocator, &source, SourceType::cjs()).parse();
+  if ret.panicked
+    || !ret.errors.is_empty()
+    || ret.program.body.iter().any(ast::Statement::is_module_declaration)
+  {
+    return None;
+  }
+  let mut lexer = CommonjsExportsLexer::default();
+  lexer.visit_program(&ret.program);
+  exports.extend(lexer.exports);
+
+  for specifier in lexer.reexports {
+    // Node.js resolves re-exports the same way as `require()`.
+    let Ok(resolved) = resolver.resolve(Some(path), &specifier, ImportKind::Require, false) else {
+      continue;
+    };
+    // A re-exported file that can't be analyzed just doesn't contribute any names.
+    let _ =
+      collect_exports(fs, resolver, Path::new(resolved.path.as_str()), exports, visited, depth + 1);
+  }
+  Some(())
+}
+
+#[derive(Default)]
+struct CommonjsExportsLexer {
+  exports: FxHashSet<CompactStr>,
+  reexports: Vec<String>,
+}
+
+impl CommonjsExportsLexer {
+  fn add_reexport(&mut self, expr: &Expression<'_>) {
+    if let Some(specifier) = require_call_specifier(expr) {
+      self.reexports.push(specifier.to_string());
+    }
+  }
+
+  fn add_object_exports(&mut self, obj: &ast::ObjectExpression<'_>) {
+    for prop in &obj.properties {
+      match prop {
+        ObjectPropertyKind::ObjectProperty(prop) => {
+          if let Some(name) = prop.key.static_name() {
+            self.exports.insert(name.as_ref().into());
+          }
+        }
+        ObjectPropertyKind::SpreadProperty(spread) => self.add_reexport(&spread.argument),
+      }
+    }
+  }
+}
+
+impl<'a> Visit<'a> for CommonjsExportsLexer {
+  fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+    if let Some(member_expr) = it.left.as_member_expression() {
+      if is_module_exports(member_expr) {
+        match it.right.without_parentheses() {
+          Expression::ObjectExpression(obj) => self.add_object_exports(obj),
+          right => self.add_reexport(right),
+        }
+      } else if is_exports_object(member_expr.object())
+        && let Some(name) = member_expr.static_property_name()
+      {
+        self.exports.insert(name.into());
+      }
+    }
+    walk::walk_assignment_expression(self, it);
+  }
+
+  fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+    match it.callee.without_parentheses() {
+      // `Object.defineProperty(exports, 'foo', { ... })`
+      Expression::StaticMemberExpression(callee)
+        if callee.property.name == "defineProperty"
+          && matches!(&callee.object, Expression::Identifier(id) if id.name == "Object") =>
+      {
+        if let [Argument::Identifier(target), Argument::StringLiteral(name), ..] =
+          it.arguments.as_slice()
+          && target.name == "exports"
+        {
+          self.exports.insert(name.value.as_str().into());
+        }
+      }
+      // `__exportStar(require('./foo'), exports)` and `tslib.__exportStar(...)`
+      callee if is_export_star_helper(callee) => {
+        if let Some(Argument::CallExpression(call)) = it.arguments.first()
+          && let Some(specifier) = require_call_specifier_of_call(call)
+        {
+          self.reexports.push(specifier.to_string());
+        }
+      }
+      _ => {}
+    }
+    walk::walk_call_expression(self, it);
+  }
+}
+
+fn is_exports_object(expr: &Expression<'_>) -> bool {
+  match expr {
+    Expression::Identifier(id) => id.name == "exports",
+    Expression::StaticMemberExpression(member_expr) => {
+      member_expr.property.name == "exports"
+        && matches!(&member_expr.object, Expression::Identifier(id) if id.name == "module")
+    }
+    _ => false,
+  }
+}
+
+/// `module.exports`
+fn is_module_exports(member_expr: &ast::MemberExpression<'_>) -> bool {
+  member_expr.static_property_name() == Some("exports")
+    && matches!(member_expr.object(), Expression::Identifier(id) if id.name == "module")
+}
+
+fn is_export_star_helper(callee: &Expression<'_>) -> bool {
+  let name = match callee {
+    Expression::Identifier(id) => id.name.as_str(),
+    Expression::StaticMemberExpression(member_expr) => member_expr.property.name.as_str(),
+    _ => return false,
+  };
+  matches!(name, "__exportStar" | "__export")
+}
+
+fn require_call_specifier<'b>(expr: &'b Expression<'_>) -> Option<&'b str> {
+  match expr.without_parentheses() {
+    Expression::CallExpression(call) => require_call_specifier_of_call(call),
+    _ => None,
+  }
+}
+
+fn require_call_specifier_of_call<'b>(call: &'b CallExpression<'_>) -> Option<&'b str> {
+  let Expression::Identifier(callee) = &call.callee else {
+    return None;
+  };
+  match call.arguments.as_slice() {
+    [Argument::StringLiteral(specifier)] if callee.name == "require" => {
+      Some(specifier.value.as_str())
+    }
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn lex(source: &str) -> (Vec<String>, Vec<String>) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+    let mut lexer = CommonjsExportsLexer::default();
+    lexer.visit_program(&ret.program);
+    let mut exports = lexer.exports.iter().map(ToString::to_string).collect::<Vec<_>>();
+    exports.sort_unstable();
+    (exports, lexer.reexports)
+  }
+
+  #[test]
+  fn test_exports_assignments() {
+    let (exports, reexports) = lex(
+      r#"
+      Object.defineProperty(exports, "__esModule", { value: true });
+      exports.a = 1;
+      module.exports.b = 2;
+      exports["c"] = 3;
+      "#,
+    );
+    assert_eq!(exports, ["__esModule", "a", "b", "c"]);
+    assert!(reexports.is_empty());
+  }
+
+  #[test]
+  fn test_module_exports_object() {
+    let (exports, reexports) = lex(
+      r"
+      const a = 1;
+      0 && (module.exports = { a, b: 2, ...require('./c') });
+      ",
+    );
+    assert_eq!(exports, ["a", "b"]);
+    assert_eq!(reexports, ["./c"]);
+  }
+
+  #[test]
+  fn test_reexports() {
+    let (exports, reexports) = lex(
+      r"
+      __exportStar(require('./a'), exports);
+      tslib.__exportStar(require('./b'), exports);
+      module.exports = require('./c');
+      ",
+    );
+    assert!(exports.is_empty());
+    assert_eq!(reexports, ["./a", "./b", "./c"]);
+  }
+}
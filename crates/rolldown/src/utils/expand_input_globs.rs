@@ -0,0 +1,118 @@
+use std::{borrow::Cow, io, path::Path};
+
+use rolldown_common::InputItem;
+use rolldown_error::{BuildDiagnostic, InvalidOptionType, ResultExt, SingleBuildResult};
+use rolldown_fs::FileSystem;
+use rustc_hash::{FxHashMap, FxHashSet};
+use sugar_path::SugarPath;
+
+/// Expands the glob patterns of `input` against `cwd`, keeping the other items as they are.
+///
+/// Every matched file becomes an entry named after its path relative to the base of the pattern,
+/// without extension. `src/pages/**/*.js` turns `src/pages/blog/post.js` into a `blog/post` entry,
+/// or `pages/blog/post` if the item is named `pages`. `node_modules` directories and symlinks to
+/// directories aren't searched, nor are directories deeper than a pattern without `**` can match.
+/// Matched files given the same name, such as `index.js` matched by both `a/*.js` and `b/*.js`,
+/// are an error, unless the patterns are named differently.
+pub fn expand_input_globs<'a, Fs: FileSystem + ?Sized>(
+  fs: &Fs,
+  cwd: &Path,
+  input: &'a [InputItem],
+) -> SingleBuildResult<Cow<'a, [InputItem]>> {
+  if !input.iter().any(InputItem::is_glob) {
+    return Ok(Cow::Borrowed(input));
+  }
+
+  let mut expanded = Vec::with_capacity(input.len());
+  // Whether each expanded item was matched by a glob pattern.
+  let mut matched = Vec::with_capacity(input.len());
+  let mut seen = FxHashSet::default();
+  for item in input {
+    if !item.is_glob() {
+      expanded.push(item.clone());
+      matched.push(false);
+      continue;
+    }
+    if item.is_negated_glob() {
+      continue;
+    }
+    let base = cwd.join(item.glob_base());
+    let mut files = vec![];
+    collect_files(fs, &base, item.glob_max_depth(), &mut files).map_err_to_unhandleable()?;
+    files.sort();
+    for file in files {
+      let relative = file.relative(cwd).to_slash_lossy().into_owned();
+      let is_excluded =
+        input.iter().any(|item| item.is_negated_glob() && item.matches_glob(&relative));
+      if !item.matches_glob(&relative) || is_excluded || !seen.insert(relative) {
+        continue;
+      }
+      let stem = file.with_extension("");
+      let stem = stem.relative(&base).to_slash_lossy().into_owned();
+      expanded.push(InputItem {
+        name: Some(match &item.name {
+          Some(name) => rolldown_utils::concat_string!(name, "/", stem),
+          None => stem,
+        }),
+        import: file.to_string_lossy().into_owned(),
+      });
+      matched.push(true);
+    }
+  }
+
+  let display = |import: &str| {
+    let path = Path::new(import);
+    if path.is_absolute() {
+      path.relative(cwd).to_slash_lossy().into_owned()
+    } else {
+      import.into()
+    }
+  };
+  let mut imports_by_name = FxHashMap::default();
+  for (index, item) in expanded.iter().enumerate() {
+    let Some(name) = &item.name else { continue };
+    if let Some(first) = imports_by_name.insert(name.as_str(), index) {
+      // Explicitly named items may share a name, their chunks are deduplicated as usual.
+      if matched[first] || matched[index] {
+        return Err(BuildDiagnostic::invalid_option(InvalidOptionType::DuplicateGlobEntryName {
+          name: name.clone(),
+          imports: [display(&expanded[first].import), display(&item.import)],
+        }));
+      }
+    }
+  }
+  Ok(Cow::Owned(expanded))
+}
+
+/// Collects the files in `dir`, and in its subdirectories up to `max_depth` levels deep.
+fn collect_files<Fs: FileSystem + ?Sized>(
+  fs: &Fs,
+  dir: &Path,
+  max_depth: Option<usize>,
+  files: &mut Vec<std::path::PathBuf>,
+) -> io::Result<()> {
+  let entries = match fs.read_dir(dir) {
+    Ok(entries) => entries,
+    Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+    Err(err) => return Err(err),
+  };
+  for entry in entries {
+    let metadata = fs.symlink_metadata(&entry)?;
+    if metadata.is_dir() {
+      if entry.file_name().is_some_and(|name| name == "node_modules") {
+        continue;
+      }
+      match max_depth {
+        Some(0) => {}
+        Some(depth) => collect_files(fs, &entry, Some(depth - 1), files)?,
+        None => collect_files(fs, &entry, None, files)?,
+      }
+    } else if metadata.is_file()
+      // Symlinks to files are entries like any other file. Broken ones are skipped.
+      || (metadata.is_symlink() && matches!(fs.metadata(&entry), Ok(metadata) if metadata.is_file()))
+    {
+      files.push(entry);
+    }
+  }
+  Ok(())
+}
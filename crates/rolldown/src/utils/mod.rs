@@ -1,6 +1,8 @@
 pub mod apply_inner_plugins;
 pub mod augment_chunk_hash;
 pub mod chunk;
+pub mod commonjs_exports_lexer;
+pub mod expand_input_globs;
 pub mod external_import_interop;
 pub mod fs_utils;
 pub mod load_entry_module;
@@ -12,8 +14,10 @@ pub mod process_code_and_sourcemap;
 pub mod renamer;
 pub mod render_chunks;
 pub mod render_ecma_module;
+pub mod replace_define_wildcards;
 pub mod resolve_id;
 pub mod transform_source;
+pub mod ts_enum;
 pub mod tweak_ast_for_scanning;
 pub mod uuid;
 
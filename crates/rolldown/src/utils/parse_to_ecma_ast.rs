@@ -1,14 +1,19 @@
 use std::{borrow::Cow, path::Path};
 
 use json_escape_simd::escape;
-use oxc::{semantic::Scoping, span::SourceType as OxcSourceType};
+use oxc::{
+  semantic::{Scoping, SymbolId},
+  span::SourceType as OxcSourceType,
+};
 use rolldown_common::{
-  ModuleType, NormalizedBundlerOptions, RUNTIME_MODULE_KEY, StrOrBytes, json_value_to_ecma_ast,
+  EnumMemberValues, ModuleType, NormalizedBundlerOptions, RUNTIME_MODULE_KEY, StrOrBytes,
+  json_value_to_ecma_ast,
 };
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_plugin::HookTransformAstArgs;
 use rolldown_utils::mime::guess_mime;
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
 use super::pre_process_ecma_ast::PreProcessEcmaAst;
@@ -31,6 +36,8 @@ pub struct ParseToEcmaAstResult {
   pub scoping: Scoping,
   pub has_lazy_export: bool,
   pub warnings: Vec<BuildDiagnostic>,
+  /// Member values of the top-level TypeScript enums, keyed by the symbol of the enum object.
+  pub ts_enum_values: FxHashMap<SymbolId, EnumMemberValues>,
 }
 
 pub async fn parse_to_ecma_ast(
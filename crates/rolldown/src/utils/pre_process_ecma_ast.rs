@@ -11,13 +11,16 @@ use oxc::transformer_plugins::{
   InjectGlobalVariables, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
 };
 
-use rolldown_common::NormalizedBundlerOptions;
+use rolldown_common::{EnumMemberValues, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, WithMutFields};
 use rolldown_error::{BatchedBuildDiagnostic, BuildDiagnostic, BuildResult, Severity};
+use rustc_hash::FxHashMap;
 
 use crate::types::oxc_parse_type::OxcParseType;
 
 use super::parse_to_ecma_ast::ParseToEcmaAstResult;
+use super::replace_define_wildcards::ReplaceDefineWildcards;
+use super::ts_enum::collect_ts_enum_values;
 use super::tweak_ast_for_scanning::PreProcessor;
 
 #[derive(Default)]
@@ -75,7 +78,7 @@ impl PreProcessEcmaAst {
     self.stats = semantic_ret.semantic.stats();
     let mut scoping = Some(semantic_ret.semantic.into_scoping());
 
-    // Step 2: Run define plugin.
+    // Step 2: Run define plugin, then replace the members left for wildcard entries.
     if let Some(replace_global_define_config) = replace_global_define_config {
       ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
         let ret = ReplaceGlobalDefines::new(allocator, replace_global_define_config.clone())
@@ -85,8 +88,31 @@ impl PreProcessEcmaAst {
         }
       });
     }
+    if !bundle_options.define_wildcards.is_empty() {
+      ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
+        let current_scoping = self.recreate_scoping(&mut scoping, program, false);
+        let mut replacer = ReplaceDefineWildcards::new(
+          allocator,
+          &current_scoping,
+          &bundle_options.define_wildcards,
+        );
+        replacer.visit_program(program);
+        if !replacer.changed {
+          scoping = Some(current_scoping);
+        }
+      });
+    }
 
-    // Step 3: Transform TypeScript and jsx.
+    // Step 3: Collect enum member values, which are lost once TypeScript is transformed.
+    let ts_enum_values = if matches!(parsed_type, OxcParseType::Ts | OxcParseType::Tsx)
+      && bundle_options.optimization.is_inline_const_enabled()
+    {
+      collect_ts_enum_values(ast.program())
+    } else {
+      FxHashMap::default()
+    };
+
+    // Step 4: Transform TypeScript and jsx.
     // Note: Currently, oxc_transform supports es syntax up to ES2024 (unicode-sets-regex).
     let is_not_js = !matches!(parsed_type, OxcParseType::Js);
     if is_not_js || bundle_options.transform_options.should_transform_js() {
@@ -119,7 +145,7 @@ impl PreProcessEcmaAst {
       })?;
     }
 
-    // Step 4: Run inject plugin.
+    // Step 5: Run inject plugin.
     if !bundle_options.inject.is_empty() {
       ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
         let new_scoping = self.recreate_scoping(&mut scoping, program, false);
@@ -131,7 +157,7 @@ impl PreProcessEcmaAst {
       });
     }
 
-    // Step 5: Run DCE.
+    // Step 6: Run DCE.
     // Avoid DCE for lazy export.
     if bundle_options.treeshake.is_some() && !has_lazy_export {
       ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
@@ -146,14 +172,19 @@ impl PreProcessEcmaAst {
       });
     }
 
-    // Step 6: Modify AST for Rolldown.
+    // Step 7: Modify AST for Rolldown.
     let scoping = ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
       let mut pre_processor = PreProcessor::new(allocator, bundle_options.keep_names);
       pre_processor.visit_program(program);
       self.recreate_scoping(&mut None, program, true)
     });
 
-    Ok(ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings })
+    let ts_enum_values = ts_enum_values
+      .into_iter()
+      .filter_map(|(name, values)| Some((scoping.get_root_binding(name.as_str())?, values)))
+      .collect::<FxHashMap<_, EnumMemberValues>>();
+
+    Ok(ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings, ts_enum_values })
   }
 
   fn recreate_scoping(
@@ -2,17 +2,20 @@ use std::{borrow::Cow, path::Path, sync::Arc};
 
 use arcstr::ArcStr;
 use itertools::Either;
-use oxc::{transformer::EngineTargets, transformer_plugins::InjectGlobalVariablesConfig};
+use oxc::{
+  transformer::EngineTargets,
+  transformer_plugins::{InjectGlobalVariablesConfig, ReplaceGlobalDefinesConfig},
+};
 use rolldown_common::{
   AttachDebugInfo, GlobalsOutputOption, InjectImport, JsxOptions, JsxPreset, LegalComments,
-  MinifyOptions, ModuleType, NormalizedBundlerOptions, OutputFormat, Platform,
+  MinifyOptions, ModuleType, NormalizedBundlerOptions, OutputFormat, OutputOptions, Platform,
   PreserveEntrySignatures, RawTransformOptions, TransformOptions, TreeshakeOptions, TsConfig,
   merge_transform_options_with_tsconfig, normalize_optimization_option,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult, InvalidOptionType};
 use rolldown_fs::{OsFileSystem, OxcResolverFileSystem as _};
 use rolldown_resolver::Resolver;
-use rolldown_utils::ecmascript::is_validate_identifier_name;
+use rolldown_utils::{ecmascript::is_validate_identifier_name, indexmap::FxIndexMap};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{SharedResolver, utils::determine_minify_internal_exports_default};
@@ -58,6 +61,17 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> BuildResult<Vec<Bu
     _ => {}
   }
 
+  if raw_options
+    .experimental
+    .as_ref()
+    .is_some_and(|experimental| experimental.module_federation.is_some())
+    && let Some(format) = raw_options.format.filter(|format| !format.is_esm())
+  {
+    errors.push(BuildDiagnostic::invalid_option(
+      InvalidOptionType::UnsupportedModuleFederationFormat(format.to_string()),
+    ));
+  }
+
   if let Some(advanced_chunks) = &raw_options.advanced_chunks {
     let has_groups = advanced_chunks.groups.as_ref().is_some_and(|groups| !groups.is_empty());
 
@@ -112,6 +126,76 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> BuildResult<Vec<Bu
   if errors.is_empty() { Ok(warnings) } else { Err(errors.into()) }
 }
 
+fn verify_normalized_minify_options(
+  options: &NormalizedBundlerOptions,
+  warnings: &mut Vec<BuildDiagnostic>,
+) {
+  if options.experimental.name_cache.is_some()
+    && let MinifyOptions::Enabled((minify_options, _)) = &options.minify
+    && !minify_options.mangle.as_ref().is_some_and(|mangle| mangle.top_level)
+  {
+    warnings.push(
+      BuildDiagnostic::invalid_option(InvalidOptionType::NameCacheWithoutTopLevelMangle)
+        .with_severity_warning(),
+    );
+  }
+}
+
+/// The `define` entries, and the wildcard entries as `(object, value)` pairs.
+type NormalizedDefine = (FxIndexMap<String, String>, Vec<(String, String)>);
+
+/// Splits wildcard entries like `process.env.*` from the other `define` entries.
+///
+/// Values that are JSON objects, like `import.meta.env` set to `{"MODE":"production"}`, also
+/// define each of their members. `import.meta.env.MODE` is then replaced by `"production"` alone
+/// instead of a member of the whole object, so unused members don't end up in the output.
+///
+/// The other entries are validated when the module loader builds the replacer from them, the
+/// wildcard entries are validated here.
+fn normalize_define(raw_define: FxIndexMap<String, String>) -> BuildResult<NormalizedDefine> {
+  let mut define = FxIndexMap::default();
+  let mut wildcards = vec![];
+  for (key, value) in raw_define {
+    match key.strip_suffix(".*") {
+      Some(object) => {
+        ReplaceGlobalDefinesConfig::new(&[(object, value.as_str())]).map_err(|errors| {
+          errors
+            .into_iter()
+            .map(|error| BuildDiagnostic::invalid_define_config(error.message.to_string()))
+            .collect::<Vec<_>>()
+        })?;
+        wildcards.push((object.to_string(), value));
+      }
+      None => {
+        if let Ok(serde_json::Value::Object(members)) = serde_json::from_str(&value) {
+          define_members(&key, members, &mut define);
+        }
+        define.insert(key, value);
+      }
+    }
+  }
+  Ok((define, wildcards))
+}
+
+/// Members don't override entries defined explicitly.
+fn define_members(
+  key: &str,
+  members: serde_json::Map<String, serde_json::Value>,
+  define: &mut FxIndexMap<String, String>,
+) {
+  for (name, value) in members {
+    if !is_validate_identifier_name(&name) {
+      continue;
+    }
+    let member_key = rolldown_utils::concat_string!(key, ".", name);
+    let code = value.to_string();
+    if let serde_json::Value::Object(members) = value {
+      define_members(&member_key, members, define);
+    }
+    define.entry(member_key).or_insert(code);
+  }
+}
+
 #[expect(clippy::too_many_lines)] // This function is long, but it's mostly just mapping values
 pub fn prepare_build_context(
   mut raw_options: crate::BundlerOptions,
@@ -137,12 +221,15 @@ pub fn prepare_build_context(
 
   let platform = raw_options.platform.unwrap_or(match format {
     OutputFormat::Cjs => Platform::Node,
-    OutputFormat::Esm | OutputFormat::Iife | OutputFormat::Umd => Platform::Browser,
+    OutputFormat::Esm | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
+      Platform::Browser
+    }
   });
 
   let raw_minify = raw_options.minify.unwrap_or_default();
 
-  let mut raw_define = raw_options.define.unwrap_or_default();
+  let (mut raw_define, define_wildcards) =
+    normalize_define(raw_options.define.unwrap_or_default())?;
   if matches!(platform, Platform::Browser) && !raw_define.contains_key("process.env.NODE_ENV") {
     if raw_minify.is_enabled() {
       raw_define.insert("process.env.NODE_ENV".to_string(), "'production'".to_string());
@@ -191,6 +278,21 @@ pub fn prepare_build_context(
   let globals = raw_options.globals.unwrap_or(GlobalsOutputOption::FxHashMap(FxHashMap::default()));
   let generated_code = raw_options.generated_code.unwrap_or_default();
 
+  if matches!(platform, Platform::Browser)
+    && let Some(node_polyfills) = raw_options
+      .experimental
+      .as_ref()
+      .and_then(|experimental| experimental.node_polyfills.as_ref())
+  {
+    let inject = raw_options.inject.get_or_insert_default();
+    for global in node_polyfills.global_injects() {
+      // User-defined injections take precedence
+      if !inject.iter().any(|item| item.local_name() == global.local_name()) {
+        inject.push(global);
+      }
+    }
+  }
+
   let oxc_inject_global_variables_config = InjectGlobalVariablesConfig::new(
     raw_options
       .inject
@@ -251,8 +353,10 @@ pub fn prepare_build_context(
 
   let tsconfig = raw_options.tsconfig.clone().map(|tsconfig| tsconfig.with_base(&cwd));
   let fs = OsFileSystem::new(raw_resolve.yarn_pnp.is_some_and(|b| b));
-  let resolver =
-    Arc::new(Resolver::new(fs.clone(), cwd.clone(), platform, tsconfig.as_ref(), raw_resolve));
+  let resolver = Arc::new(
+    Resolver::new(fs.clone(), cwd.clone(), platform, tsconfig.as_ref(), raw_resolve)
+      .with_trace(experimental.is_resolve_trace_enabled()),
+  );
 
   let transform_options = {
     let mut raw_transform_options = raw_options.transform.unwrap_or_default();
@@ -368,6 +472,7 @@ pub fn prepare_build_context(
     exports: raw_options.exports.unwrap_or(crate::OutputExports::Auto),
     hash_characters: raw_options.hash_characters.unwrap_or(crate::HashCharacters::Base64),
     globals,
+    amd: raw_options.amd.unwrap_or_default(),
     paths: raw_options.paths,
     generated_code,
     sourcemap: raw_options.sourcemap,
@@ -382,6 +487,7 @@ pub fn prepare_build_context(
     // Use placeholder for minify options at first
     minify: MinifyOptions::Disabled,
     define,
+    define_wildcards,
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
     extend: raw_options.extend.unwrap_or(false),
@@ -428,6 +534,38 @@ pub fn prepare_build_context(
   };
 
   normalized.minify = raw_minify.normalize(&normalized);
+  verify_normalized_minify_options(&normalized, &mut warnings);
 
   Ok(PrepareBuildContext { fs, resolver, options: Arc::new(normalized), warnings })
 }
+
+/// Normalizes the options of an additional output generated from the same build as `base`, see
+/// `Bundler::generate_outputs`.
+///
+/// Only the output-related fields of `raw_base`, the raw options of `base`, are overridden. The
+/// modules are scanned once with `base`, so the other options are normalized the same way, apart
+/// from the ones derived from output fields, which don't affect scanning.
+pub fn normalize_output_options(
+  base: &NormalizedBundlerOptions,
+  raw_base: &crate::BundlerOptions,
+  output: OutputOptions,
+) -> BuildResult<(NormalizedBundlerOptions, Vec<BuildDiagnostic>)> {
+  // Only report the issues of the output fields, the others were reported with the base options.
+  let mut raw_output = crate::BundlerOptions {
+    format: Some(base.format),
+    preserve_entry_signatures: raw_base.preserve_entry_signatures,
+    ..Default::default()
+  };
+  output.clone().apply_to(&mut raw_output);
+  let mut warnings = verify_raw_options(&raw_output)?;
+
+  let mut raw_options = raw_base.clone();
+  output.apply_to(&mut raw_options);
+  // The platform defaults to one derived from the format, but the modules are scanned only once.
+  raw_options.platform = Some(base.platform);
+  let PrepareBuildContext { options, .. } = prepare_build_context(raw_options)?;
+  let options = Arc::unwrap_or_clone(options);
+  verify_normalized_minify_options(&options, &mut warnings);
+
+  Ok((options, warnings))
+}
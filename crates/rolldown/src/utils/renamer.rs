@@ -1,6 +1,5 @@
 use oxc::semantic::ScopeId;
 use oxc::span::CompactStr;
-use oxc::syntax::keyword::{GLOBAL_OBJECTS, RESERVED_KEYWORDS};
 use rolldown_common::{
   AstScopes, ModuleIdx, ModuleScopeSymbolIdMap, NormalModule, OutputFormat, SymbolRef, SymbolRefDb,
   SymbolRefFlags,
@@ -8,6 +7,7 @@ use rolldown_common::{
 use rolldown_utils::rustc_hash::FxHashMapExt;
 use rolldown_utils::{
   concat_string,
+  conflictless_names::ConflictlessNames,
   rayon::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator},
 };
 use rustc_hash::FxHashMap;
@@ -18,24 +18,7 @@ use crate::stages::link_stage::LinkStageOutput;
 
 #[derive(Debug)]
 pub struct Renamer<'name> {
-  /// key is the original name,
-  /// value is the how many same variable name in the top level are used before
-  /// It is also used to calculate the candidate_name e.g.
-  /// ```js
-  /// // index.js
-  /// import {a as b} from './a.js'
-  /// const a = 1; // {a => 0}
-  /// const a$1 = 1000; // {a => 0, a$1 => 0}
-  ///
-  ///
-  /// // a.js
-  /// export const a = 100; // {a => 0, a$1 => 0}, first we try looking up `a`, it is used. So we try the
-  ///                       // candidate_name `a$1`(conflict_index + 1 = 1). Then we try `a$2`, so
-  ///                       // on and so forth. Until we find a name that is not used. In this case, `a$2` is not used
-  ///                       // so we rename `a` to `a$2`
-  /// ```
-  ///
-  used_canonical_names: FxHashMap<CompactStr, u32>,
+  used_canonical_names: ConflictlessNames,
   canonical_names: FxHashMap<SymbolRef, CompactStr>,
   symbol_db: &'name SymbolRefDb,
 }
@@ -46,24 +29,21 @@ impl<'name> Renamer<'name> {
     let mut manual_reserved = match format {
       OutputFormat::Esm => vec![],
       OutputFormat::Cjs => vec!["module", "require", "__filename", "__dirname", "exports"],
-      OutputFormat::Iife | OutputFormat::Umd => vec!["exports"], // Also for  AMD, but we don't support them yet.
+      OutputFormat::Iife | OutputFormat::Umd => vec!["exports"],
+      // The factory of AMD output receives the local `require` of the loader.
+      OutputFormat::Amd => vec!["exports", "require"],
     };
     // https://github.com/rollup/rollup/blob/bfbea66569491f5466fbba99de2ba6a0225f851b/src/Chunk.ts#L1359
     manual_reserved.extend(["Object", "Promise"]);
     Self {
       canonical_names: FxHashMap::default(),
       symbol_db: symbols,
-      used_canonical_names: manual_reserved
-        .iter()
-        .chain(RESERVED_KEYWORDS.iter())
-        .chain(GLOBAL_OBJECTS.iter())
-        .map(|s| (CompactStr::new(s), 0))
-        .collect(),
+      used_canonical_names: ConflictlessNames::with_reserved(manual_reserved),
     }
   }
 
   pub fn reserve(&mut self, name: CompactStr) {
-    self.used_canonical_names.insert(name, 0);
+    self.used_canonical_names.reserve(name);
   }
 
   /// Assigns a canonical name to a symbol without checking for conflicts.
@@ -95,23 +75,7 @@ impl<'name> Renamer<'name> {
 
     match self.canonical_names.entry(canonical_ref) {
       Entry::Vacant(vacant) => {
-        let mut candidate_name = original_name.clone();
-        loop {
-          match self.used_canonical_names.entry(candidate_name.clone()) {
-            Entry::Occupied(mut occ) => {
-              let next_conflict_index = *occ.get() + 1;
-              *occ.get_mut() = next_conflict_index;
-              candidate_name =
-                concat_string!(original_name, "$", itoa::Buffer::new().format(next_conflict_index))
-                  .into();
-            }
-            Entry::Vacant(vac) => {
-              vac.insert(0);
-              break;
-            }
-          }
-        }
-        vacant.insert(candidate_name);
+        vacant.insert(self.used_canonical_names.create(&original_name));
       }
       Entry::Occupied(_) => {
         // The symbol is already renamed
@@ -120,22 +84,7 @@ impl<'name> Renamer<'name> {
   }
 
   pub fn create_conflictless_name(&mut self, hint: &str) -> String {
-    let mut conflictless_name = CompactStr::new(hint);
-    loop {
-      match self.used_canonical_names.entry(conflictless_name.clone()) {
-        Entry::Occupied(mut occ) => {
-          let next_conflict_index = *occ.get() + 1;
-          *occ.get_mut() = next_conflict_index;
-          conflictless_name =
-            concat_string!(hint, "$", itoa::Buffer::new().format(next_conflict_index)).into();
-        }
-        Entry::Vacant(vac) => {
-          vac.insert(0);
-          break;
-        }
-      }
-    }
-    conflictless_name.to_string()
+    self.used_canonical_names.create(hint).to_string()
   }
 
   // non-top-level symbols won't be linked cross-module. So the canonical `SymbolRef` for them are themselves.
@@ -204,7 +153,7 @@ impl<'name> Renamer<'name> {
             ast_scope.scoping().get_scope_child_ids(ast_scope.scoping().root_scope_id());
 
           child_scopes.into_par_iter().map(|child_scope_id| {
-            let mut stack = vec![Cow::Borrowed(&self.used_canonical_names)];
+            let mut stack = vec![Cow::Borrowed(self.used_canonical_names.used_names())];
             let mut canonical_names = FxHashMap::default();
             rename_symbols_of_nested_scopes(
               module,
@@ -0,0 +1,81 @@
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{Expression, MemberExpression},
+  ast_visit::{VisitMut, walk_mut},
+  parser::Parser,
+  semantic::Scoping,
+  span::SourceType,
+};
+
+/// Replaces the members of the objects of wildcard `define` entries, e.g. `process.env.FOO` for
+/// `process.env.*`, with the fallback of the entry.
+///
+/// It runs after the other `define` entries are replaced, so it only sees the members they don't
+/// cover. Objects rooted at a global are matched only, `process.env.FOO` with a local `process`
+/// is kept as is.
+pub struct ReplaceDefineWildcards<'a, 'me> {
+  allocator: &'a Allocator,
+  scoping: &'me Scoping,
+  wildcards: &'me [(String, String)],
+  pub changed: bool,
+}
+
+impl<'a, 'me> ReplaceDefineWildcards<'a, 'me> {
+  pub fn new(
+    allocator: &'a Allocator,
+    scoping: &'me Scoping,
+    wildcards: &'me [(String, String)],
+  ) -> Self {
+    Self { allocator, scoping, wildcards, changed: false }
+  }
+
+  fn fallback_of(&self, expr: &Expression<'a>) -> Option<&'me str> {
+    let member = expr.as_member_expression()?;
+    if let MemberExpression::ComputedMemberExpression(computed) = member
+      && !computed.expression.is_string_literal()
+    {
+      return None;
+    }
+    self.wildcards.iter().find_map(|(object, fallback)| {
+      let segments = object.split('.').collect::<Vec<_>>();
+      self.is_object(member.object(), &segments).then_some(fallback.as_str())
+    })
+  }
+
+  /// Whether `expr` is the chain of `segments`, like `["process", "env"]` for `process.env`.
+  fn is_object(&self, expr: &Expression<'a>, segments: &[&str]) -> bool {
+    match (expr, segments) {
+      (Expression::Identifier(ident), [name]) => {
+        ident.name == *name
+          && ident
+            .reference_id
+            .get()
+            .is_none_or(|id| self.scoping.get_reference(id).symbol_id().is_none())
+      }
+      (Expression::MetaProperty(meta), ["import", "meta"]) => {
+        meta.meta.name == "import" && meta.property.name == "meta"
+      }
+      (Expression::StaticMemberExpression(member), [rest @ .., name]) => {
+        member.property.name == *name && self.is_object(&member.object, rest)
+      }
+      _ => false,
+    }
+  }
+}
+
+impl<'a> VisitMut<'a> for ReplaceDefineWildcards<'a, '_> {
+  fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+    if let Some(fallback) = self.fallback_of(expr) {
+      let source = self.allocator.alloc_str(fallback);
+      // The fallback is checked to be an expression while normalizing the options.
+      if let Ok(replacement) =
+        Parser::new(self.allocator, source, SourceType::mjs()).parse_expression()
+      {
+        *expr = replacement;
+        self.changed = true;
+        return;
+      }
+    }
+    walk_mut::walk_expression(self, expr);
+  }
+}
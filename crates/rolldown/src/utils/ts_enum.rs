@@ -0,0 +1,156 @@
+use oxc::{
+  ast::ast::{
+    BinaryOperator, Declaration, Expression, Program, Statement, TSEnumDeclaration,
+    TSModuleDeclarationName, UnaryOperator,
+  },
+  span::CompactStr,
+};
+use rolldown_common::{ConstExportMeta, ConstantValue, EnumMemberValues};
+use rustc_hash::FxHashMap;
+
+/// Collects the member values of top-level TypeScript enums. This must run before the TypeScript
+/// transform, which turns enums into plain objects built by an IIFE.
+///
+/// Only enums that are declared once, aren't merged with a namespace and whose members are all
+/// constant are collected, so their values can be inlined and the enum object dropped.
+pub fn collect_ts_enum_values(program: &Program<'_>) -> FxHashMap<CompactStr, EnumMemberValues> {
+  let mut declaration_count = FxHashMap::<&str, u32>::default();
+  let mut enums = FxHashMap::<CompactStr, EnumMemberValues>::default();
+  for stmt in &program.body {
+    let declaration = match stmt {
+      Statement::ExportNamedDeclaration(decl) => decl.declaration.as_ref(),
+      _ => stmt.as_declaration(),
+    };
+    match declaration {
+      Some(Declaration::TSEnumDeclaration(decl)) => {
+        *declaration_count.entry(decl.id.name.as_str()).or_default() += 1;
+        if !decl.declare
+          && let Some(values) = evaluate_enum(decl, &enums)
+        {
+          enums.insert(decl.id.name.as_str().into(), values);
+        }
+      }
+      Some(Declaration::TSModuleDeclaration(decl)) => {
+        if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+          *declaration_count.entry(id.name.as_str()).or_default() += 1;
+        }
+      }
+      _ => {}
+    }
+  }
+  enums.retain(|name, _| declaration_count.get(name.as_str()) == Some(&1));
+  enums
+}
+
+fn evaluate_enum(
+  decl: &TSEnumDeclaration<'_>,
+  enums: &FxHashMap<CompactStr, EnumMemberValues>,
+) -> Option<EnumMemberValues> {
+  let enum_name = decl.id.name.as_str();
+  let mut values = FxHashMap::<CompactStr, ConstantValue>::default();
+  // Members without an initializer continue from the previous numeric member.
+  let mut next_value = Some(0.0);
+  for member in &decl.body.members {
+    let value = match &member.initializer {
+      Some(init) => evaluate_member_initializer(init, enum_name, &values, enums)?,
+      None => ConstantValue::Number(next_value?),
+    };
+    next_value = match value {
+      ConstantValue::Number(n) => Some(n + 1.0),
+      _ => None,
+    };
+    values.insert(member.id.static_name().as_str().into(), value);
+  }
+  Some(values.into_iter().map(|(name, value)| (name, ConstExportMeta::new(value, false))).collect())
+}
+
+/// Evaluates the subset of constant enum expressions that TypeScript itself allows: literals,
+/// arithmetic on numbers, string concatenation and references to previously declared members.
+fn evaluate_member_initializer(
+  expr: &Expression<'_>,
+  enum_name: &str,
+  values: &FxHashMap<CompactStr, ConstantValue>,
+  enums: &FxHashMap<CompactStr, EnumMemberValues>,
+) -> Option<ConstantValue> {
+  let evaluate =
+    |expr: &Expression<'_>| evaluate_member_initializer(expr, enum_name, values, enums);
+  match expr {
+    Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+    Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
+    Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+      lit.quasis.first()?.value.cooked.map(|cooked| ConstantValue::String(cooked.to_string()))
+    }
+    Expression::ParenthesizedExpression(paren) => evaluate(&paren.expression),
+    Expression::Identifier(ident) => values.get(ident.name.as_str()).cloned(),
+    Expression::StaticMemberExpression(member) => {
+      let Expression::Identifier(object) = &member.object else {
+        return None;
+      };
+      let name = member.property.name.as_str();
+      if object.name.as_str() == enum_name {
+        values.get(name).cloned()
+      } else {
+        enums.get(object.name.as_str())?.get(name).map(|meta| meta.value.clone())
+      }
+    }
+    Expression::UnaryExpression(unary) => {
+      let ConstantValue::Number(n) = evaluate(&unary.argument)? else {
+        return None;
+      };
+      let value = match unary.operator {
+        UnaryOperator::UnaryPlus => n,
+        UnaryOperator::UnaryNegation => -n,
+        UnaryOperator::BitwiseNot => f64::from(!to_int32(n)),
+        _ => return None,
+      };
+      Some(ConstantValue::Number(value))
+    }
+    Expression::BinaryExpression(binary) => {
+      match (evaluate(&binary.left)?, evaluate(&binary.right)?) {
+        (ConstantValue::String(left), ConstantValue::String(right))
+          if binary.operator == BinaryOperator::Addition =>
+        {
+          Some(ConstantValue::String(left + &right))
+        }
+        (ConstantValue::Number(left), ConstantValue::Number(right)) => {
+          evaluate_numeric_binary(binary.operator, left, right).map(ConstantValue::Number)
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+fn evaluate_numeric_binary(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+  let shift = to_int32(right).cast_unsigned() & 31;
+  let value = match operator {
+    BinaryOperator::Addition => left + right,
+    BinaryOperator::Subtraction => left - right,
+    BinaryOperator::Multiplication => left * right,
+    BinaryOperator::Division => left / right,
+    BinaryOperator::Remainder => left % right,
+    BinaryOperator::Exponential => left.powf(right),
+    BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+    BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+    BinaryOperator::BitwiseXOR => f64::from(to_int32(left) ^ to_int32(right)),
+    BinaryOperator::ShiftLeft => f64::from(to_int32(left) << shift),
+    BinaryOperator::ShiftRight => f64::from(to_int32(left) >> shift),
+    BinaryOperator::ShiftRightZeroFill => f64::from(to_int32(left).cast_unsigned() >> shift),
+    _ => return None,
+  };
+  Some(value)
+}
+
+/// <https://tc39.es/ecma262/#sec-toint32>
+#[expect(
+  clippy::cast_possible_truncation,
+  clippy::cast_sign_loss,
+  reason = "the value is truncated and wrapped into the u32 range first"
+)]
+fn to_int32(n: f64) -> i32 {
+  if !n.is_finite() {
+    return 0;
+  }
+  (n.trunc().rem_euclid(4_294_967_296.0) as u32).cast_signed()
+}
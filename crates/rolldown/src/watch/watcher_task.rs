@@ -13,9 +13,10 @@ use super::{emitter::SharedWatcherEmitter, event::BundleErrorEventData};
 use crate::watch::event::{BundleEndEventData, BundleEvent, WatcherEvent};
 use arcstr::ArcStr;
 use notify::{RecommendedWatcher, WatchMode, Watcher};
-use rolldown_common::{OutputsDiagnostics, WatcherChangeKind};
+use rolldown_common::{OutputsDiagnostics, WatcherChangeKind, matches_input_globs};
 use rolldown_error::{BuildDiagnostic, BuildResult, ResultExt};
 use rolldown_utils::{dashmap::FxDashSet, pattern_filter};
+use sugar_path::SugarPath;
 use tokio::sync::Mutex;
 
 use crate::Bundler;
@@ -24,6 +25,9 @@ pub struct WatcherTask {
   pub emitter: SharedWatcherEmitter,
   bundler: Arc<Mutex<Bundler>>,
   pub invalidate_flag: AtomicBool,
+  /// Set when a file matching a glob of `input` is added or removed, so the entries are expanded
+  /// again by a full scan.
+  pub rescan_entries_flag: AtomicBool,
   notify_watcher: Arc<Mutex<RecommendedWatcher>>,
   pub watch_files: FxDashSet<ArcStr>,
 }
@@ -38,6 +42,7 @@ impl WatcherTask {
       emitter,
       bundler,
       invalidate_flag: AtomicBool::new(true),
+      rescan_entries_flag: AtomicBool::new(false),
       watch_files: FxDashSet::default(),
       notify_watcher,
     }
@@ -62,7 +67,9 @@ impl WatcherTask {
     let result = {
       let is_incremental = bundler.options.experimental.is_incremental_build_enabled();
 
-      let scan_mode = if is_incremental && !changed_files.is_empty() {
+      let rescan_entries = self.rescan_entries_flag.swap(false, Ordering::Relaxed);
+
+      let scan_mode = if is_incremental && !changed_files.is_empty() && !rescan_entries {
         rolldown_common::ScanMode::Partial(changed_files.to_vec())
       } else {
         rolldown_common::ScanMode::Full
@@ -154,6 +161,20 @@ impl WatcherTask {
         }
       }
     }
+    // Watch the directories searched by the globs of `input` to pick up added entries.
+    for item in options.input.iter().filter(|item| item.is_glob() && !item.is_negated_glob()) {
+      let base = options.cwd.join(item.glob_base());
+      let base_str = base.to_string_lossy();
+      if base.is_dir() && !self.watch_files.contains(base_str.as_ref()) {
+        self.watch_files.insert(base_str.as_ref().into());
+        let mode = if item.glob_max_depth() == Some(0) {
+          WatchMode::non_recursive()
+        } else {
+          WatchMode::recursive()
+        };
+        watcher_paths.add(&base, mode).map_err_to_unhandleable()?;
+      }
+    }
     watcher_paths.commit().map_err_to_unhandleable()?;
 
     // The inner mutex should be dropped to avoid deadlock with bundler lock at `Watcher::close`
@@ -199,6 +220,15 @@ impl WatcherTask {
   #[tracing::instrument(level = "debug", skip(self))]
   pub async fn on_change(&self, path: &str, kind: WatcherChangeKind) {
     let bundler = self.bundler.lock().await;
+    if matches!(kind, WatcherChangeKind::Create | WatcherChangeKind::Delete)
+      && matches_input_globs(
+        &bundler.options.input,
+        &Path::new(path).relative(&bundler.options.cwd).to_slash_lossy(),
+      )
+    {
+      self.invalidate_flag.store(true, Ordering::Relaxed);
+      self.rescan_entries_flag.store(true, Ordering::Relaxed);
+    }
     if let Some(plugin_driver) = bundler.last_bundle_handle.as_ref().map(|ctx| &ctx.plugin_driver) {
       let _ = plugin_driver.watch_change(path, kind).await.map_err(|e| {
         self.emitter.emit(WatcherEvent::Event(BundleEvent::Error(BundleErrorEventData {
@@ -0,0 +1,11 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+
+assert.strictEqual(globalThis.loaded, true);
+// The module is converted and the empty object declares nothing.
+assert.ok(!code.includes('__commonJS'));
+assert.ok(!code.includes('var ;'));
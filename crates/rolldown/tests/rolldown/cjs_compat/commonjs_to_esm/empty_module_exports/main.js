@@ -0,0 +1,3 @@
+import './empty.js';
+
+globalThis.loaded = true;
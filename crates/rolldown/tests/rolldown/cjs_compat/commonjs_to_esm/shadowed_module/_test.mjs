@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import './dist/main.js';
+
+assert.deepStrictEqual(globalThis.result, ['foo', 'local']);
@@ -0,0 +1,6 @@
+exports.foo = 'foo';
+
+var module = { exports: {} };
+module.exports = { bar: 'local' };
+
+exports.bar = module.exports.bar;
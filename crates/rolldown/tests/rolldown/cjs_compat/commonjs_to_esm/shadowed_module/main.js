@@ -0,0 +1,3 @@
+import { bar, foo } from './lib.js';
+
+globalThis.result = [foo, bar];
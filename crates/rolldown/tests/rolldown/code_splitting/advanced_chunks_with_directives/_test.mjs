@@ -0,0 +1,20 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+assert.deepStrictEqual(globalThis.result, ['<button>', 'query']);
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const chunks = fs
+  .readdirSync(dist)
+  .filter((file) => file.startsWith('lib'))
+  .map((file) => fs.readFileSync(path.join(dist, file), 'utf-8'));
+// The group is split by directives, and only the client part keeps `'use client'`.
+assert.strictEqual(chunks.length, 2);
+const client = chunks.find((code) => code.includes('function Button'));
+const server = chunks.find((code) => code.includes('function query'));
+assert.match(client, /^['"]use client['"]/);
+assert.ok(client.includes('function format'));
+assert.ok(!server.includes('use client'));
+assert.ok(!server.includes('function Button'));
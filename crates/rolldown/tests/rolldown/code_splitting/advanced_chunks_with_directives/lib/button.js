@@ -0,0 +1,7 @@
+'use client';
+
+import { format } from './format.js';
+
+export function Button() {
+  return format('button');
+}
@@ -0,0 +1,3 @@
+export function query() {
+  return 'query';
+}
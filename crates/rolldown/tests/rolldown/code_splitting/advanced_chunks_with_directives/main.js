@@ -0,0 +1,4 @@
+import { Button } from './lib/button.js';
+import { query } from './lib/query.js';
+
+globalThis.result = [Button(), query()];
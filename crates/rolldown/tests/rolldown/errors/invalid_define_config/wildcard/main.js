@@ -0,0 +1 @@
+console.log("hello world")
\ No newline at end of file
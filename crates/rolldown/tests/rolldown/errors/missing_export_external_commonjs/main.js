@@ -0,0 +1 @@
+import './nested/entry.js'
@@ -0,0 +1,3 @@
+import { foo, bar } from 'cjs-pkg'
+
+console.log(foo, bar)
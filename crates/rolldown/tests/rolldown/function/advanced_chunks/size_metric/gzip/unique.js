@@ -0,0 +1 @@
+export const unique = 'B8OySFROdRSEHRd50aLPCzrZaqyufab1HGXtRaSwDL4ba3vYqc9Fhrlqy9obMnWlLWYDvMzknZO6Zq4zGPiMPYZqUT8H/jv+EO+R/Dg+8seBMwk5WFQPGaYmX6zdY7n7pRvPsYlwVFP2aSfwna2nfiEE8jhZ3uazE4xZebpsmvObIf9/5jX1EI9Tm8AAjwikmfnzdeSoMYCv/n8hnxybc0GfeG0piNHpf+2SGnoF0c4SJC/84i99AreVL7tHypxZ1cH5E05zRo2egL6HRnZQw7djrr1oi41rIXchVONni53ZKMq+9tS39THgponHpPdWhRmx4ohJVTS8S26XW6Rtx+Z9rB/vYR9nUaM7/sRJlVIMCGsXeJv01mGmzMIMzUlUEsJSykMpff8t02Vh'
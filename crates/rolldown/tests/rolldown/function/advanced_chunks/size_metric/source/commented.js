@@ -0,0 +1,9 @@
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+// Documentation that is larger than the code. Documentation that is larger than the code.
+export const commented = 'commented'
@@ -0,0 +1,5 @@
+import { commented } from './commented.js'
+import { repeated } from './repeated.js'
+import { unique } from './unique.js'
+
+console.log(commented, repeated.length, unique.length)
@@ -0,0 +1,25 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const vendorChunks = fs
+  .readdirSync(dist)
+  .filter((file) => file.startsWith('vendor'))
+  .map((file) => fs.readFileSync(path.join(dist, file), 'utf-8'));
+
+assert.deepStrictEqual(globalThis.log, [
+  'a1',
+  'a2',
+  '........................',
+  'b1',
+  'b2',
+  '........................',
+]);
+// Each package ends up in its own part.
+assert.strictEqual(vendorChunks.length, 2);
+const chunkOf = (name) => vendorChunks.findIndex((code) => code.includes(`"${name}"`));
+assert.strictEqual(chunkOf('a1'), chunkOf('a2'));
+assert.strictEqual(chunkOf('b1'), chunkOf('b2'));
+assert.notStrictEqual(chunkOf('a1'), chunkOf('b1'));
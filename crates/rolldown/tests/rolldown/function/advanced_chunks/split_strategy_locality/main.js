@@ -0,0 +1,4 @@
+import './node_modules/pkg-a/a1.js'
+import './node_modules/pkg-a/a2.js'
+import './node_modules/pkg-b/b1.js'
+import './node_modules/pkg-b/b2.js'
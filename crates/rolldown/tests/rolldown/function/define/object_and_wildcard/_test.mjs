@@ -0,0 +1,14 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import * as main from './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, 'dist/main.js'), 'utf-8');
+
+assert.deepStrictEqual(
+  { ...main },
+  { mode: 'production', dev: false, nodeEnv: 'production', other: undefined, computed: undefined },
+);
+// Members of the object are replaced one by one, so the unused ones don't end up in the output.
+assert.ok(!code.includes('unused-value'));
+assert.ok(!code.includes('process'));
@@ -0,0 +1,5 @@
+export const mode = import.meta.env.MODE
+export const dev = import.meta.env.DEV
+export const nodeEnv = process.env.NODE_ENV
+export const other = process.env.OTHER
+export const computed = process.env['COMPUTED']
@@ -0,0 +1,15 @@
+import assert from 'node:assert';
+
+await import('./dist/main.js');
+// `cjs.js` runs between the modules before and after it, while `second.js` and `third.js`
+// still run concurrently
+assert.deepStrictEqual(globalThis.log, [
+  'first start',
+  'first end',
+  'cjs',
+  'second start',
+  'third start',
+  'third end',
+  'second end',
+  'main',
+]);
@@ -0,0 +1,2 @@
+globalThis.log.push('cjs')
+module.exports = {}
@@ -0,0 +1,5 @@
+import { log } from './log.js'
+
+log.push('first start')
+await new Promise((resolve) => setTimeout(resolve, 5))
+log.push('first end')
@@ -0,0 +1,7 @@
+import { log } from './log.js'
+import './first.js'
+import './cjs.js'
+import './second.js'
+import './third.js'
+
+log.push('main')
@@ -0,0 +1,5 @@
+import { log } from './log.js'
+
+log.push('third start')
+await new Promise((resolve) => setTimeout(resolve, 5))
+log.push('third end')
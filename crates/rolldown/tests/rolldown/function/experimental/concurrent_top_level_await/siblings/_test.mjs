@@ -0,0 +1,11 @@
+import assert from 'node:assert';
+
+await import('./dist/main.js');
+// `fast.js` starts before `slow.js` finishes, and finishes first
+assert.deepStrictEqual(globalThis.log, [
+  'slow start',
+  'fast start',
+  'fast end',
+  'slow end',
+  'main',
+]);
@@ -0,0 +1,5 @@
+import { log } from './log.js'
+
+log.push('fast start')
+await new Promise((resolve) => setTimeout(resolve, 5))
+log.push('fast end')
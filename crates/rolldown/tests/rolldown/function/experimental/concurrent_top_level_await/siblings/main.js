@@ -0,0 +1,5 @@
+import { log } from './log.js'
+import './slow.js'
+import './fast.js'
+
+log.push('main')
@@ -0,0 +1,5 @@
+import { log } from './log.js'
+
+log.push('slow start')
+await new Promise((resolve) => setTimeout(resolve, 20))
+log.push('slow end')
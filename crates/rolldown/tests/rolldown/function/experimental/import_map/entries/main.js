@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { shared } from 'shared'
+
+// The import map applies to imports only, the entry itself isn't mapped to `other.js`
+assert.strictEqual(shared, 'shared')
@@ -0,0 +1 @@
+throw new Error('the entry is not mapped')
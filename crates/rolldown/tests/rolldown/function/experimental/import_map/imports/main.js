@@ -0,0 +1,14 @@
+// Exact key to a path
+import { utils } from 'utils'
+// Prefix key, extensions are resolved as usual
+import { format } from 'lib/format'
+// The exact key wins over the prefix key
+import { special } from 'lib/special'
+// Relative specifiers are matched by the path they point to
+import { version } from './src/old.js'
+// Bare specifier to another bare specifier
+import { real } from 'alias'
+// Url, kept as an external import
+import * as React from 'react'
+
+console.log(utils, format, special, version, real, React)
@@ -0,0 +1 @@
+export const format = 'lib/format'
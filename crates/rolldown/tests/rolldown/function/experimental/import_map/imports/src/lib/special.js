@@ -0,0 +1 @@
+export const special = 'lib/special'
@@ -0,0 +1 @@
+export { dep as legacy } from 'dep'
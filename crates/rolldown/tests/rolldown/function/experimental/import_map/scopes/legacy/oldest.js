@@ -0,0 +1 @@
+export { dep as oldest } from 'dep'
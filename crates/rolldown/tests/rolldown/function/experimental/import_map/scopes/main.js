@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import { dep } from 'dep'
+import { legacy } from './legacy/index.js'
+import { oldest } from './legacy/oldest.js'
+
+// `imports` applies outside of the scopes, and the most specific scope wins
+assert.strictEqual(dep, 'v3')
+assert.strictEqual(legacy, 'v2')
+assert.strictEqual(oldest, 'v1')
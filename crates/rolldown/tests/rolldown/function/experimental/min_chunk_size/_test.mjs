@@ -0,0 +1,16 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import { result } from './dist/main.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const commonChunks = fs
+  .readdirSync(dist)
+  .filter((file) => file.endsWith('.js') && !/^(main|r1|r2|r3)\b/.test(file))
+  .map((file) => fs.readFileSync(path.join(dist, file), 'utf-8'));
+
+assert.deepStrictEqual(await result, [['s12'], ['s12', 's23'], ['s23']]);
+// `shared12.js` and `shared23.js` would each get a common chunk, which are small enough to be merged.
+assert.strictEqual(commonChunks.length, 1);
+assert.ok(commonChunks[0].includes('function s12'));
+assert.ok(commonChunks[0].includes('function s23'));
@@ -0,0 +1,3 @@
+export const result = Promise.all([import('./r1.js'), import('./r2.js'), import('./r3.js')]).then(
+  (routes) => routes.map((route) => route.default()),
+)
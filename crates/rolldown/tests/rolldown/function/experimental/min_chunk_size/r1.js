@@ -0,0 +1,3 @@
+import { s12 } from './shared12.js'
+
+export default () => [s12()]
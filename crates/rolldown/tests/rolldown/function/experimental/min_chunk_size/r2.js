@@ -0,0 +1,4 @@
+import { s12 } from './shared12.js'
+import { s23 } from './shared23.js'
+
+export default () => [s12(), s23()]
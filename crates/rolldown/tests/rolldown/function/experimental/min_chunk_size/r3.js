@@ -0,0 +1,3 @@
+import { s23 } from './shared23.js'
+
+export default () => [s23()]
@@ -0,0 +1,3 @@
+export function s12() {
+  return 's12'
+}
@@ -0,0 +1,3 @@
+export function s23() {
+  return 's23'
+}
@@ -0,0 +1,16 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import { a } from './dist/a.js';
+import { b } from './dist/b.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const runtimeChunk = fs.readdirSync(dist).find((file) => file.startsWith('rolldown-runtime'));
+
+assert.strictEqual(a, 'cjs');
+assert.strictEqual(b, 'b');
+// Only `a.js` uses the helpers, which still live in their own chunk.
+assert.ok(runtimeChunk);
+assert.ok(fs.readFileSync(path.join(dist, runtimeChunk), 'utf-8').includes('__commonJS'));
+assert.ok(fs.readFileSync(path.join(dist, 'a.js'), 'utf-8').includes(`from "./${runtimeChunk}"`));
+assert.ok(!fs.readFileSync(path.join(dist, 'b.js'), 'utf-8').includes(runtimeChunk));
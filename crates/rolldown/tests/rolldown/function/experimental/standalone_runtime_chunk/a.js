@@ -0,0 +1,3 @@
+import cjs from './cjs.js'
+
+export const a = cjs.value
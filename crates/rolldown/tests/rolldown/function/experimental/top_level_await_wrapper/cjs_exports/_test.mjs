@@ -0,0 +1,7 @@
+const require = (await import('node:module')).createRequire(import.meta.url);
+const assert = require('node:assert');
+const exports = require('./dist/main.js');
+
+// `module.exports` is a promise of the exports.
+assert.ok(exports instanceof Promise);
+assert.strictEqual((await exports).value, 'tla');
@@ -0,0 +1,6 @@
+import assert from 'node:assert';
+
+await import('./dist/main.js');
+// The chunk runs in an async function, which finishes after the import.
+await new Promise((resolve) => setTimeout(resolve));
+assert.strictEqual(globalThis.result, 'tla');
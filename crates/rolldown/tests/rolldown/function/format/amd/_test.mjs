@@ -0,0 +1,51 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const loaded = new Map();
+
+// A minimal AMD loader, with dependencies resolved relative to the loaded file.
+function load(file) {
+  if (!loaded.has(file)) {
+    let definition;
+    const define = (...args) => {
+      const factory = args.pop();
+      definition = { dependencies: args.pop() ?? [], factory };
+    };
+    define.amd = true;
+    new Function('define', fs.readFileSync(path.join(dist, file), 'utf-8'))(define);
+
+    const exports = {};
+    const localRequire = (dependencies, resolve, reject) => {
+      Promise.resolve()
+        .then(() => resolve(...dependencies.map(loadDependency)))
+        .catch(reject);
+    };
+    const loadDependency = (dependency) => {
+      if (dependency === 'exports') return exports;
+      if (dependency === 'require') return localRequire;
+      return load(path.posix.join(path.posix.dirname(file), `${dependency}.js`));
+    };
+    const result = definition.factory(...definition.dependencies.map(loadDependency));
+    loaded.set(file, definition.dependencies.includes('exports') ? exports : result);
+  }
+  return loaded.get(file);
+}
+
+const main = load('main.js');
+const code = fs.readFileSync(path.join(dist, 'main.js'), 'utf-8');
+
+assert.ok(code.startsWith("define(['exports', "));
+assert.match(code, /require\(\[["']\.\/lazy/);
+assert.strictEqual(main.value, 'shared');
+assert.strictEqual(await main.lazy, 'shared-lazy');
+// Depending on `preserveEntrySignatures`, `shared.js` gets its own chunk or is read from the entry
+// chunk. Either way, every chunk is loaded once through the dependencies of the others.
+assert.deepStrictEqual(
+  [...loaded.keys()].sort(),
+  fs
+    .readdirSync(dist)
+    .filter((file) => file.endsWith('.js'))
+    .sort(),
+);
@@ -0,0 +1,4 @@
+import { shared } from './shared.js'
+
+export const value = shared
+export const lazy = import('./lazy.js').then((mod) => mod.lazy)
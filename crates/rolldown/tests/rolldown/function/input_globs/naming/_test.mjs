@@ -0,0 +1,16 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const files = fs
+  .readdirSync(dist, { recursive: true })
+  .map((file) => file.split(path.sep).join('/'))
+  .filter((file) => file.endsWith('.js'))
+  .sort();
+
+// Entries are named after their path relative to the base of the pattern, prefixed by the name of
+// the item.
+assert.deepStrictEqual(files, ['blog/post.js', 'index.js', 'layouts/main.js']);
+assert.strictEqual((await import('./dist/blog/post.js')).page, 'blog/post');
+assert.strictEqual((await import('./dist/layouts/main.js')).layout, 'main');
@@ -0,0 +1,2 @@
+// Deeper than `src/layouts/*.js` can match, so it isn't an entry.
+export const layout = 'nested'
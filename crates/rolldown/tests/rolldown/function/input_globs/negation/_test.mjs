@@ -0,0 +1,13 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const files = fs
+  .readdirSync(dist, { recursive: true })
+  .map((file) => file.split(path.sep).join('/'))
+  .filter((file) => file.endsWith('.js'))
+  .sort();
+
+// Files matched by a pattern starting with `!` aren't entries.
+assert.deepStrictEqual(files, ['a.js', 'nested/c.js']);
@@ -1,2 +1,3 @@
 pub mod asset_filenames;
 pub mod chunk_filenames_function;
+pub mod multiple_outputs;
@@ -0,0 +1 @@
+export const lazy = 'hello from the lazy module'
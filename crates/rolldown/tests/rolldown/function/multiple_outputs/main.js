@@ -0,0 +1,6 @@
+export const message = 'hello from the main module'
+
+export async function loadLazy() {
+  const { lazy } = await import('./lazy.js')
+  return lazy
+}
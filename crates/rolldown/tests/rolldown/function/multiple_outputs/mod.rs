@@ -0,0 +1,79 @@
+use rolldown::{
+  Bundler, BundlerOptions, ExperimentalOptions, OutputFormat, OutputOptions, RawMinifyOptions,
+};
+use rolldown_common::Output;
+use rolldown_testing::abs_file_dir;
+
+fn chunk_codes(assets: &[Output]) -> Vec<&str> {
+  assets
+    .iter()
+    .filter_map(|asset| match asset {
+      Output::Chunk(chunk) => Some(chunk.code.as_str()),
+      Output::Asset(_) => None,
+    })
+    .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn generate_from_a_single_scan() {
+  let mut bundler = Bundler::new(BundlerOptions {
+    cwd: Some(abs_file_dir!()),
+    input: Some(vec!["main.js".to_string().into()]),
+    ..Default::default()
+  })
+  .unwrap();
+
+  // The two ESM outputs share a link output, while the IIFE output inlines dynamic imports, so it
+  // links the modules separately.
+  let outputs = bundler
+    .generate_outputs(vec![
+      OutputOptions { format: Some(OutputFormat::Esm), ..Default::default() },
+      OutputOptions {
+        format: Some(OutputFormat::Esm),
+        minify: Some(RawMinifyOptions::Bool(true)),
+        ..Default::default()
+      },
+      OutputOptions {
+        format: Some(OutputFormat::Iife),
+        name: Some("lib".to_string()),
+        ..Default::default()
+      },
+    ])
+    .await
+    .unwrap();
+  assert_eq!(outputs.len(), 3);
+
+  let esm = chunk_codes(&outputs[0].assets);
+  assert_eq!(esm.len(), 2);
+  assert!(esm[0].contains("export {"));
+  assert!(esm[0].contains("import(\"./lazy-"));
+
+  let minified_esm = chunk_codes(&outputs[1].assets);
+  assert_eq!(minified_esm.len(), 2);
+  assert!(minified_esm[0].len() < esm[0].len());
+
+  let iife = chunk_codes(&outputs[2].assets);
+  assert_eq!(iife.len(), 1);
+  assert!(iife[0].starts_with("var lib = (function"));
+  assert!(iife[0].contains("hello from the lazy module"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reject_incremental_build() {
+  let mut bundler = Bundler::new(BundlerOptions {
+    cwd: Some(abs_file_dir!()),
+    input: Some(vec!["main.js".to_string().into()]),
+    experimental: Some(ExperimentalOptions { incremental_build: Some(true), ..Default::default() }),
+    ..Default::default()
+  })
+  .unwrap();
+
+  let Err(errors) = bundler.generate_outputs(vec![OutputOptions::default()]).await else {
+    panic!("multiple outputs should be rejected with incremental build");
+  };
+  assert!(
+    errors
+      .iter()
+      .any(|error| error.to_diagnostic().to_string().contains("not supported with incremental"))
+  );
+}
@@ -0,0 +1,13 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import { kept, result } from './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, 'dist/main.js'), 'utf-8');
+
+assert.deepStrictEqual(result, [1, 2, 'red', 'green', 1]);
+assert.strictEqual(kept.B, 2);
+// All uses of `Direction` and `Color` are inlined, so the enum objects are removed.
+assert.ok(!code.includes('Direction'));
+assert.ok(!code.includes('Color'));
+assert.ok(code.includes('Kept'));
@@ -0,0 +1,14 @@
+export enum Direction {
+  Up = 1,
+  Down,
+}
+
+export const enum Color {
+  Red = 'red',
+  Green = 'green',
+}
+
+export enum Kept {
+  A = 1,
+  B = A * 2,
+}
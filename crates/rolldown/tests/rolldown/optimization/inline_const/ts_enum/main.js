@@ -0,0 +1,6 @@
+import { Color, Direction, Kept } from './enums.ts'
+
+export const result = [Direction.Up, Direction.Down, Color.Red, Color.Green, Kept.A]
+
+// Used as an object, so the enum is kept.
+export const kept = Kept
@@ -0,0 +1,16 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const html = fs.readFileSync(path.join(dist, 'index.html'), 'utf-8');
+
+// The entry chunk is smaller than `inlineScriptLimit` and imports nothing, so it is inlined. The
+// `package.json` is written by the test runner.
+assert.deepStrictEqual(
+  fs.readdirSync(dist).filter((file) => file !== 'package.json'),
+  ['index.html'],
+);
+assert.ok(!html.includes('src='));
+assert.ok(html.includes('<script type="module">\n'));
+assert.ok(html.includes('globalThis.main = "main"'));
@@ -0,0 +1 @@
+globalThis.main = 'main'
@@ -0,0 +1,22 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/index.js';
+import './dist/about.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const sharedChunk = fs.readdirSync(dist).find((file) => file.startsWith('shared'));
+
+assert.strictEqual(globalThis.main, 'shared-main');
+assert.strictEqual(globalThis.about, 'shared-about');
+assert.ok(sharedChunk);
+
+// Both entries import the shared chunk, so they aren't inlined and preload it.
+// The urls are relative to each HTML file, which keeps its location. `/about.js` is resolved
+// from the directory of `about/index.html`.
+const html = fs.readFileSync(path.join(dist, 'index.html'), 'utf-8');
+assert.ok(html.includes('<script type="module" crossorigin src="index.js"></script>'));
+assert.ok(html.includes(`<link rel="modulepreload" crossorigin href="${sharedChunk}">`));
+const aboutHtml = fs.readFileSync(path.join(dist, 'about/index.html'), 'utf-8');
+assert.ok(aboutHtml.includes('<script type="module" crossorigin src="../about.js"></script>'));
+assert.ok(aboutHtml.includes(`<link rel="modulepreload" crossorigin href="../${sharedChunk}">`));
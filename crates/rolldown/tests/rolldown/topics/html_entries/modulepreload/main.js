@@ -0,0 +1,3 @@
+import { shared } from './shared.js'
+
+globalThis.main = shared + 'main'
@@ -0,0 +1,25 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/index.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const html = fs.readFileSync(path.join(dist, 'index.html'), 'utf-8');
+
+// The module scripts are bundled into the entry chunk, which replaces their tags.
+assert.strictEqual(globalThis.main, 'main');
+assert.strictEqual(globalThis.inline, 'inline');
+assert.ok(!html.includes('./main.js'));
+assert.ok(!html.includes("globalThis.inline = 'inline'"));
+assert.ok(html.includes('<script type="module" crossorigin src="index.js"></script>'));
+
+// The stylesheet is bundled, while the one with `media` is left alone.
+assert.ok(!html.includes('href="./style.css"'));
+assert.ok(html.includes('href="./print.css"'));
+const [, css] = html.match(/<link rel="stylesheet" crossorigin href="([^"]+\.css)">/);
+assert.ok(fs.readFileSync(path.join(dist, css), 'utf-8').includes('color: red'));
+
+// Other assets are emitted and their urls rewritten.
+const [, icon] = html.match(/<link rel="icon" href="([^"]+)" \/>/);
+assert.notStrictEqual(icon, './logo.svg');
+assert.ok(fs.readFileSync(path.join(dist, icon), 'utf-8').includes('<svg'));
@@ -0,0 +1,11 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+
+assert.deepStrictEqual(globalThis.result, [11, 11, 2]);
+// `_x` is also accessed with a quoted name, so it keeps its name everywhere.
+assert.ok(code.includes('obj._x'));
+assert.ok(!code.includes('_y'));
@@ -0,0 +1,4 @@
+const obj = { _x: 1, _y: 2 };
+obj._x += 10;
+
+globalThis.result = [obj._x, obj['_x'], obj._y];
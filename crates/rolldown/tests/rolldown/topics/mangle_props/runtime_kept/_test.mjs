@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+
+// The runtime reads `__esModule` to interop with CommonJS, so it keeps its name everywhere.
+assert.strictEqual(globalThis.result, 'default');
+assert.ok(code.includes('exports.__esModule'));
@@ -0,0 +1,3 @@
+import value from './cjs.js';
+
+globalThis.result = value;
@@ -0,0 +1,8 @@
+import assert from 'node:assert';
+
+const container = await import('./dist/remoteEntry.js');
+
+container.init({});
+const factory = await container.get('./greet');
+assert.strictEqual(factory().greet('rolldown'), 'hello rolldown');
+await assert.rejects(container.get('./missing'), /isn't exposed by the "app" container/);
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import './dist/main.js';
+
+assert.deepStrictEqual(globalThis.result, ['hello before', 'hello after']);
+assert.ok(globalThis.remoteShareScope);
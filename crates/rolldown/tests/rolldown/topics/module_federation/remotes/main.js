@@ -0,0 +1,6 @@
+// Runs before the import in source order, so the remote has to be loaded up front.
+globalThis.result = [greet('before')]
+
+import { greet } from 'app2/greet'
+
+globalThis.result.push(greet('after'))
@@ -0,0 +1,11 @@
+// A container built separately, implementing the same protocol as the generated remote entries.
+export function init(shareScope) {
+  globalThis.remoteShareScope = shareScope
+}
+
+export async function get(module) {
+  if (module !== './greet') {
+    throw new Error(`Module "${module}" isn't exposed by the "app2" container.`)
+  }
+  return () => ({ greet: (name) => `hello ${name}` })
+}
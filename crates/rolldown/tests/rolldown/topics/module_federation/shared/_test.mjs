@@ -0,0 +1,18 @@
+import assert from 'node:assert';
+
+const container = await import('./dist/remoteEntry.js');
+
+// The share scope of a host providing other versions of `dep`.
+const shareScope = {
+  dep: {
+    '1.2.0': { from: 'host', get: async () => ({ value: 'host' }), loaded: undefined },
+    '2.0.0': { from: 'other', get: async () => ({ value: 'other' }), loaded: undefined },
+  },
+};
+container.init(shareScope);
+// The version provided by this build joins the share scope of the host.
+assert.strictEqual(shareScope.dep['1.0.0'].from, 'app');
+
+// The highest version that satisfies `^1.0.0` is used.
+const factory = await container.get('./value');
+assert.strictEqual(factory().value, 'host');
@@ -0,0 +1,3 @@
+import { value } from 'dep'
+
+globalThis.result = value
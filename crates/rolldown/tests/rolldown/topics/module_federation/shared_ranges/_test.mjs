@@ -0,0 +1,25 @@
+import assert from 'node:assert';
+
+const container = await import('./dist/remoteEntry.js');
+
+const provide = (versions) =>
+  Object.fromEntries(
+    Object.entries(versions).map(([version, value]) => [
+      version,
+      { from: 'host', get: async () => ({ value }), loaded: undefined },
+    ]),
+  );
+
+// The share scope of a host providing other versions of the shared modules.
+container.init({
+  x_range: provide({ '1.9.0': '1.9.0', '2.0.0': '2.0.0' }),
+  hyphen_range: provide({ '1.1.0': '1.1.0', '2.9.0': '2.9.0', '3.0.0': '3.0.0' }),
+  workspace_dep: provide({ '3.0.0': '3.0.0' }),
+});
+
+// The highest version that satisfies the range is used.
+const { xRange, hyphenRange, workspaceDep } = (await container.get('./value'))();
+assert.strictEqual(xRange, '1.9.0');
+// `1.2 - 2` includes every `2.x` version.
+assert.strictEqual(hyphenRange, '2.9.0');
+assert.strictEqual(workspaceDep, '3.0.0');
@@ -0,0 +1,3 @@
+import { value } from 'x_range'
+
+globalThis.result = value
@@ -0,0 +1,5 @@
+import { value as xRange } from 'x_range'
+import { value as hyphenRange } from 'hyphen_range'
+import { value as workspaceDep } from 'workspace_dep'
+
+export { xRange, hyphenRange, workspaceDep }
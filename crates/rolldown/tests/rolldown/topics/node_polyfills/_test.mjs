@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import './dist/main.js';
+
+assert.deepStrictEqual(globalThis.result, {
+  importedBuffer: true,
+  events: true,
+  fs: [],
+  process: 'process-polyfill',
+  buffer: true,
+});
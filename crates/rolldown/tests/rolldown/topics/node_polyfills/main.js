@@ -0,0 +1,12 @@
+import { Buffer as ImportedBuffer } from 'node:buffer'
+import { EventEmitter } from 'events'
+import * as fs from 'fs'
+
+globalThis.result = {
+  importedBuffer: ImportedBuffer.polyfilled,
+  events: EventEmitter.polyfilled,
+  fs: Object.keys(fs),
+  // Referenced as globals, which are injected from the polyfills
+  process: process.env.POLYFILLED,
+  buffer: Buffer.polyfilled,
+}
@@ -0,0 +1,17 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+
+assert.deepStrictEqual(
+  globalThis.result.map((module) => module.a ?? module.b),
+  ['shared-a', 'shared-b'],
+);
+// Dynamic imports are inlined into a single chunk, which leaves nothing to preload.
+assert.deepStrictEqual(
+  fs.readdirSync(dist).filter((file) => file.endsWith('.js')),
+  ['main.js'],
+);
+assert.ok(!fs.readFileSync(path.join(dist, 'main.js'), 'utf-8').includes('__preload'));
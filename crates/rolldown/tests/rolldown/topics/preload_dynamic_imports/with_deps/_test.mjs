@@ -0,0 +1,17 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+const main = fs.readFileSync(path.join(dist, 'main.js'), 'utf-8');
+const sharedChunk = fs.readdirSync(dist).find((file) => file.startsWith('shared'));
+
+assert.deepStrictEqual(
+  globalThis.result.map((module) => module.a ?? module.b),
+  ['shared-a', 'shared-b'],
+);
+// Both dynamically imported chunks import the shared chunk, which is preloaded with them.
+assert.ok(sharedChunk);
+assert.strictEqual(main.match(/__preload\(\(\) =>/g).length, 2);
+assert.ok(main.includes(`"./${sharedChunk}"`));
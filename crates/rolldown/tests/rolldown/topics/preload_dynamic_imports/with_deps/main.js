@@ -0,0 +1 @@
+globalThis.result = await Promise.all([import('./a.js'), import('./b.js')])
@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const dist = path.resolve(import.meta.dirname, 'dist');
+
+assert.strictEqual(globalThis.result.lazy, 'lazy');
+// The imported chunk has no dependencies, so neither the call nor the helper are emitted.
+for (const file of fs.readdirSync(dist)) {
+  assert.ok(!fs.readFileSync(path.join(dist, file), 'utf-8').includes('__preload'), file);
+}
@@ -0,0 +1 @@
+globalThis.result = await import('./lazy.js')
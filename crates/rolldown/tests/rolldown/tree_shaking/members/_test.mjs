@@ -0,0 +1,11 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+
+assert.deepStrictEqual(globalThis.result, ['user 1', 'loaded']);
+assert.strictEqual(globalThis.escaping.kept(), 'kept');
+assert.ok(!code.includes('deleteUser'));
+assert.ok(!code.includes('reset'));
@@ -0,0 +1,17 @@
+export const api = {
+  getUser: (id) => `user ${id}`,
+  deleteUser: (id) => `deleted ${id}`,
+};
+
+export class Store {
+  static load() {
+    return 'loaded';
+  }
+  static reset() {
+    return 'reset';
+  }
+}
+
+export const escaping = {
+  kept: () => 'kept',
+};
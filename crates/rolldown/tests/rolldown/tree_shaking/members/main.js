@@ -0,0 +1,4 @@
+import { api, escaping, Store } from './api.js';
+
+globalThis.result = [api.getUser(1), Store.load()];
+globalThis.escaping = escaping;
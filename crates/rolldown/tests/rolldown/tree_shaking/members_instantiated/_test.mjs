@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+
+assert.deepStrictEqual(globalThis.result, ['hello user', 'user']);
+// `rename` is never read on any object, `toString` could be called implicitly.
+assert.ok(!code.includes('rename'));
+assert.ok(code.includes('toString'));
+assert.ok(!code.includes('unused'));
@@ -0,0 +1,4 @@
+import { User } from './user.js';
+
+const user = new User();
+globalThis.result = [user.greet(), `${User.create()}`];
@@ -0,0 +1,18 @@
+export class User {
+  name = 'user';
+  greet() {
+    return `hello ${this.name}`;
+  }
+  rename(name) {
+    this.name = name;
+  }
+  toString() {
+    return this.name;
+  }
+  static create() {
+    return new User();
+  }
+  static unused() {
+    return 'unused';
+  }
+}
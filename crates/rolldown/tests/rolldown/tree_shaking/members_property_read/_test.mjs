@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+import './dist/main.js';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+
+assert.strictEqual(globalThis.result, 'debug');
+// Reading `config.verbose` has no side effects, so the statement and the member are removed.
+assert.ok(!code.includes('verbose'));
@@ -0,0 +1,4 @@
+export const config = {
+  debug: 'debug',
+  verbose: 'verbose',
+};
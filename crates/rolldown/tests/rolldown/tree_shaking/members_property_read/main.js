@@ -0,0 +1,4 @@
+import { config } from './config.js';
+
+config.verbose;
+globalThis.result = config.debug;
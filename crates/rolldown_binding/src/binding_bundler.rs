@@ -2,6 +2,7 @@
 
 use crate::{
   classic_bundler::ClassicBundler,
+  options::BindingOutputOptions,
   types::{
     binding_bundler_options::BindingBundlerOptions,
     binding_outputs::{BindingOutputs, to_binding_error},
@@ -9,7 +10,9 @@ use crate::{
   },
   utils::{
     handle_result, handle_warnings,
-    normalize_binding_options::{NormalizeBindingOptionsReturn, normalize_binding_options},
+    normalize_binding_options::{
+      NormalizeBindingOptionsReturn, normalize_binding_options, normalize_binding_output_options,
+    },
   },
 };
 use napi::{Env, bindgen_prelude::PromiseRaw};
@@ -128,6 +131,29 @@ impl BindingBundler {
     env.spawn_future(fut)
   }
 
+  /// Scans the modules once and generates the output of `options` and each of
+  /// `additional_outputs`.
+  #[napi]
+  pub fn generate_outputs<'env>(
+    &mut self,
+    env: &'env Env,
+    options: BindingBundlerOptions<'env>,
+    additional_outputs: Vec<BindingOutputOptions<'env>>,
+  ) -> napi::Result<PromiseRaw<'env, BindingResult<Vec<BindingOutputs>>>> {
+    self.bundle_outputs(env, options, additional_outputs, /* is_write */ false)
+  }
+
+  /// Scans the modules once and writes the output of `options` and each of `additional_outputs`.
+  #[napi]
+  pub fn write_outputs<'env>(
+    &mut self,
+    env: &'env Env,
+    options: BindingBundlerOptions<'env>,
+    additional_outputs: Vec<BindingOutputOptions<'env>>,
+  ) -> napi::Result<PromiseRaw<'env, BindingResult<Vec<BindingOutputs>>>> {
+    self.bundle_outputs(env, options, additional_outputs, /* is_write */ true)
+  }
+
   #[napi]
   pub fn scan<'env>(
     &mut self,
@@ -202,6 +228,75 @@ impl BindingBundler {
 }
 
 impl BindingBundler {
+  fn bundle_outputs<'env>(
+    &mut self,
+    env: &'env Env,
+    options: BindingBundlerOptions<'env>,
+    additional_outputs: Vec<BindingOutputOptions<'env>>,
+    is_write: bool,
+  ) -> napi::Result<PromiseRaw<'env, BindingResult<Vec<BindingOutputs>>>> {
+    let normalized = Self::normalize_binding_options(options)?;
+    let api_name = if is_write { "writeOutputs" } else { "generateOutputs" };
+    if let Some(result) = Self::validate_hmr_not_allowed(&normalized, api_name) {
+      return env.spawn_future(async move { Ok(result) });
+    }
+    let additional_outputs = additional_outputs
+      .into_iter()
+      .map(|output| {
+        if !output.plugins.is_empty() {
+          return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "Only the first output can have `plugins` when generating multiple outputs",
+          ));
+        }
+        normalize_binding_output_options(output)
+      })
+      .collect::<napi::Result<Vec<_>>>()?;
+
+    let maybe_bundle = self.inner.create_bundle_with_outputs(
+      normalized.bundler_options,
+      normalized.plugins,
+      additional_outputs,
+    );
+    if let Ok((bundle, _)) = &maybe_bundle {
+      // Extract bundle handle before consuming the bundle
+      self.last_bundle_handle = Some(bundle.context());
+    }
+
+    let fut = async move {
+      let (bundle, outputs_options) = maybe_bundle.map_err(|err| {
+        napi::Error::new(
+          napi::Status::GenericFailure,
+          err.iter().map(|e| e.to_diagnostic().to_string()).collect::<Vec<_>>().join("\n"),
+        )
+      })?;
+      let cwd = bundle.options().cwd.clone();
+      let options = Arc::clone(bundle.options());
+      let bundle_outputs = match bundle.generate_multiple(outputs_options, is_write).await {
+        Ok(outputs) => outputs,
+        Err(errs) => {
+          let errors: Vec<BindingError> = errs
+            .into_vec()
+            .iter()
+            .map(|diagnostic| to_binding_error(diagnostic, cwd.clone()))
+            .collect();
+          return Ok(napi::Either::A(BindingErrors::new(errors)));
+        }
+      };
+
+      let mut outputs = Vec::with_capacity(bundle_outputs.len());
+      for bundle_output in bundle_outputs {
+        if let Err(err) = handle_warnings(bundle_output.warnings, &options).await {
+          let error = to_binding_error(&err.into(), cwd.clone());
+          return Ok(napi::Either::A(BindingErrors::new(vec![error])));
+        }
+        outputs.push(bundle_output.assets.into());
+      }
+      Ok(napi::Either::B(outputs))
+    };
+    env.spawn_future(fut)
+  }
+
   fn normalize_binding_options(
     option: BindingBundlerOptions,
   ) -> napi::Result<NormalizeBindingOptionsReturn> {
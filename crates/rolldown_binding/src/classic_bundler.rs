@@ -59,7 +59,10 @@
 /// - Both `ClassicBundler` and core `Bundler` benefit from `Bundle` improvements
 /// - The codebase maintains clear separation of concerns, preventing the wrong mental model that caused bugs previously
 /// - Development is more maintainable as changes are made at the appropriate abstraction level
-use rolldown::{Bundle, BundleFactory, BundleFactoryOptions, BundleHandle, BundlerOptions};
+use rolldown::{
+  Bundle, BundleFactory, BundleFactoryOptions, BundleHandle, BundlerOptions, OutputOptions,
+  SharedNormalizedBundlerOptions,
+};
 use rolldown_common::BundleMode;
 use rolldown_error::BuildResult;
 use rolldown_plugin::__inner::SharedPluginable;
@@ -109,6 +112,38 @@ impl ClassicBundler {
     Ok(bundle)
   }
 
+  /// Like `create_bundle`, but also returns the options of each output to pass to
+  /// `Bundle::generate_multiple`: the output of `bundler_options`, then `additional_outputs`.
+  pub fn create_bundle_with_outputs(
+    &mut self,
+    bundler_options: BundlerOptions,
+    plugins: Vec<SharedPluginable>,
+    additional_outputs: Vec<OutputOptions>,
+  ) -> BuildResult<(Bundle, Vec<SharedNormalizedBundlerOptions>)> {
+    if self.closed {
+      return Err(rolldown_error::BuildDiagnostic::already_closed().into());
+    }
+    self.enable_debug_tracing_if_needed(&bundler_options);
+
+    let mut bundle_factory = BundleFactory::new(BundleFactoryOptions {
+      bundler_options,
+      plugins,
+      session: Some(self.session.clone()),
+      disable_tracing_setup: true,
+    })?;
+
+    let mut outputs_options = vec![Arc::clone(&bundle_factory.options)];
+    for output in additional_outputs {
+      outputs_options.push(bundle_factory.normalize_output_options(output)?);
+    }
+
+    let bundle = bundle_factory.create_bundle(BundleMode::FullBuild, None)?;
+
+    self.last_bundle_handle = Some(bundle.context());
+
+    Ok((bundle, outputs_options))
+  }
+
   #[must_use = "Future must be awaited to do the actual cleanup work"]
   pub fn close(&mut self) -> impl Future<Output = anyhow::Result<()>> + Send + 'static {
     let is_closed = self.closed;
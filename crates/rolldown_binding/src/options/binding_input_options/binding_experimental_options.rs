@@ -1,4 +1,14 @@
-use napi::bindgen_prelude::Either;
+use std::{collections::HashMap, sync::Arc};
+
+use napi::bindgen_prelude::{Buffer, Either, FnArgs};
+use rolldown_common::{RemoteFetcher, RemoteFetcherFn};
+use rolldown_utils::js_regex::HybridRegex;
+use rustc_hash::FxBuildHasher;
+
+use crate::types::{
+  js_callback::{MaybeAsyncJsCallback, MaybeAsyncJsCallbackExt as _},
+  js_regex::JsRegExp,
+};
 
 #[napi_derive::napi(object, object_to_js = false)]
 #[derive(Debug, Default)]
@@ -16,6 +26,22 @@ pub struct BindingExperimentalOptions {
   #[napi(ts_type = "boolean | 'boundary'")]
   pub transform_hires_sourcemap: Option<Either<bool, String>>,
   pub native_magic_string: Option<bool>,
+  pub resolve_trace: Option<bool>,
+  pub node_polyfills: Option<BindingNodePolyfillsOptions>,
+  pub standalone_runtime_chunk: Option<bool>,
+  pub min_chunk_size: Option<Either<f64, BindingMinChunkSizeOptions>>,
+  pub preload_dynamic_imports: Option<bool>,
+  pub name_cache: Option<String>,
+  pub mangle_props: Option<BindingManglePropsOptions>,
+  pub commonjs_to_esm: Option<bool>,
+  pub detect_external_commonjs_exports: Option<bool>,
+  pub concurrent_top_level_await: Option<bool>,
+  pub top_level_await_wrapper: Option<bool>,
+  pub preserve_directives: Option<bool>,
+  pub html_entries: Option<Either<bool, BindingHtmlEntriesOptions>>,
+  pub import_map: Option<BindingImportMap>,
+  pub remote_imports: Option<BindingRemoteImportsOptions>,
+  pub module_federation: Option<BindingModuleFederationOptions>,
 }
 
 impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOptions {
@@ -54,6 +80,28 @@ impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOption
         None
       },
       native_magic_string: value.native_magic_string,
+      resolve_trace: value.resolve_trace,
+      node_polyfills: value.node_polyfills.map(Into::into),
+      standalone_runtime_chunk: value.standalone_runtime_chunk,
+      min_chunk_size: value.min_chunk_size.map(|v| match v {
+        Either::A(min_size) => rolldown_common::MinChunkSizeOptions { min_size, tolerance: None },
+        Either::B(v) => v.into(),
+      }),
+      preload_dynamic_imports: value.preload_dynamic_imports,
+      name_cache: value.name_cache,
+      mangle_props: value.mangle_props.map(TryInto::try_into).transpose()?,
+      commonjs_to_esm: value.commonjs_to_esm,
+      detect_external_commonjs_exports: value.detect_external_commonjs_exports,
+      concurrent_top_level_await: value.concurrent_top_level_await,
+      top_level_await_wrapper: value.top_level_await_wrapper,
+      preserve_directives: value.preserve_directives,
+      html_entries: value.html_entries.and_then(|v| match v {
+        Either::A(v) => v.then_some(rolldown_common::HtmlEntriesOptions::default()),
+        Either::B(v) => Some(v.into()),
+      }),
+      import_map: value.import_map.map(Into::into),
+      remote_imports: value.remote_imports.map(Into::into),
+      module_federation: value.module_federation.map(Into::into),
     })
   }
 }
@@ -118,3 +166,169 @@ impl From<BindingChunkImportMap> for rolldown_common::ChunkImportMap {
     Self { base_url: value.base_url, file_name: value.file_name }
   }
 }
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingHtmlEntriesOptions {
+  pub inline_script_limit: Option<u32>,
+}
+
+impl From<BindingHtmlEntriesOptions> for rolldown_common::HtmlEntriesOptions {
+  fn from(value: BindingHtmlEntriesOptions) -> Self {
+    Self { inline_script_limit: value.inline_script_limit }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingImportMap {
+  pub imports: Option<HashMap<String, String, FxBuildHasher>>,
+  pub scopes: Option<HashMap<String, HashMap<String, String, FxBuildHasher>, FxBuildHasher>>,
+}
+
+impl From<BindingImportMap> for rolldown_common::ImportMap {
+  fn from(value: BindingImportMap) -> Self {
+    Self { imports: value.imports, scopes: value.scopes }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(derive_more::Debug, Default)]
+pub struct BindingRemoteImportsOptions {
+  pub cache_dir: Option<String>,
+  pub lockfile: Option<String>,
+  pub offline: Option<bool>,
+  pub fetch_dir: Option<String>,
+  #[debug(skip)]
+  #[napi(ts_type = "(url: string) => MaybePromise<string | Uint8Array>")]
+  pub fetch: Option<MaybeAsyncJsCallback<FnArgs<(String,)>, Either<String, Buffer>>>,
+}
+
+impl From<BindingRemoteImportsOptions> for rolldown_common::RemoteImportsOptions {
+  fn from(value: BindingRemoteImportsOptions) -> Self {
+    Self {
+      cache_dir: value.cache_dir,
+      lockfile: value.lockfile,
+      offline: value.offline,
+      fetch_dir: value.fetch_dir,
+      fetcher: value.fetch.map(|fetch| {
+        let fetcher: Arc<RemoteFetcherFn> = Arc::new(move |url: String| {
+          let fetch = Arc::clone(&fetch);
+          Box::pin(async move {
+            Ok(match fetch.await_call((url,).into()).await? {
+              Either::A(text) => text.into_bytes(),
+              Either::B(buffer) => buffer.to_vec(),
+            })
+          })
+        });
+        RemoteFetcher::new(fetcher)
+      }),
+    }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingModuleFederationOptions {
+  pub name: String,
+  pub filename: Option<String>,
+  pub exposes: Option<HashMap<String, String, FxBuildHasher>>,
+  pub remotes: Option<HashMap<String, String, FxBuildHasher>>,
+  pub shared: Option<HashMap<String, BindingSharedModuleOptions, FxBuildHasher>>,
+}
+
+impl From<BindingModuleFederationOptions> for rolldown_common::ModuleFederationOptions {
+  fn from(value: BindingModuleFederationOptions) -> Self {
+    Self {
+      name: value.name,
+      filename: value.filename,
+      exposes: value.exposes.map(|exposes| exposes.into_iter().collect()),
+      remotes: value.remotes.map(|remotes| remotes.into_iter().collect()),
+      shared: value
+        .shared
+        .map(|shared| shared.into_iter().map(|(name, config)| (name, config.into())).collect()),
+    }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingSharedModuleOptions {
+  pub singleton: Option<bool>,
+  pub required_version: Option<String>,
+  pub version: Option<String>,
+}
+
+impl From<BindingSharedModuleOptions> for rolldown_common::SharedModuleOptions {
+  fn from(value: BindingSharedModuleOptions) -> Self {
+    Self {
+      singleton: value.singleton,
+      required_version: value.required_version,
+      version: value.version,
+    }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingNodePolyfillsOptions {
+  #[napi(ts_type = "Record<string, string | boolean>")]
+  pub modules: Option<HashMap<String, Either<String, bool>, FxBuildHasher>>,
+  pub globals: Option<bool>,
+}
+
+impl From<BindingNodePolyfillsOptions> for rolldown_common::NodePolyfillsOptions {
+  fn from(value: BindingNodePolyfillsOptions) -> Self {
+    Self {
+      modules: value.modules.map(|modules| {
+        modules
+          .into_iter()
+          .map(|(name, polyfill)| match polyfill {
+            Either::A(package) => (name, Some(package)),
+            // `true` uses the npm package with the same name as the built-in, e.g. `buffer`
+            Either::B(true) => (name.clone(), Some(name)),
+            Either::B(false) => (name, None),
+          })
+          .collect()
+      }),
+      globals: value.globals,
+    }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingMinChunkSizeOptions {
+  pub min_size: f64,
+  pub tolerance: Option<f64>,
+}
+
+impl From<BindingMinChunkSizeOptions> for rolldown_common::MinChunkSizeOptions {
+  fn from(value: BindingMinChunkSizeOptions) -> Self {
+    Self { min_size: value.min_size, tolerance: value.tolerance }
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingManglePropsOptions {
+  #[napi(ts_type = "RegExp")]
+  pub regex: JsRegExp,
+  pub reserved: Option<Vec<String>>,
+  pub quoted: Option<bool>,
+  pub cache: Option<String>,
+}
+
+impl TryFrom<BindingManglePropsOptions> for rolldown_common::ManglePropsOptions {
+  type Error = napi::Error;
+
+  fn try_from(value: BindingManglePropsOptions) -> Result<Self, Self::Error> {
+    let regex = HybridRegex::try_from(value.regex).map_err(|err| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("Invalid value for `experimental.mangleProps.regex` option: {err}"),
+      )
+    })?;
+    Ok(Self { regex, reserved: value.reserved, quoted: value.quoted, cache: value.cache })
+  }
+}
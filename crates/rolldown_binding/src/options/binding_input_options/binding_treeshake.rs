@@ -48,6 +48,7 @@ pub struct BindingTreeshake {
   pub commonjs: Option<bool>,
   pub property_read_side_effects: Option<BindingPropertyReadSideEffects>,
   pub property_write_side_effects: Option<BindingPropertyWriteSideEffects>,
+  pub members: Option<bool>,
 }
 
 #[napi_derive::napi(object, object_to_js = false)]
@@ -109,6 +110,7 @@ impl TryFrom<BindingTreeshake> for rolldown::TreeshakeOptions {
       commonjs: value.commonjs,
       property_read_side_effects,
       property_write_side_effects,
+      members: value.members,
     }))
   }
 
@@ -17,6 +17,10 @@ pub struct BindingAdvancedChunksOptions {
   pub max_size: Option<f64>,
   pub min_module_size: Option<f64>,
   pub max_module_size: Option<f64>,
+  #[napi(ts_type = "'source' | 'rendered' | 'minified' | 'gzip'")]
+  pub size_metric: Option<String>,
+  #[napi(ts_type = "'size' | 'locality'")]
+  pub split_strategy: Option<String>,
 }
 
 type BindingMatchGroupTest =
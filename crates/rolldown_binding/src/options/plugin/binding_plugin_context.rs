@@ -11,7 +11,7 @@ use super::types::{
   binding_emitted_prebuilt_chunk::BindingEmittedPrebuiltChunk,
   binding_hook_side_effects::BindingHookSideEffects,
   binding_plugin_context_resolve_options::BindingPluginContextResolveOptions,
-  binding_resolved_external::BindingResolvedExternal,
+  binding_resolve_trace::BindingResolveTrace, binding_resolved_external::BindingResolvedExternal,
 };
 
 use crate::{types::binding_module_info::BindingModuleInfo, utils::napi_error};
@@ -70,6 +70,7 @@ impl BindingPluginContext {
       // TODO: should use `&str` instead. (claude code) Attempt failed due to PathBuf conversion requires to_string_lossy()
       package_json_path:
         info.package_json.map(|item| item.realpath().to_string_lossy().to_string()),
+      resolve_trace: info.resolve_trace.as_deref().map(Into::into),
     }))
   }
 
@@ -150,4 +151,5 @@ pub struct BindingPluginContextResolvedId {
   pub external: BindingResolvedExternal,
   #[napi(ts_type = "boolean | 'no-treeshake'")]
   pub module_side_effects: Option<BindingHookSideEffects>,
+  pub resolve_trace: Option<BindingResolveTrace>,
 }
@@ -0,0 +1,25 @@
+use rolldown_common::ResolveTrace;
+
+/// How a specifier was resolved, only present when `experimental.resolveTrace` is enabled.
+#[napi_derive::napi(object, object_from_js = false)]
+pub struct BindingResolveTrace {
+  /// Every step, formatted the same way as in resolution diagnostics.
+  pub steps: Vec<String>,
+  /// The candidate paths that were tried but didn't exist.
+  pub candidates: Vec<String>,
+  /// The plugin whose `resolveId` hook resolved the specifier.
+  pub resolved_by_plugin: Option<String>,
+  /// The conditions that selected the target of the `exports` or `imports` entry used.
+  pub matched_conditions: Option<Vec<String>>,
+}
+
+impl From<&ResolveTrace> for BindingResolveTrace {
+  fn from(trace: &ResolveTrace) -> Self {
+    Self {
+      steps: trace.steps.iter().map(ToString::to_string).collect(),
+      candidates: trace.candidates().map(|path| path.to_string_lossy().into_owned()).collect(),
+      resolved_by_plugin: trace.resolved_by_plugin().map(ToString::to_string),
+      matched_conditions: trace.matched_conditions().map(<[String]>::to_vec),
+    }
+  }
+}
@@ -126,13 +126,14 @@ impl BindingNormalizedOptions {
     self.inner.file.as_deref()
   }
 
-  #[napi(getter, ts_return_type = "'es' | 'cjs' | 'iife' | 'umd'")]
+  #[napi(getter, ts_return_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd'")]
   pub fn format(&self) -> &'static str {
     match self.inner.format {
       rolldown::OutputFormat::Esm => "es",
       rolldown::OutputFormat::Cjs => "cjs",
       rolldown::OutputFormat::Iife => "iife",
       rolldown::OutputFormat::Umd => "umd",
+      rolldown::OutputFormat::Amd => "amd",
     }
   }
 
@@ -14,13 +14,13 @@ use crate::{
 };
 use napi::bindgen_prelude::{Either, Either3, FnArgs};
 use rolldown::{
-  AddonOutputOption, AdvancedChunksOptions, AssetFilenamesOutputOption, BundlerOptions,
+  AddonOutputOption, AdvancedChunksOptions, AmdOptions, AssetFilenamesOutputOption, BundlerOptions,
   ChunkFilenamesOutputOption, DeferSyncScanDataOption, HashCharacters, IsExternal, MatchGroup,
   MatchGroupName, ModuleType, OptimizationOption, OutputExports, OutputFormat, Platform,
   RawMinifyOptions, RawMinifyOptionsDetailed, SanitizeFilename, TsConfig,
 };
 use rolldown_common::DeferSyncScanData;
-use rolldown_common::GeneratedCodeOptions;
+use rolldown_common::{GeneratedCodeOptions, OutputOptions};
 use rolldown_plugin::__inner::SharedPluginable;
 use rolldown_utils::indexmap::FxIndexMap;
 use rolldown_utils::rustc_hash::FxHashMapExt;
@@ -148,10 +148,91 @@ fn normalize_paths_option(
   })
 }
 
+fn normalize_advanced_chunks_options(
+  inner: crate::options::binding_advanced_chunks_options::BindingAdvancedChunksOptions,
+) -> napi::Result<AdvancedChunksOptions> {
+  let size_metric = inner
+    .size_metric
+    .map(|metric| match metric.as_str() {
+      "source" => Ok(rolldown::ChunkSizeMetric::Source),
+      "rendered" => Ok(rolldown::ChunkSizeMetric::Rendered),
+      "minified" => Ok(rolldown::ChunkSizeMetric::Minified),
+      "gzip" => Ok(rolldown::ChunkSizeMetric::Gzip),
+      _ => Err(napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("Invalid value for `advancedChunks.sizeMetric` option: {metric}"),
+      )),
+    })
+    .transpose()?;
+  let split_strategy = inner
+    .split_strategy
+    .map(|strategy| match strategy.as_str() {
+      "size" => Ok(rolldown::ChunkSplitStrategy::Size),
+      "locality" => Ok(rolldown::ChunkSplitStrategy::Locality),
+      _ => Err(napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("Invalid value for `advancedChunks.splitStrategy` option: {strategy}"),
+      )),
+    })
+    .transpose()?;
+  Ok(AdvancedChunksOptions {
+    min_size: inner.min_size,
+    min_share_count: inner.min_share_count,
+    min_module_size: inner.min_module_size,
+    max_module_size: inner.max_module_size,
+    max_size: inner.max_size,
+    groups: inner.groups.map(|inner| {
+      inner
+        .into_iter()
+        .map(|item| MatchGroup {
+          name: match item.name {
+            Either::A(name) => MatchGroupName::Static(name),
+            Either::B(func) => {
+              let func = Arc::clone(&func);
+              MatchGroupName::Dynamic(Arc::new(move |module_id, ctx| {
+                let module_id = module_id.to_string();
+                let func = Arc::clone(&func);
+                let owned_ctx = ctx.clone();
+                Box::pin(async move {
+                  func
+                    .invoke_async((module_id, BindingChunkingContext::new(owned_ctx)).into())
+                    .await
+                    .map_err(anyhow::Error::from)
+                })
+              }))
+            }
+          },
+          test: item.test.map(|inner| match inner {
+            Either::A(reg) => {
+              rolldown::MatchGroupTest::Regex(reg.try_into().expect("Invalid regex pass to test"))
+            }
+            Either::B(func) => rolldown::MatchGroupTest::Function(Arc::new(move |id: &str| {
+              let id = id.to_string();
+              let func = Arc::clone(&func);
+              Box::pin(
+                async move { func.invoke_async((id,).into()).await.map_err(anyhow::Error::from) },
+              )
+            })),
+          }),
+          priority: item.priority,
+          min_size: item.min_size,
+          min_share_count: item.min_share_count,
+          max_module_size: item.max_module_size,
+          min_module_size: item.min_module_size,
+          max_size: item.max_size,
+        })
+        .collect::<Vec<_>>()
+    }),
+    include_dependencies_recursively: inner.include_dependencies_recursively,
+    size_metric,
+    split_strategy,
+  })
+}
+
 #[expect(clippy::too_many_lines)]
 pub fn normalize_binding_options(
   input_options: crate::options::BindingInputOptions,
-  output_options: crate::options::BindingOutputOptions,
+  mut output_options: crate::options::BindingOutputOptions,
   #[cfg(not(target_family = "wasm"))] mut parallel_plugins_map: Option<
     crate::parallel_js_plugin_registry::PluginValues,
   >,
@@ -191,33 +272,8 @@ pub fn normalize_binding_options(
     })
   });
 
-  let sourcemap_ignore_list = output_options.sourcemap_ignore_list.map(|option| match option {
-    Either3::A(bool_val) => rolldown::SourceMapIgnoreList::from_bool(bool_val),
-    Either3::B(string_or_regex) => {
-      rolldown::SourceMapIgnoreList::from_string_or_regex(string_or_regex.inner())
-    }
-    Either3::C(ts_fn) => {
-      rolldown::SourceMapIgnoreList::new(Arc::new(move |source, sourcemap_path| {
-        let ts_fn = Arc::clone(&ts_fn);
-        let source = source.to_string();
-        let sourcemap_path = sourcemap_path.to_string();
-        Box::pin(async move {
-          ts_fn.invoke_async((source, sourcemap_path).into()).await.map_err(anyhow::Error::from)
-        })
-      }))
-    }
-  });
-
-  let sourcemap_path_transform = output_options.sourcemap_path_transform.map(|ts_fn| {
-    rolldown::SourceMapPathTransform::new(Arc::new(move |source, sourcemap_path| {
-      let ts_fn = Arc::clone(&ts_fn);
-      let source = source.to_string();
-      let sourcemap_path = sourcemap_path.to_string();
-      Box::pin(async move {
-        ts_fn.invoke_async((source, sourcemap_path).into()).await.map_err(anyhow::Error::from)
-      })
-    }))
-  });
+  let output_plugins = std::mem::take(&mut output_options.plugins);
+  let output = normalize_binding_output_options(output_options)?;
 
   let invalidate_js_side_cache = input_options.invalidate_js_side_cache.map(|ts_fn| {
     rolldown::InvalidateJsSideCache::new(Arc::new(move || {
@@ -254,7 +310,7 @@ pub fn normalize_binding_options(
 
   let transform_options = input_options.transform.map(normalize_binding_transform_options);
 
-  let bundler_options = BundlerOptions {
+  let mut bundler_options = BundlerOptions {
     input: Some(input_options.input.into_iter().map(Into::into).collect()),
     cwd: cwd.into(),
     external,
@@ -270,6 +326,137 @@ pub fn normalize_binding_options(
       .transpose()
       .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
     shim_missing_exports: input_options.shim_missing_exports,
+    module_types,
+    experimental: if let Some(experimental) = input_options.experimental {
+      Some(experimental.try_into()?)
+    } else {
+      None
+    },
+    define: input_options.define.map(FxIndexMap::from_iter),
+    inject: input_options
+      .inject
+      .map(|inner| inner.into_iter().map(normalize_binding_inject_import).collect()),
+    checks: input_options.checks.map(Into::into),
+    profiler_names: input_options.profiler_names,
+    watch: input_options.watch.map(TryInto::try_into).transpose()?,
+    drop_labels: input_options.drop_labels,
+    keep_names: input_options.keep_names,
+    defer_sync_scan_data: get_defer_sync_scan_data,
+    transform: transform_options,
+    make_absolute_externals_relative: input_options
+      .make_absolute_externals_relative
+      .map(Into::into),
+    debug: input_options.debug.map(|inner| rolldown::DebugOptions { session_id: inner.session_id }),
+    invalidate_js_side_cache,
+    log_level: Some(input_options.log_level.into()),
+    on_log,
+    preserve_entry_signatures: input_options
+      .preserve_entry_signatures
+      .map(std::convert::TryInto::try_into)
+      .transpose()?,
+    optimization: input_options.optimization.map(OptimizationOption::try_from).transpose()?,
+    context: input_options.context,
+    tsconfig: input_options.tsconfig.and_then(|v| {
+      Some(match v {
+        Either::A(false) => return None,
+        Either::A(true) => TsConfig::Auto,
+        Either::B(s) => TsConfig::Manual(s.into()),
+      })
+    }),
+    ..Default::default()
+  };
+  output.apply_to(&mut bundler_options);
+
+  #[cfg(not(target_family = "wasm"))]
+  // Deal with plugins
+  let worker_manager = worker_manager.map(Arc::new);
+
+  #[cfg(not(target_family = "wasm"))]
+  let plugins: Vec<SharedPluginable> = input_options
+    .plugins
+    .into_iter()
+    .chain(output_plugins)
+    .enumerate()
+    .map(|(index, plugin)| {
+      plugin.map_or_else(
+        || {
+          let plugins = parallel_plugins_map
+            .as_mut()
+            .and_then(|plugin| plugin.remove(&index))
+            .unwrap_or_default();
+          let worker_manager = worker_manager.as_ref().unwrap();
+          ParallelJsPlugin::new_shared(plugins, Arc::clone(worker_manager))
+        },
+        |plugin| match plugin {
+          Either::A(plugin_options) => JsPlugin::new_shared(plugin_options),
+          Either::B(builtin) => {
+            // Needs to save the name, since `try_into` will consume the ownership
+            let name = format!("{:?}", builtin.__name);
+            builtin
+              .try_into()
+              .unwrap_or_else(|err| panic!("Should convert to builtin plugin: {name} \n {err}"))
+          }
+        },
+      )
+    })
+    .collect::<Vec<_>>();
+
+  #[cfg(target_family = "wasm")]
+  let plugins: Vec<SharedPluginable> = input_options
+    .plugins
+    .into_iter()
+    .chain(output_plugins)
+    .filter_map(|plugin| {
+      plugin.map(|plugin| match plugin {
+        Either::A(plugin_options) => JsPlugin::new_shared(plugin_options),
+        Either::B(builtin) => {
+          // Needs to save the name, since `try_into` will consume the ownership
+          let name = format!("{:?}", builtin.__name);
+          builtin
+            .try_into()
+            .unwrap_or_else(|err| panic!("Should convert to builtin plugin: {name} \n {err}"))
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+
+  Ok(NormalizeBindingOptionsReturn { bundler_options, plugins })
+}
+
+/// Normalizes the options of an output, except for `plugins`.
+#[expect(clippy::too_many_lines)]
+pub fn normalize_binding_output_options(
+  output_options: crate::options::BindingOutputOptions,
+) -> napi::Result<OutputOptions> {
+  let sourcemap_ignore_list = output_options.sourcemap_ignore_list.map(|option| match option {
+    Either3::A(bool_val) => rolldown::SourceMapIgnoreList::from_bool(bool_val),
+    Either3::B(string_or_regex) => {
+      rolldown::SourceMapIgnoreList::from_string_or_regex(string_or_regex.inner())
+    }
+    Either3::C(ts_fn) => {
+      rolldown::SourceMapIgnoreList::new(Arc::new(move |source, sourcemap_path| {
+        let ts_fn = Arc::clone(&ts_fn);
+        let source = source.to_string();
+        let sourcemap_path = sourcemap_path.to_string();
+        Box::pin(async move {
+          ts_fn.invoke_async((source, sourcemap_path).into()).await.map_err(anyhow::Error::from)
+        })
+      }))
+    }
+  });
+
+  let sourcemap_path_transform = output_options.sourcemap_path_transform.map(|ts_fn| {
+    rolldown::SourceMapPathTransform::new(Arc::new(move |source, sourcemap_path| {
+      let ts_fn = Arc::clone(&ts_fn);
+      let source = source.to_string();
+      let sourcemap_path = sourcemap_path.to_string();
+      Box::pin(async move {
+        ts_fn.invoke_async((source, sourcemap_path).into()).await.map_err(anyhow::Error::from)
+      })
+    }))
+  });
+
+  Ok(OutputOptions {
     name: output_options.name,
     asset_filenames: normalize_asset_file_names_option(output_options.asset_file_names)?,
     entry_filenames: normalize_chunk_file_names_option(output_options.entry_file_names)?,
@@ -323,6 +510,7 @@ pub fn normalize_binding_options(
       "cjs" => OutputFormat::Cjs,
       "iife" => OutputFormat::Iife,
       "umd" => OutputFormat::Umd,
+      "amd" => OutputFormat::Amd,
       _ => panic!("Invalid format: {format_str}"),
     }),
     hash_characters: output_options.hash_characters.map(|format_str| match format_str.as_str() {
@@ -332,17 +520,12 @@ pub fn normalize_binding_options(
       _ => panic!("Invalid hash characters: {format_str}"),
     }),
     globals: normalize_globals_option(output_options.globals),
+    amd: output_options.amd.map(|amd| AmdOptions { id: amd.id, define: amd.define }),
     paths: normalize_paths_option(output_options.paths),
     generated_code: output_options
       .generated_code
       .map(normalize_generated_code_option)
       .transpose()?,
-    module_types,
-    experimental: if let Some(experimental) = input_options.experimental {
-      Some(experimental.try_into()?)
-    } else {
-      None
-    },
     minify: output_options
       .minify
       .map(|opts| match opts {
@@ -374,65 +557,12 @@ pub fn normalize_binding_options(
       })
       .transpose()?,
     extend: output_options.extend,
-    define: input_options.define.map(FxIndexMap::from_iter),
-    inject: input_options
-      .inject
-      .map(|inner| inner.into_iter().map(normalize_binding_inject_import).collect()),
     external_live_bindings: output_options.external_live_bindings,
     inline_dynamic_imports: output_options.inline_dynamic_imports,
-    advanced_chunks: output_options.advanced_chunks.map(|inner| AdvancedChunksOptions {
-      min_size: inner.min_size,
-      min_share_count: inner.min_share_count,
-      min_module_size: inner.min_module_size,
-      max_module_size: inner.max_module_size,
-      max_size: inner.max_size,
-      groups: inner.groups.map(|inner| {
-        inner
-          .into_iter()
-          .map(|item| MatchGroup {
-            name: match item.name {
-              Either::A(name) => MatchGroupName::Static(name),
-              Either::B(func) => {
-                let func = Arc::clone(&func);
-                MatchGroupName::Dynamic(Arc::new(move |module_id, ctx| {
-                  let module_id = module_id.to_string();
-                  let func = Arc::clone(&func);
-                  let owned_ctx = ctx.clone();
-                  Box::pin(async move {
-                    func
-                      .invoke_async((module_id, BindingChunkingContext::new(owned_ctx)).into())
-                      .await
-                      .map_err(anyhow::Error::from)
-                  })
-                }))
-              }
-            },
-            test: item.test.map(|inner| match inner {
-              Either::A(reg) => {
-                rolldown::MatchGroupTest::Regex(reg.try_into().expect("Invalid regex pass to test"))
-              }
-              Either::B(func) => rolldown::MatchGroupTest::Function(Arc::new(move |id: &str| {
-                let id = id.to_string();
-                let func = Arc::clone(&func);
-                Box::pin(async move {
-                  func.invoke_async((id,).into()).await.map_err(anyhow::Error::from)
-                })
-              })),
-            }),
-            priority: item.priority,
-            min_size: item.min_size,
-            min_share_count: item.min_share_count,
-            max_module_size: item.max_module_size,
-            min_module_size: item.min_module_size,
-            max_size: item.max_size,
-          })
-          .collect::<Vec<_>>()
-      }),
-      include_dependencies_recursively: inner.include_dependencies_recursively,
-    }),
-    checks: input_options.checks.map(Into::into),
-    profiler_names: input_options.profiler_names,
-    watch: input_options.watch.map(TryInto::try_into).transpose()?,
+    advanced_chunks: output_options
+      .advanced_chunks
+      .map(normalize_advanced_chunks_options)
+      .transpose()?,
     legal_comments: output_options
       .legal_comments
       .map(|inner| match inner.as_str() {
@@ -444,91 +574,12 @@ pub fn normalize_binding_options(
         )),
       })
       .transpose()?,
-    drop_labels: input_options.drop_labels,
-    keep_names: input_options.keep_names,
     polyfill_require: output_options.polyfill_require,
-    defer_sync_scan_data: get_defer_sync_scan_data,
-    transform: transform_options,
-    make_absolute_externals_relative: input_options
-      .make_absolute_externals_relative
-      .map(Into::into),
-    debug: input_options.debug.map(|inner| rolldown::DebugOptions { session_id: inner.session_id }),
-    invalidate_js_side_cache,
-    log_level: Some(input_options.log_level.into()),
-    on_log,
     preserve_modules: output_options.preserve_modules,
     virtual_dirname: output_options.virtual_dirname,
     preserve_modules_root: output_options.preserve_modules_root,
-    preserve_entry_signatures: input_options
-      .preserve_entry_signatures
-      .map(std::convert::TryInto::try_into)
-      .transpose()?,
-    optimization: input_options.optimization.map(OptimizationOption::try_from).transpose()?,
     top_level_var: output_options.top_level_var,
     minify_internal_exports: output_options.minify_internal_exports,
     clean_dir: output_options.clean_dir,
-    context: input_options.context,
-    tsconfig: input_options.tsconfig.and_then(|v| {
-      Some(match v {
-        Either::A(false) => return None,
-        Either::A(true) => TsConfig::Auto,
-        Either::B(s) => TsConfig::Manual(s.into()),
-      })
-    }),
-  };
-
-  #[cfg(not(target_family = "wasm"))]
-  // Deal with plugins
-  let worker_manager = worker_manager.map(Arc::new);
-
-  #[cfg(not(target_family = "wasm"))]
-  let plugins: Vec<SharedPluginable> = input_options
-    .plugins
-    .into_iter()
-    .chain(output_options.plugins)
-    .enumerate()
-    .map(|(index, plugin)| {
-      plugin.map_or_else(
-        || {
-          let plugins = parallel_plugins_map
-            .as_mut()
-            .and_then(|plugin| plugin.remove(&index))
-            .unwrap_or_default();
-          let worker_manager = worker_manager.as_ref().unwrap();
-          ParallelJsPlugin::new_shared(plugins, Arc::clone(worker_manager))
-        },
-        |plugin| match plugin {
-          Either::A(plugin_options) => JsPlugin::new_shared(plugin_options),
-          Either::B(builtin) => {
-            // Needs to save the name, since `try_into` will consume the ownership
-            let name = format!("{:?}", builtin.__name);
-            builtin
-              .try_into()
-              .unwrap_or_else(|err| panic!("Should convert to builtin plugin: {name} \n {err}"))
-          }
-        },
-      )
-    })
-    .collect::<Vec<_>>();
-
-  #[cfg(target_family = "wasm")]
-  let plugins: Vec<SharedPluginable> = input_options
-    .plugins
-    .into_iter()
-    .chain(output_options.plugins)
-    .filter_map(|plugin| {
-      plugin.map(|plugin| match plugin {
-        Either::A(plugin_options) => JsPlugin::new_shared(plugin_options),
-        Either::B(builtin) => {
-          // Needs to save the name, since `try_into` will consume the ownership
-          let name = format!("{:?}", builtin.__name);
-          builtin
-            .try_into()
-            .unwrap_or_else(|err| panic!("Should convert to builtin plugin: {name} \n {err}"))
-        }
-      })
-    })
-    .collect::<Vec<_>>();
-
-  Ok(NormalizeBindingOptionsReturn { bundler_options, plugins })
+  })
 }
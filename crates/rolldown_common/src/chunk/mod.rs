@@ -60,9 +60,13 @@ pub struct Chunk {
   // Sorted by Module#stable_id of modules in the chunk
   pub cross_chunk_imports: Vec<ChunkIdx>,
   pub cross_chunk_dynamic_imports: Vec<ChunkIdx>,
+  /// Only used with `experimental.preloadDynamicImports`.
+  /// Maps each chunk imported by `import()` to the chunks that should be preloaded with it: the imported
+  /// chunk itself, followed by its static dependencies that this chunk doesn't already import.
+  pub dynamic_import_preloads: FxHashMap<ChunkIdx, Vec<ChunkIdx>>,
   pub bits: BitSet,
   pub imports_from_other_chunks: FxIndexMap<ChunkIdx, Vec<CrossChunkImportItem>>,
-  // Only meaningful for cjs format
+  // Only meaningful for cjs and amd format
   pub require_binding_names_for_other_chunks: FxHashMap<ChunkIdx, String>,
   /// The first element of tuple is module idx of external module
   /// the second element is the related named import of external module.
@@ -127,6 +131,16 @@ impl Chunk {
     if import_path.starts_with("../") { import_path } else { format!("./{import_path}") }
   }
 
+  /// The module id of `importee` in AMD output. AMD loaders treat ids ending with `.js` as plain
+  /// URLs instead of resolving them relative to the importer, so the extension is omitted.
+  pub fn amd_import_path_for(&self, importee: &Chunk) -> String {
+    let import_path = self.import_path_for(importee);
+    match import_path.strip_suffix(".js") {
+      Some(stripped) => stripped.to_string(),
+      None => import_path,
+    }
+  }
+
   pub fn relative_path_for(&self, target: &Path) -> String {
     let source_dir = self
       .absolute_preliminary_filename
@@ -1,4 +1,7 @@
-use crate::{ConstExportMeta, DependedRuntimeHelperMap, ImportAttribute, SourcemapChainElement};
+use crate::{
+  ConstExportMeta, DependedRuntimeHelperMap, EnumMemberValues, ImportAttribute,
+  SourcemapChainElement,
+};
 use arcstr::ArcStr;
 use bitflags::bitflags;
 use oxc::{
@@ -11,7 +14,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   ExportsKind, HmrInfo, ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleId, ModuleIdx,
-  NamedImport, ResolvedImportRecord, SourceMutation, StmtInfos, SymbolRef,
+  NamedImport, ResolvedImportRecord, SourceMutation, StmtInfoIdx, StmtInfos, SymbolRef,
   side_effects::DeterminedSideEffects, types::source_mutation::ArcSourceMutation,
 };
 
@@ -28,6 +31,9 @@ bitflags! {
         /// If the module has top-level empty function, if any module has top level empty function, we need
         /// to apply cross module optimization.
         const TopExportedSideEffectsFreeFunction = 1 << 6;
+        /// The module was written in CommonJS and has been turned into an ES module by
+        /// `experimental.commonjsToEsm`.
+        const ConvertedFromCommonjs = 1 << 7;
     }
 }
 
@@ -109,11 +115,35 @@ pub struct EcmaView {
   pub hmr_hot_ref: Option<SymbolRef>,
   pub hmr_info: HmrInfo,
   pub constant_export_map: FxHashMap<SymbolId, ConstExportMeta>,
+  /// Member values of the top-level TypeScript enums whose members are all constant.
+  pub ts_enum_values: FxHashMap<SymbolId, EnumMemberValues>,
+  /// Top-level object literals and classes -> names of the members that `treeshake.members` could
+  /// remove if they are never read.
+  pub removable_members: FxHashMap<SymbolId, FxHashSet<CompactStr>>,
+  /// How properties are read in this module, which decides whether the instance methods of a class
+  /// could be removed by `treeshake.members`.
+  pub member_reads: MemberReads,
+  /// `Some` if the module is CommonJS and only assigns its exports in top-level statements, so that
+  /// it could be turned into an ES module. Holds the statements setting `__esModule`, which are
+  /// dropped by the conversion.
+  pub convertible_commonjs: Option<Vec<StmtInfoIdx>>,
   pub import_attribute_map: FxHashMap<ImportRecordIdx, ImportAttribute>,
   /// Use `Box` since it is rarely used also it could reduce the size of `EcmaView`, .
   pub json_module_none_self_reference_included_symbol: Option<Box<FxHashSet<SymbolRef>>>,
 }
 
+/// Collected for `treeshake.members`. Instances of a class could end up anywhere, so its instance
+/// methods are only removed if no property of the same name is read on any object.
+#[derive(Debug, Default, Clone)]
+pub struct MemberReads {
+  /// Property names read with `obj.name`, `obj['name']`, `'name' in obj` or destructuring.
+  pub names: FxHashSet<CompactStr>,
+  /// Whether a property is read with a computed key, which could be any name.
+  pub has_computed_read: bool,
+  /// Spans of the `X` in `new X()`, which create instances without letting `X` itself escape.
+  pub constructed: FxHashSet<Span>,
+}
+
 bitflags! {
     #[derive(Debug, Clone, Copy)]
     pub struct EcmaModuleAstUsage: u16 {
@@ -11,8 +11,8 @@ use rolldown_utils::make_unique_name::make_unique_name;
 use rolldown_utils::xxhash::{xxhash_base64_url, xxhash_with_base};
 use std::ffi::OsStr;
 use std::path::Path;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 use tokio::sync::Mutex;
 
 #[derive(Debug, Default)]
@@ -62,7 +62,10 @@ pub struct FileEmitter {
   chunks: FxDashMap<ArcStr, Arc<EmittedChunk>>,
   prebuilt_chunks: FxDashMap<ArcStr, Arc<EmittedPrebuiltChunk>>,
   base_reference_id: AtomicUsize,
-  options: Arc<NormalizedBundlerOptions>,
+  /// The options of the output being generated, see [`FileEmitter::restore`].
+  options: RwLock<Arc<NormalizedBundlerOptions>>,
+  /// The files emitted without a `fileName`, whose names are rendered from `assetFileNames`.
+  unnamed_files: FxDashSet<ArcStr>,
   /// Mark the files that have been emitted to bundle.
   emitted_files: FxDashSet<ArcStr>,
   emitted_chunks: FxDashMap<ArcStr, ArcStr>,
@@ -80,12 +83,17 @@ impl FileEmitter {
       prebuilt_chunks: DashMap::default(),
       emitted_chunks: DashMap::default(),
       base_reference_id: AtomicUsize::new(0),
-      options,
+      options: RwLock::new(options),
+      unnamed_files: FxDashSet::default(),
       emitted_files: DashSet::default(),
       emitted_filenames: FxDashSet::default(),
     }
   }
 
+  pub fn options(&self) -> Arc<NormalizedBundlerOptions> {
+    Arc::clone(&self.options.read().expect("FileEmitter options lock poisoned"))
+  }
+
   pub fn set_emitted_chunk_info(&self, emitted_chunk_info: impl Iterator<Item = EmittedChunkInfo>) {
     for info in emitted_chunk_info {
       self.emitted_chunks.insert(info.reference_id, info.filename);
@@ -122,7 +130,7 @@ impl FileEmitter {
     sanitized_file_name: Option<ArcStr>,
   ) -> anyhow::Result<ArcStr> {
     let hash: ArcStr =
-      xxhash_with_base(file.source.as_bytes(), self.options.hash_characters.base()).into();
+      xxhash_with_base(file.source.as_bytes(), self.options().hash_characters.base()).into();
 
     // Deduplicate assets if an explicit fileName is not provided
     let reference_id = if file.file_name.is_none() {
@@ -153,6 +161,9 @@ impl FileEmitter {
       self.assign_reference_id(file.file_name.clone())
     };
 
+    if file.file_name.is_none() {
+      self.unnamed_files.insert(reference_id.clone());
+    }
     // Generate filename and insert into files map
     self.generate_file_name(&mut file, &hash, asset_filename_template, sanitized_file_name)?;
     self.files.insert(
@@ -310,9 +321,78 @@ impl FileEmitter {
     *tx_guard = tx;
   }
 
+  /// Takes a snapshot of the files emitted so far, which are the files emitted while building
+  /// when called before generating any output.
+  pub fn snapshot(&self) -> FileEmitterSnapshot {
+    let mut files = self
+      .files
+      .iter()
+      .map(|file| {
+        let (reference_id, asset) = file.pair();
+        (reference_id.clone(), asset.clone(), self.unnamed_files.contains(reference_id))
+      })
+      .collect::<Vec<_>>();
+    // Names are deconflicted in this order when the snapshot is restored.
+    files.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    FileEmitterSnapshot {
+      files,
+      chunks: self.chunks.iter().map(|c| (c.key().clone(), Arc::clone(c.value()))).collect(),
+      prebuilt_chunks: self
+        .prebuilt_chunks
+        .iter()
+        .map(|c| (c.key().clone(), Arc::clone(c.value())))
+        .collect(),
+      base_reference_id: self.base_reference_id.load(Ordering::Relaxed),
+    }
+  }
+
+  /// Resets the emitter to `snapshot` before generating an output with `options`.
+  ///
+  /// The names of the files emitted without a `fileName` are rendered again, so `assetFileNames`,
+  /// `hashCharacters` and `sanitizeFileName` of `options` apply to them. The files and chunks
+  /// emitted while generating the previous output are dropped.
+  pub async fn restore(
+    &self,
+    snapshot: &FileEmitterSnapshot,
+    options: Arc<NormalizedBundlerOptions>,
+  ) -> anyhow::Result<()> {
+    *self.options.write().expect("FileEmitter options lock poisoned") = Arc::clone(&options);
+    self.clear();
+    self.emitted_filenames.clear();
+    self.base_reference_id.store(snapshot.base_reference_id, Ordering::Relaxed);
+    for (reference_id, chunk) in &snapshot.chunks {
+      self.chunks.insert(reference_id.clone(), Arc::clone(chunk));
+    }
+    for (reference_id, chunk) in &snapshot.prebuilt_chunks {
+      self.prebuilt_chunks.insert(reference_id.clone(), Arc::clone(chunk));
+    }
+    for (reference_id, asset, is_unnamed) in &snapshot.files {
+      let mut asset = asset.clone();
+      if *is_unnamed {
+        let mut file = EmittedAsset {
+          name: asset.names.first().cloned(),
+          original_file_name: asset.original_file_names.first().cloned(),
+          file_name: None,
+          source: asset.source.clone(),
+        };
+        let hash: ArcStr =
+          xxhash_with_base(file.source.as_bytes(), options.hash_characters.base()).into();
+        let filename_template = options.asset_filename_with_file(&file).await?;
+        let sanitized_file_name = options.sanitize_file_name_with_file(&file).await?;
+        self.generate_file_name(&mut file, &hash, filename_template, sanitized_file_name)?;
+        asset.filename = file.file_name.expect("should have a file name after generating it");
+        self.source_hash_to_reference_id.insert(hash, reference_id.clone());
+        self.unnamed_files.insert(reference_id.clone());
+      }
+      self.files.insert(reference_id.clone(), asset);
+    }
+    Ok(())
+  }
+
   pub fn clear(&self) {
     self.chunks.clear();
     self.files.clear();
+    self.unnamed_files.clear();
     self.prebuilt_chunks.clear();
     self.names.clear();
     self.source_hash_to_reference_id.clear();
@@ -322,6 +402,16 @@ impl FileEmitter {
   }
 }
 
+/// The state of a [`FileEmitter`] taken with [`FileEmitter::snapshot`].
+#[derive(Debug)]
+pub struct FileEmitterSnapshot {
+  /// The emitted files sorted by reference id, and whether they were emitted without a `fileName`.
+  files: Vec<(ArcStr, OutputAsset, bool)>,
+  chunks: Vec<(ArcStr, Arc<EmittedChunk>)>,
+  prebuilt_chunks: Vec<(ArcStr, Arc<EmittedPrebuiltChunk>)>,
+  base_reference_id: usize,
+}
+
 fn sort_names(names: &mut [String]) {
   names.sort_unstable_by(|a, b| {
     let len_ord = a.len().cmp(&b.len());
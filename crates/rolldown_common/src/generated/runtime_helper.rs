@@ -24,7 +24,8 @@ bitflags! {
     const ToBinaryNode = 1 << 16;
     const ToBinary = 1 << 17;
     const ToDynamicImportEsm = 1 << 18;
-    const Require = 1 << 19;
+    const Preload = 1 << 19;
+    const Require = 1 << 20;
   }
 }
 
@@ -56,7 +57,7 @@ impl DependedRuntimeHelperMapExt for DependedRuntimeHelperMap {
   }
 }
 
-pub const RUNTIME_HELPER_NAMES: [&str; 20] = [
+pub const RUNTIME_HELPER_NAMES: [&str; 21] = [
   "__create",
   "__defProp",
   "__name",
@@ -76,5 +77,6 @@ pub const RUNTIME_HELPER_NAMES: [&str; 20] = [
   "__toBinaryNode",
   "__toBinary",
   "__toDynamicImportESM",
+  "__preload",
   "__require",
 ];
@@ -2,6 +2,7 @@ use rolldown_utils::indexmap::FxIndexMap;
 use rustc_hash::FxHashMap;
 use std::{fmt::Debug, path::PathBuf};
 use types::advanced_chunks_options::AdvancedChunksOptions;
+use types::amd_options::AmdOptions;
 use types::debug_options::DebugOptions;
 use types::generated_code_options::GeneratedCodeOptions;
 use types::inject_import::InjectImport;
@@ -110,6 +111,7 @@ pub struct BundlerOptions {
     schemars(with = "Option<FxHashMap<String, String>>")
   )]
   pub globals: Option<GlobalsOutputOption>,
+  pub amd: Option<AmdOptions>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_paths"),
@@ -336,6 +338,7 @@ where
         commonjs: Some(true),
         property_read_side_effects: None,
         property_write_side_effects: None,
+        members: None,
       }))
     }
     Some(Value::Object(obj)) => {
@@ -382,6 +385,13 @@ where
           _ => Err(serde::de::Error::custom("manualPureFunctions should be a `Vec<String>`")),
         },
       )?;
+      let members = obj.get("members").map_or_else(
+        || Ok(None),
+        |v| match v {
+          Value::Bool(b) => Ok(Some(*b)),
+          _ => Err(serde::de::Error::custom("members should be a `true` or `false`")),
+        },
+      )?;
       // Use string to make deserialization logic easier
       let property_read_side_effects = obj.get("propertyReadSideEffects").map_or_else(
         || Ok(None),
@@ -419,6 +429,7 @@ where
         commonjs,
         property_read_side_effects,
         property_write_side_effects,
+        members,
       }))
     }
     _ => Err(serde::de::Error::custom("treeshake should be a boolean or an object")),
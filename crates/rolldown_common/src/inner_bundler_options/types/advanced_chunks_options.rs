@@ -23,6 +23,42 @@ pub struct AdvancedChunksOptions {
   pub max_module_size: Option<f64>,
   pub include_dependencies_recursively: Option<bool>,
   pub groups: Option<Vec<MatchGroup>>,
+  pub size_metric: Option<ChunkSizeMetric>,
+  pub split_strategy: Option<ChunkSplitStrategy>,
+}
+
+/// How the sizes compared with `minSize`, `maxSize`, `minModuleSize` and `maxModuleSize` are measured.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase")
+)]
+pub enum ChunkSizeMetric {
+  /// The length of the module source, before tree shaking.
+  #[default]
+  Source,
+  /// The length of the code that survives tree shaking.
+  Rendered,
+  /// The estimated length of the rendered code after removing comments and whitespace.
+  Minified,
+  /// The estimated length of the minified code after gzip compression.
+  Gzip,
+}
+
+/// How a group that exceeds `maxSize` is split into smaller parts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase")
+)]
+pub enum ChunkSplitStrategy {
+  /// Sort modules by size and fill the parts from the smallest module.
+  #[default]
+  Size,
+  /// Sort modules by path and split where neighboring paths differ the most, keeping modules of the same package and directory together.
+  Locality,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -0,0 +1,25 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for the `amd` output format. Compatible with rollup's `output.amd`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct AmdOptions {
+  /// The id to use for the module, e.g. `define('my-bundle', ['dependency'], ...)`.
+  /// Anonymous modules are generated if not specified.
+  pub id: Option<String>,
+  /// The function name to use instead of `define`.
+  pub define: Option<String>,
+}
+
+impl AmdOptions {
+  pub fn define_function_name(&self) -> &str {
+    self.define.as_deref().unwrap_or("define")
+  }
+}
@@ -8,6 +8,13 @@ use crate::inner_bundler_options::types::chunk_import_map::ChunkImportMap;
 use super::attach_debug_info::AttachDebugInfo;
 use super::chunk_modules_order::ChunkModulesOrderBy;
 use super::hmr_options::HmrOptions;
+use super::html_entries::HtmlEntriesOptions;
+use super::import_map::ImportMap;
+use super::mangle_props::ManglePropsOptions;
+use super::min_chunk_size::MinChunkSizeOptions;
+use super::module_federation::ModuleFederationOptions;
+use super::node_polyfills::NodePolyfillsOptions;
+use super::remote_imports::RemoteImportsOptions;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
@@ -52,6 +59,22 @@ pub struct ExperimentalOptions {
   pub on_demand_wrapping: Option<bool>,
   pub transform_hires_sourcemap: Option<SourcemapHires>,
   pub native_magic_string: Option<bool>,
+  pub resolve_trace: Option<bool>,
+  pub node_polyfills: Option<NodePolyfillsOptions>,
+  pub standalone_runtime_chunk: Option<bool>,
+  pub min_chunk_size: Option<MinChunkSizeOptions>,
+  pub preload_dynamic_imports: Option<bool>,
+  pub name_cache: Option<String>,
+  pub mangle_props: Option<ManglePropsOptions>,
+  pub commonjs_to_esm: Option<bool>,
+  pub detect_external_commonjs_exports: Option<bool>,
+  pub concurrent_top_level_await: Option<bool>,
+  pub top_level_await_wrapper: Option<bool>,
+  pub preserve_directives: Option<bool>,
+  pub html_entries: Option<HtmlEntriesOptions>,
+  pub import_map: Option<ImportMap>,
+  pub remote_imports: Option<RemoteImportsOptions>,
+  pub module_federation: Option<ModuleFederationOptions>,
 }
 
 impl ExperimentalOptions {
@@ -87,4 +110,36 @@ impl ExperimentalOptions {
   pub fn is_native_magic_string_enabled(&self) -> bool {
     self.native_magic_string.unwrap_or(false)
   }
+
+  pub fn is_resolve_trace_enabled(&self) -> bool {
+    self.resolve_trace.unwrap_or(false)
+  }
+
+  pub fn is_standalone_runtime_chunk_enabled(&self) -> bool {
+    self.standalone_runtime_chunk.unwrap_or(false)
+  }
+
+  pub fn is_preload_dynamic_imports_enabled(&self) -> bool {
+    self.preload_dynamic_imports.unwrap_or(false)
+  }
+
+  pub fn is_commonjs_to_esm_enabled(&self) -> bool {
+    self.commonjs_to_esm.unwrap_or(false)
+  }
+
+  pub fn is_detect_external_commonjs_exports_enabled(&self) -> bool {
+    self.detect_external_commonjs_exports.unwrap_or(false)
+  }
+
+  pub fn is_concurrent_top_level_await_enabled(&self) -> bool {
+    self.concurrent_top_level_await.unwrap_or(false)
+  }
+
+  pub fn is_top_level_await_wrapper_enabled(&self) -> bool {
+    self.top_level_await_wrapper.unwrap_or(false)
+  }
+
+  pub fn is_preserve_directives_enabled(&self) -> bool {
+    self.preserve_directives.unwrap_or(false)
+  }
 }
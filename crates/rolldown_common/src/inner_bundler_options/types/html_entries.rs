@@ -0,0 +1,21 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct HtmlEntriesOptions {
+  /// Entry scripts smaller than this size in bytes are inlined into the HTML. Defaults to `4096`.
+  pub inline_script_limit: Option<u32>,
+}
+
+impl HtmlEntriesOptions {
+  pub fn inline_script_limit(&self) -> usize {
+    self.inline_script_limit.map_or(4096, |limit| limit as usize)
+  }
+}
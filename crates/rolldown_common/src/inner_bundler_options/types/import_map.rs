@@ -0,0 +1,19 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use rustc_hash::FxHashMap;
+
+/// A [WHATWG import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps) used
+/// to resolve specifiers before the resolver.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ImportMap {
+  pub imports: Option<FxHashMap<String, String>>,
+  pub scopes: Option<FxHashMap<String, FxHashMap<String, String>>>,
+}
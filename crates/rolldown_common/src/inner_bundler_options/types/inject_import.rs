@@ -49,4 +49,12 @@ impl InjectImport {
   pub fn default(alias: String, from: String) -> Self {
     Self::Named { imported: "default".to_string(), alias: Some(alias), from }
   }
+
+  /// The name of the binding the injected import introduces.
+  pub fn local_name(&self) -> &str {
+    match self {
+      Self::Named { imported, alias, .. } => alias.as_deref().unwrap_or(imported),
+      Self::Namespace { alias, .. } => alias,
+    }
+  }
 }
@@ -7,9 +7,65 @@ use serde::Deserialize;
 #[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
 pub struct InputItem {
   pub name: Option<String>,
+  /// A path or specifier, or a glob pattern relative to `cwd` like `src/pages/**/*.js`. Patterns
+  /// starting with `!` exclude files matched by the other patterns.
   pub import: String,
 }
 
+impl InputItem {
+  /// `?` and `[` alone don't make a glob, since they are common in file names like `[id].js`.
+  pub fn is_glob(&self) -> bool {
+    self.is_negated_glob() || self.import.contains(['*', '{'])
+  }
+
+  pub fn is_negated_glob(&self) -> bool {
+    self.import.starts_with('!')
+  }
+
+  /// The pattern without the leading `!` and `./`.
+  pub fn glob_pattern(&self) -> &str {
+    let pattern = self.import.strip_prefix('!').unwrap_or(&self.import);
+    pattern.strip_prefix("./").unwrap_or(pattern)
+  }
+
+  /// The directory to search for files matching the pattern, which is also what entry names are
+  /// relative to. `src/pages/**/*.js` -> `src/pages`, `*.js` -> ``.
+  pub fn glob_base(&self) -> &str {
+    let pattern = self.glob_pattern();
+    let mut end = 0;
+    for (index, _) in pattern.match_indices('/') {
+      if pattern[end..index].contains(['*', '?', '[', '{']) {
+        break;
+      }
+      end = index;
+    }
+    &pattern[..end]
+  }
+
+  /// How many directories deep below `glob_base` the pattern can match, or `None` if it contains
+  /// `**`. `src/pages/*.js` -> `Some(0)`, `src/*/index.js` -> `Some(1)`.
+  pub fn glob_max_depth(&self) -> Option<usize> {
+    let rest = &self.glob_pattern()[self.glob_base().len()..];
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    // Separators inside braces like `{a,b/c}` are counted too, which only searches deeper.
+    (!rest.contains("**")).then(|| rest.matches('/').count())
+  }
+
+  /// Whether the pattern matches `path`, relative to `cwd` and with `/` separators.
+  pub fn matches_glob(&self, path: &str) -> bool {
+    fast_glob::glob_match(self.glob_pattern(), path)
+  }
+}
+
+/// Whether `path`, relative to `cwd` and with `/` separators, is an entry contributed by the glob
+/// patterns of `input`.
+pub fn matches_input_globs(input: &[InputItem], path: &str) -> bool {
+  let (negated, globs): (Vec<_>, Vec<_>) =
+    input.iter().filter(|item| item.is_glob()).partition(|item| item.is_negated_glob());
+  globs.iter().any(|item| item.matches_glob(path))
+    && !negated.iter().any(|item| item.matches_glob(path))
+}
+
 impl From<String> for InputItem {
   fn from(value: String) -> Self {
     Self { name: None, import: value }
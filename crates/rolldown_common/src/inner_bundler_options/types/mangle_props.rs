@@ -0,0 +1,53 @@
+use rolldown_utils::js_regex::HybridRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// Renames the object properties matching `regex` consistently across all chunks.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ManglePropsOptions {
+  /// Properties whose names match are mangled.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_regex"),
+    schemars(with = "String")
+  )]
+  pub regex: HybridRegex,
+  /// Names that are never mangled, even if they match `regex`.
+  pub reserved: Option<Vec<String>>,
+  /// Whether to mangle quoted properties such as `obj['_x']` and `{ '_x': 1 }`. Defaults to `false`.
+  pub quoted: Option<bool>,
+  /// Path to a JSON file, relative to `cwd`, keeping the names picked for each property.
+  pub cache: Option<String>,
+}
+
+impl ManglePropsOptions {
+  /// Names the runtime and the module interop rely on.
+  const ALWAYS_RESERVED: [&str; 4] = ["__esModule", "__proto__", "constructor", "prototype"];
+
+  pub fn should_mangle(&self, name: &str) -> bool {
+    self.regex.matches(name)
+      && !Self::ALWAYS_RESERVED.contains(&name)
+      && !self.reserved.as_ref().is_some_and(|reserved| reserved.iter().any(|item| item == name))
+  }
+
+  pub fn is_quoted_enabled(&self) -> bool {
+    self.quoted.unwrap_or(false)
+  }
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<HybridRegex, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = String::deserialize(deserializer)?;
+  HybridRegex::new(&deserialized)
+    .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))
+}
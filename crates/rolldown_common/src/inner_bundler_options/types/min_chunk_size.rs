@@ -0,0 +1,19 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Merges common chunks that are smaller than `minSize` into another common chunk.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinChunkSizeOptions {
+  /// Common chunks whose rendered code is shorter than this are merged.
+  pub min_size: f64,
+  /// How many bytes of code a merge is allowed to add to an entry that didn't load it before.
+  /// Defaults to `minSize`.
+  pub tolerance: Option<f64>,
+}
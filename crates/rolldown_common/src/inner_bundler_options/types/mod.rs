@@ -1,4 +1,5 @@
 pub mod advanced_chunks_options;
+pub mod amd_options;
 pub mod attach_debug_info;
 pub mod chunk_import_map;
 pub mod chunk_modules_order;
@@ -10,6 +11,8 @@ pub mod filename_template;
 pub mod generated_code_options;
 pub mod hash_characters;
 pub mod hmr_options;
+pub mod html_entries;
+pub mod import_map;
 pub mod inject_import;
 pub mod input_item;
 pub mod invalidate_js_side_cache;
@@ -17,15 +20,21 @@ pub mod is_external;
 pub mod legal_comments;
 pub mod log_level;
 pub mod make_absolute_externals_relative;
+pub mod mangle_props;
+pub mod min_chunk_size;
 pub mod minify_options;
+pub mod module_federation;
 pub mod module_type;
+pub mod node_polyfills;
 pub mod normalized_bundler_options;
 pub mod on_log;
 pub mod optimization;
 pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
+pub mod output_options;
 pub mod platform;
+pub mod remote_imports;
 pub mod resolve_options;
 pub mod sanitize_filename;
 pub mod source_map_type;
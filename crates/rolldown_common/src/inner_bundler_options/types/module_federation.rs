@@ -0,0 +1,62 @@
+use rolldown_utils::indexmap::FxIndexMap;
+#[cfg(feature = "deserialize_bundler_options")]
+use rustc_hash::FxHashMap;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ModuleFederationOptions {
+  /// Name of the container, used in diagnostics and to tell which container provides a shared
+  /// module.
+  pub name: String,
+  /// File name of the remote entry chunk. Defaults to `remoteEntry.js`.
+  pub filename: Option<String>,
+  /// Modules exposed by the container, e.g. `{ "./Button": "./src/Button.jsx" }`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    schemars(with = "Option<FxHashMap<String, String>>")
+  )]
+  pub exposes: Option<FxIndexMap<String, String>>,
+  /// Urls of the remote entries of other containers, e.g.
+  /// `{ "app2": "https://example.com/remoteEntry.js" }`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    schemars(with = "Option<FxHashMap<String, String>>")
+  )]
+  pub remotes: Option<FxIndexMap<String, String>>,
+  /// Packages shared between the containers of a page.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    schemars(with = "Option<FxHashMap<String, SharedModuleOptions>>")
+  )]
+  pub shared: Option<FxIndexMap<String, SharedModuleOptions>>,
+}
+
+impl ModuleFederationOptions {
+  pub fn filename(&self) -> &str {
+    self.filename.as_deref().unwrap_or("remoteEntry.js")
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SharedModuleOptions {
+  /// Only load a single version of the package on the page.
+  pub singleton: Option<bool>,
+  /// Range of versions this build works with. Defaults to the range in the `dependencies`,
+  /// `peerDependencies` or `devDependencies` of `package.json`.
+  pub required_version: Option<String>,
+  /// Version provided by this build. Defaults to the version of the installed package.
+  pub version: Option<String>,
+}
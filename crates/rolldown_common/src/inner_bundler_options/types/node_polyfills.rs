@@ -0,0 +1,61 @@
+use rustc_hash::FxHashMap;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use crate::{InjectImport, is_existing_node_builtin_modules};
+
+/// Substitutes Node built-in modules with browser polyfills when `platform` is `'browser'`.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct NodePolyfillsOptions {
+  /// The key is the name of the built-in module without the `node:` prefix, e.g. `"buffer"`.
+  /// The value is the specifier of the polyfill package, or `None` to use an empty module.
+  pub modules: Option<FxHashMap<String, Option<String>>>,
+  /// Inject the `process` and `Buffer` globals from the polyfills of `process` and `buffer` when
+  /// they are referenced. Defaults to `true`.
+  pub globals: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum NodePolyfill<'a> {
+  Package(&'a str),
+  Empty,
+}
+
+impl NodePolyfillsOptions {
+  /// Returns the polyfill configured for `specifier` if it is a Node built-in module.
+  pub fn get(&self, specifier: &str) -> Option<NodePolyfill<'_>> {
+    if !is_existing_node_builtin_modules(specifier) {
+      return None;
+    }
+    let name = specifier.strip_prefix("node:").unwrap_or(specifier);
+    self.modules.as_ref()?.get(name).map(|polyfill| match polyfill {
+      Some(package) => NodePolyfill::Package(package.as_str()),
+      None => NodePolyfill::Empty,
+    })
+  }
+
+  /// The `inject` entries needed to provide `process` and `Buffer` globals.
+  ///
+  /// Globals are only injected for built-ins that are mapped to a polyfill package, since injecting
+  /// from an empty module would leave them `undefined`.
+  pub fn global_injects(&self) -> Vec<InjectImport> {
+    if !self.globals.unwrap_or(true) {
+      return vec![];
+    }
+    let mut injects = vec![];
+    if matches!(self.get("process"), Some(NodePolyfill::Package(_))) {
+      injects.push(InjectImport::default("process".to_string(), "process".to_string()));
+    }
+    if matches!(self.get("buffer"), Some(NodePolyfill::Package(_))) {
+      injects.push(InjectImport::named("Buffer".to_string(), None, "buffer".to_string()));
+    }
+    injects
+  }
+}
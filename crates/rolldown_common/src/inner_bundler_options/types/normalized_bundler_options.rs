@@ -11,6 +11,7 @@ use rolldown_error::EventKindSwitcher;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::advanced_chunks_options::AdvancedChunksOptions;
+use super::amd_options::AmdOptions;
 use super::experimental_options::ExperimentalOptions;
 use super::generated_code_options::GeneratedCodeOptions;
 use super::legal_comments::LegalComments;
@@ -31,11 +32,12 @@ use crate::inner_bundler_options::types::optimization::NormalizedOptimizationCon
 use crate::{
   DeferSyncScanDataOption, EmittedAsset, EsModuleFlag, FilenameTemplate, GlobalsOutputOption,
   HashCharacters, InjectImport, InputItem, InvalidateJsSideCache, LogLevel,
-  MakeAbsoluteExternalsRelative, ModuleType, OnLog, RollupPreRenderedAsset, TransformOptions,
+  MakeAbsoluteExternalsRelative, ModuleType, NodePolyfill, OnLog, RollupPreRenderedAsset,
+  TransformOptions,
 };
 
 #[expect(clippy::struct_excessive_bools)] // Using raw booleans is more clear in this case
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NormalizedBundlerOptions {
   // --- Input
   pub input: Vec<InputItem>,
@@ -65,6 +67,7 @@ pub struct NormalizedBundlerOptions {
   pub es_module: EsModuleFlag,
   pub hash_characters: HashCharacters,
   pub globals: GlobalsOutputOption,
+  pub amd: AmdOptions,
   pub paths: Option<PathsOutputOption>,
   pub generated_code: GeneratedCodeOptions,
   pub sourcemap: Option<SourceMapType>,
@@ -80,6 +83,8 @@ pub struct NormalizedBundlerOptions {
   pub minify: MinifyOptions,
   pub extend: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
+  /// `define` entries like `process.env.*`, replacing the members not covered by other entries.
+  pub define_wildcards: Vec<(/* Object of the members */ String, /* Fallback */ String)>,
   pub keep_names: bool,
   pub inject: Vec<InjectImport>,
   pub oxc_inject_global_variables_config: InjectGlobalVariablesConfig,
@@ -137,6 +142,7 @@ impl Default for NormalizedBundlerOptions {
       es_module: Default::default(),
       hash_characters: Default::default(),
       globals: GlobalsOutputOption::FxHashMap(FxHashMap::default()),
+      amd: Default::default(),
       paths: Default::default(),
       generated_code: Default::default(),
       sourcemap: Default::default(),
@@ -152,6 +158,7 @@ impl Default for NormalizedBundlerOptions {
       minify: MinifyOptions::Disabled,
       extend: Default::default(),
       define: Default::default(),
+      define_wildcards: Default::default(),
       keep_names: Default::default(),
       inject: Default::default(),
       oxc_inject_global_variables_config: InjectGlobalVariablesConfig::new(vec![]),
@@ -199,6 +206,14 @@ impl NormalizedBundlerOptions {
     self.experimental.hmr.is_some()
   }
 
+  /// Browser polyfills only apply when bundling for the browser.
+  pub fn node_polyfill(&self, specifier: &str) -> Option<NodePolyfill<'_>> {
+    if !matches!(self.platform, Platform::Browser) {
+      return None;
+    }
+    self.experimental.node_polyfills.as_ref()?.get(specifier)
+  }
+
   /// make sure the `polyfill_require` is only valid for `esm` format with `node` platform
   #[inline]
   pub fn polyfill_require_for_esm_format_with_node_platform(&self) -> bool {
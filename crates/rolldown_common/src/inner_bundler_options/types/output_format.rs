@@ -16,6 +16,7 @@ pub enum OutputFormat {
   Cjs,
   Iife,
   Umd,
+  Amd,
 }
 
 impl OutputFormat {
@@ -26,6 +27,7 @@ impl OutputFormat {
       Self::Cjs => "cjs",
       Self::Iife => "iife",
       Self::Umd => "umd",
+      Self::Amd => "amd",
     }
   }
 
@@ -48,14 +50,14 @@ impl OutputFormat {
   /// https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/config/config.go#L664-L666
   /// Since we have different implementation for `IIFE` and extra implementation of `UMD` omit them as well
   pub fn should_call_runtime_require(&self) -> bool {
-    !matches!(self, Self::Cjs | Self::Umd | Self::Iife)
+    !matches!(self, Self::Cjs | Self::Umd | Self::Iife | Self::Amd)
   }
 
   #[inline]
   pub fn source_type(&self) -> SourceType {
     match self {
       Self::Esm => SourceType::mjs(),
-      Self::Cjs | Self::Iife | Self::Umd => SourceType::cjs(),
+      Self::Cjs | Self::Iife | Self::Umd | Self::Amd => SourceType::cjs(),
     }
   }
 }
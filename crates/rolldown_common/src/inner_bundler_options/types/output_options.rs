@@ -0,0 +1,116 @@
+use crate::{
+  AddonOutputOption, AdvancedChunksOptions, AmdOptions, AssetFilenamesOutputOption, BundlerOptions,
+  ChunkFilenamesOutputOption, EsModuleFlag, GeneratedCodeOptions, GlobalsOutputOption,
+  HashCharacters, LegalComments, OutputExports, OutputFormat, PathsOutputOption, RawMinifyOptions,
+  SanitizeFilename, SourceMapIgnoreList, SourceMapPathTransform, SourceMapType,
+};
+
+/// The subset of [`BundlerOptions`] that only affects how the output is generated.
+///
+/// Used to generate multiple outputs from a single scan. See `Bundler::generate_outputs`.
+#[derive(Debug, Default, Clone)]
+pub struct OutputOptions {
+  pub name: Option<String>,
+  pub entry_filenames: Option<ChunkFilenamesOutputOption>,
+  pub chunk_filenames: Option<ChunkFilenamesOutputOption>,
+  pub css_entry_filenames: Option<ChunkFilenamesOutputOption>,
+  pub css_chunk_filenames: Option<ChunkFilenamesOutputOption>,
+  pub asset_filenames: Option<AssetFilenamesOutputOption>,
+  pub sanitize_filename: Option<SanitizeFilename>,
+  pub dir: Option<String>,
+  pub file: Option<String>,
+  pub format: Option<OutputFormat>,
+  pub exports: Option<OutputExports>,
+  pub globals: Option<GlobalsOutputOption>,
+  pub amd: Option<AmdOptions>,
+  pub paths: Option<PathsOutputOption>,
+  pub generated_code: Option<GeneratedCodeOptions>,
+  pub sourcemap: Option<SourceMapType>,
+  pub es_module: Option<EsModuleFlag>,
+  pub hash_characters: Option<HashCharacters>,
+  pub banner: Option<AddonOutputOption>,
+  pub footer: Option<AddonOutputOption>,
+  pub intro: Option<AddonOutputOption>,
+  pub outro: Option<AddonOutputOption>,
+  pub sourcemap_base_url: Option<String>,
+  pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
+  pub sourcemap_path_transform: Option<SourceMapPathTransform>,
+  pub sourcemap_debug_ids: Option<bool>,
+  pub minify: Option<RawMinifyOptions>,
+  pub extend: Option<bool>,
+  pub external_live_bindings: Option<bool>,
+  pub inline_dynamic_imports: Option<bool>,
+  pub advanced_chunks: Option<AdvancedChunksOptions>,
+  pub legal_comments: Option<LegalComments>,
+  pub polyfill_require: Option<bool>,
+  pub preserve_modules: Option<bool>,
+  pub virtual_dirname: Option<String>,
+  pub preserve_modules_root: Option<String>,
+  pub top_level_var: Option<bool>,
+  pub minify_internal_exports: Option<bool>,
+  pub clean_dir: Option<bool>,
+}
+
+macro_rules! override_fields {
+  ($from:ident, $to:ident, [$($field:ident),* $(,)?]) => {
+    $(
+      if $from.$field.is_some() {
+        $to.$field = $from.$field;
+      }
+    )*
+  };
+}
+
+impl OutputOptions {
+  /// Overrides the output options of `options` with the ones specified in `self`.
+  pub fn apply_to(self, options: &mut BundlerOptions) {
+    // `dir` and `file` are mutually exclusive, so they are always overridden together.
+    if self.dir.is_some() || self.file.is_some() {
+      options.dir = self.dir;
+      options.file = self.file;
+    }
+    override_fields!(
+      self,
+      options,
+      [
+        name,
+        entry_filenames,
+        chunk_filenames,
+        css_entry_filenames,
+        css_chunk_filenames,
+        asset_filenames,
+        sanitize_filename,
+        format,
+        exports,
+        globals,
+        amd,
+        paths,
+        generated_code,
+        sourcemap,
+        es_module,
+        hash_characters,
+        banner,
+        footer,
+        intro,
+        outro,
+        sourcemap_base_url,
+        sourcemap_ignore_list,
+        sourcemap_path_transform,
+        sourcemap_debug_ids,
+        minify,
+        extend,
+        external_live_bindings,
+        inline_dynamic_imports,
+        advanced_chunks,
+        legal_comments,
+        polyfill_require,
+        preserve_modules,
+        virtual_dirname,
+        preserve_modules_root,
+        top_level_var,
+        minify_internal_exports,
+        clean_dir,
+      ]
+    );
+  }
+}
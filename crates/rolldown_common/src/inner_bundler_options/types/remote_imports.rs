@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::{future::Future, pin::Pin};
+
+use derive_more::Debug;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+pub type RemoteFetcherFn = dyn Fn(String) -> Pin<Box<dyn Future<Output = anyhow::Result<Vec<u8>>> + Send + 'static>>
+  + Send
+  + Sync;
+
+/// Fetches the content of a remote url.
+#[derive(Clone, Debug)]
+#[debug("RemoteFetcherFn::Fn(...)")]
+pub struct RemoteFetcher(Arc<RemoteFetcherFn>);
+
+impl RemoteFetcher {
+  pub fn new(f: Arc<RemoteFetcherFn>) -> Self {
+    Self(f)
+  }
+
+  pub async fn call(&self, url: String) -> anyhow::Result<Vec<u8>> {
+    self.0(url).await
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct RemoteImportsOptions {
+  /// Directory of the content-addressed cache. Defaults to `node_modules/.rolldown/remote`.
+  pub cache_dir: Option<String>,
+  /// Path of the lockfile that records the integrity of every fetched url. Defaults to
+  /// `rolldown-lock.json`.
+  pub lockfile: Option<String>,
+  /// Only read from the cache, never fetch.
+  pub offline: Option<bool>,
+  /// Fetch `https://host/path` from `<fetchDir>/host/path` instead of the network. Ports and
+  /// queries are kept, e.g. `https://host:8080/path?v=1` is read from
+  /// `<fetchDir>/host_8080/path%3Fv=1`.
+  pub fetch_dir: Option<String>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub fetcher: Option<RemoteFetcher>,
+}
+
+impl RemoteImportsOptions {
+  pub fn cache_dir(&self) -> &str {
+    self.cache_dir.as_deref().unwrap_or("node_modules/.rolldown/remote")
+  }
+
+  pub fn lockfile(&self) -> &str {
+    self.lockfile.as_deref().unwrap_or("rolldown-lock.json")
+  }
+
+  pub fn is_offline(&self) -> bool {
+    self.offline.unwrap_or(false)
+  }
+}
@@ -41,7 +41,7 @@ pub enum TreeshakeOptions {
   Option(InnerOptions),
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct NormalizedTreeshakeOptions(Option<InnerOptions>);
 
 impl Deref for NormalizedTreeshakeOptions {
@@ -90,6 +90,11 @@ impl NormalizedTreeshakeOptions {
       .unwrap_or(PropertyWriteSideEffects::Always)
   }
 
+  /// Whether unread members of top-level object literals and classes are removed.
+  pub fn members(&self) -> bool {
+    self.as_ref().and_then(|item| item.members).unwrap_or(false)
+  }
+
   // TODO: optimize this
   pub fn manual_pure_functions(&self) -> Option<&FxHashSet<String>> {
     self.as_ref().and_then(|item| item.manual_pure_functions.as_ref())
@@ -206,6 +211,7 @@ pub struct InnerOptions {
   pub commonjs: Option<bool>,
   pub property_read_side_effects: Option<PropertyReadSideEffects>,
   pub property_write_side_effects: Option<PropertyWriteSideEffects>,
+  pub members: Option<bool>,
 }
 
 impl Default for InnerOptions {
@@ -218,6 +224,7 @@ impl Default for InnerOptions {
       commonjs: None,
       property_read_side_effects: None,
       property_write_side_effects: None,
+      members: None,
     }
   }
 }
@@ -29,8 +29,10 @@ pub mod bundler_options {
     BundlerOptions,
     types::{
       advanced_chunks_options::{
-        AdvancedChunksOptions, ChunkingContext, MatchGroup, MatchGroupName, MatchGroupTest,
+        AdvancedChunksOptions, ChunkSizeMetric, ChunkSplitStrategy, ChunkingContext, MatchGroup,
+        MatchGroupName, MatchGroupTest,
       },
+      amd_options::AmdOptions,
       attach_debug_info::AttachDebugInfo,
       chunk_import_map::ChunkImportMap,
       chunk_modules_order::ChunkModulesOrderBy,
@@ -42,15 +44,21 @@ pub mod bundler_options {
       generated_code_options::GeneratedCodeOptions,
       hash_characters::HashCharacters,
       hmr_options::HmrOptions,
+      html_entries::HtmlEntriesOptions,
+      import_map::ImportMap,
       inject_import::InjectImport,
-      input_item::InputItem,
+      input_item::{InputItem, matches_input_globs},
       invalidate_js_side_cache::InvalidateJsSideCache,
       is_external::IsExternal,
       legal_comments::LegalComments,
       log_level::LogLevel,
       make_absolute_externals_relative::MakeAbsoluteExternalsRelative,
+      mangle_props::ManglePropsOptions,
+      min_chunk_size::MinChunkSizeOptions,
       minify_options::{MinifyOptions, RawMinifyOptions, RawMinifyOptionsDetailed},
+      module_federation::{ModuleFederationOptions, SharedModuleOptions},
       module_type::ModuleType,
+      node_polyfills::{NodePolyfill, NodePolyfillsOptions},
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
       on_log::{Log, LogWithoutPlugin, OnLog},
       optimization::{
@@ -63,7 +71,9 @@ pub mod bundler_options {
         AddonFunction, AddonOutputOption, AssetFilenamesOutputOption, ChunkFilenamesOutputOption,
         GlobalsOutputOption, PathsOutputOption, PreserveEntrySignatures,
       },
+      output_options::OutputOptions,
       platform::Platform,
+      remote_imports::{RemoteFetcher, RemoteFetcherFn, RemoteImportsOptions},
       resolve_options::ResolveOptions,
       sanitize_filename::SanitizeFilename,
       source_map_type::SourceMapType,
@@ -110,7 +120,7 @@ pub use crate::{
     dynamic_import_usage,
     ecma_asset_meta::EcmaAssetMeta,
     ecma_view::{
-      EcmaModuleAstUsage, EcmaView, EcmaViewMeta, ImportMetaRolldownAssetReplacer,
+      EcmaModuleAstUsage, EcmaView, EcmaViewMeta, ImportMetaRolldownAssetReplacer, MemberReads,
       PrependRenderedImport, ThisExprReplaceKind, generate_replace_this_expr_map,
     },
     json_to_program::{json_value_to_ecma_ast, json_value_to_expression},
@@ -120,7 +130,7 @@ pub use crate::{
   },
   file_emitter::{
     EmittedAsset, EmittedChunk, EmittedChunkInfo, EmittedPrebuiltChunk, FileEmitter,
-    SharedFileEmitter,
+    FileEmitterSnapshot, SharedFileEmitter,
   },
   hmr::{
     client_hmr_input::ClientHmrInput, client_hmr_update::ClientHmrUpdate,
@@ -150,7 +160,7 @@ pub use crate::{
   types::concatenate_wrapped_module::{
     ConcatenateWrappedModuleKind, RenderedConcatenatedModuleParts,
   },
-  types::constant_value::{ConstExportMeta, ConstantValue},
+  types::constant_value::{ConstExportMeta, ConstantValue, EnumMemberValues},
   types::deconflict::ModuleScopeSymbolIdMap,
   types::defer_sync_scan_data::DeferSyncScanData,
   types::entry_point::{EntryPoint, EntryPointKind},
@@ -186,6 +196,7 @@ pub use crate::{
   types::package_json::PackageJson,
   types::plugin_idx::PluginIdx,
   types::rendered_module::RenderedModule,
+  types::resolve_trace::{ResolveTrace, ResolveTraceStep},
   types::resolved_export::ResolvedExport,
   types::resolved_request_info::{ResolvedExternal, ResolvedId},
   types::rollup_pre_rendered_asset::RollupPreRenderedAsset,
@@ -4,8 +4,10 @@ use crate::inner_bundler_options::types::output_option::PathsOutputOption;
 use crate::side_effects::DeterminedSideEffects;
 use crate::{Chunk, ImportRecordIdx, ModuleIdx, ResolvedImportRecord, SymbolRef};
 use arcstr::ArcStr;
+use oxc::span::CompactStr;
 use oxc_index::IndexVec;
 use rolldown_utils::concat_string;
+use rustc_hash::FxHashSet;
 use sugar_path::SugarPath;
 
 #[derive(Debug, Clone)]
@@ -25,6 +27,9 @@ pub struct ExternalModule {
   pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
   pub side_effects: DeterminedSideEffects,
   pub need_renormalize_render_path: bool,
+  /// Named exports of the CommonJS file behind this module, detected when
+  /// `experimental.detectExternalCommonjsExports` is enabled.
+  pub commonjs_exports: Option<FxHashSet<CompactStr>>,
 }
 
 impl ExternalModule {
@@ -47,6 +52,7 @@ impl ExternalModule {
       import_records: IndexVec::default(),
       side_effects,
       need_renormalize_render_path,
+      commonjs_exports: None,
     }
   }
 
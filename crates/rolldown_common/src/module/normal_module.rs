@@ -234,6 +234,20 @@ impl NormalModule {
     self.ecma_view.meta.is_included()
   }
 
+  /// The top-level directives of the module other than `"use strict"`, such as `"use client"`,
+  /// as they are written in the source. Used by `experimental.preserveDirectives`.
+  pub fn custom_directives(&self) -> Vec<&str> {
+    self
+      .ecma_view
+      .directive_range
+      .iter()
+      .map(|range| &self.ecma_view.source[range.start as usize..range.end as usize])
+      .filter(|directive| {
+        directive.trim_start_matches(['\'', '"']).trim_end_matches(['\'', '"', ';']) != "use strict"
+      })
+      .collect()
+  }
+
   #[expect(clippy::cast_precision_loss)]
   pub fn size(&self) -> f64 {
     self.ecma_view.source.len() as f64
@@ -3,10 +3,11 @@ use crate::{
   dynamic_import_usage::DynamicImportExportsUsage, side_effects::DeterminedSideEffects,
 };
 use arcstr::ArcStr;
+use oxc::span::CompactStr;
 use oxc_index::IndexVec;
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildDiagnostic;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub struct NormalModuleTaskResult {
   pub module: Module,
@@ -23,6 +24,7 @@ pub struct ExternalModuleTaskResult {
   pub identifier_name: ArcStr,
   pub side_effects: DeterminedSideEffects,
   pub need_renormalize_render_path: bool,
+  pub commonjs_exports: Option<FxHashSet<CompactStr>>,
 }
 
 pub struct EcmaRelated {
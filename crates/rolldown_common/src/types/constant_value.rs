@@ -1,8 +1,12 @@
 use std::borrow::Cow;
 
 use num_bigint::BigInt;
-use oxc::{ast::ast::Expression, span::SPAN};
+use oxc::{
+  ast::ast::Expression,
+  span::{CompactStr, SPAN},
+};
 use oxc_ecmascript::constant_evaluation;
+use rustc_hash::FxHashMap;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum ConstantValue {
@@ -26,6 +30,9 @@ pub struct ConstExportMeta {
   pub safe_to_inline: bool,
 }
 
+/// Member name -> value of a TypeScript `enum` whose members are all constant.
+pub type EnumMemberValues = FxHashMap<CompactStr, ConstExportMeta>;
+
 impl ConstExportMeta {
   pub fn new(value: ConstantValue, commonjs_export: bool) -> Self {
     let safe_to_inline = match &value {
@@ -40,6 +40,7 @@ pub mod outputs_diagnostics;
 pub mod package_json;
 pub mod plugin_idx;
 pub mod rendered_module;
+pub mod resolve_trace;
 pub mod resolved_export;
 pub mod resolved_request_info;
 pub mod rollup_pre_rendered_asset;
@@ -0,0 +1,122 @@
+use std::{fmt::Display, path::PathBuf};
+
+use arcstr::ArcStr;
+
+/// A record of how a specifier was resolved, collected when `experimental.resolveTrace` is enabled.
+///
+/// Steps are kept in the order they happened, so the trace reads top to bottom like a log.
+#[derive(Debug, Default, Clone)]
+pub struct ResolveTrace {
+  pub steps: Vec<ResolveTraceStep>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ResolveTraceStep {
+  /// A plugin's `resolveId` hook returned a result, so the internal resolver was never consulted.
+  PluginResolved { plugin_name: String },
+  /// The specifier matched a `resolve.alias` entry and was substituted.
+  Alias { key: String, replacements: Vec<Option<String>> },
+  /// The specifier matched a `compilerOptions.paths` pattern of a `tsconfig.json` and was
+  /// substituted.
+  TsconfigPaths { tsconfig: PathBuf, pattern: String, substitutions: Vec<String> },
+  /// The `exports` or `imports` entry of a `package.json` that was used, and the conditions that
+  /// selected its target, outermost first.
+  PackageEntry { package_json: PathBuf, field: &'static str, key: String, conditions: Vec<String> },
+  /// A `tsconfig.json` that was consulted for `paths` mapping.
+  Tsconfig(PathBuf),
+  /// A `package.json` that was read while resolving.
+  PackageJson(PathBuf),
+  /// A candidate path that was tried but didn't exist.
+  Candidate(PathBuf),
+  /// An entry that couldn't be resolved was retried as a path relative to `cwd`, like Rollup does.
+  RollupCompatibility(PathBuf),
+  /// The final path the specifier was resolved to.
+  Resolved(ArcStr),
+  /// The reason why resolution failed.
+  Failed(String),
+}
+
+impl ResolveTrace {
+  pub fn push(&mut self, step: ResolveTraceStep) {
+    self.steps.push(step);
+  }
+
+  pub fn candidates(&self) -> impl Iterator<Item = &PathBuf> {
+    self.steps.iter().filter_map(|step| match step {
+      ResolveTraceStep::Candidate(path) => Some(path),
+      _ => None,
+    })
+  }
+
+  pub fn resolved_by_plugin(&self) -> Option<&str> {
+    self.steps.iter().find_map(|step| match step {
+      ResolveTraceStep::PluginResolved { plugin_name } => Some(plugin_name.as_str()),
+      _ => None,
+    })
+  }
+
+  /// The conditions that selected the target of the last `exports` or `imports` entry used.
+  pub fn matched_conditions(&self) -> Option<&[String]> {
+    self.steps.iter().rev().find_map(|step| match step {
+      ResolveTraceStep::PackageEntry { conditions, .. } => Some(conditions.as_slice()),
+      _ => None,
+    })
+  }
+
+  pub fn resolved(&self) -> Option<&ArcStr> {
+    self.steps.iter().find_map(|step| match step {
+      ResolveTraceStep::Resolved(id) => Some(id),
+      _ => None,
+    })
+  }
+}
+
+impl Display for ResolveTraceStep {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ResolveTraceStep::PluginResolved { plugin_name } => {
+        write!(f, "resolved by the `resolveId` hook of plugin \"{plugin_name}\"")
+      }
+      ResolveTraceStep::Alias { key, replacements } => {
+        let replacements = replacements
+          .iter()
+          .map(|replacement| {
+            replacement.as_deref().map_or("false".to_string(), |r| format!("{r:?}"))
+          })
+          .collect::<Vec<_>>()
+          .join(", ");
+        write!(f, "matched alias {key:?} -> [{replacements}]")
+      }
+      ResolveTraceStep::TsconfigPaths { tsconfig, pattern, substitutions } => {
+        let substitutions =
+          substitutions.iter().map(|s| format!("{s:?}")).collect::<Vec<_>>().join(", ");
+        write!(f, "matched paths {pattern:?} -> [{substitutions}] of {}", tsconfig.display())
+      }
+      ResolveTraceStep::PackageEntry { package_json, field, key, conditions } => {
+        write!(f, "matched {field} {key:?} of {}", package_json.display())?;
+        if !conditions.is_empty() {
+          write!(f, " with conditions [{}]", conditions.join(", "))?;
+        }
+        Ok(())
+      }
+      ResolveTraceStep::Tsconfig(path) => write!(f, "used tsconfig {}", path.display()),
+      ResolveTraceStep::PackageJson(path) => write!(f, "read {}", path.display()),
+      ResolveTraceStep::Candidate(path) => write!(f, "tried {}", path.display()),
+      ResolveTraceStep::RollupCompatibility(path) => {
+        write!(f, "retried as {} for Rollup compatibility", path.display())
+      }
+      ResolveTraceStep::Resolved(id) => write!(f, "resolved to {id}"),
+      ResolveTraceStep::Failed(reason) => write!(f, "failed: {reason}"),
+    }
+  }
+}
+
+impl Display for ResolveTrace {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Resolution trace:")?;
+    for step in &self.steps {
+      write!(f, "\n  - {step}")?;
+    }
+    Ok(())
+  }
+}
@@ -3,7 +3,7 @@ use std::{path::Path, sync::Arc};
 use arcstr::ArcStr;
 use rolldown_utils::stabilize_id::stabilize_id;
 
-use crate::{ModuleDefFormat, PackageJson, side_effects::HookSideEffects};
+use crate::{ModuleDefFormat, PackageJson, ResolveTrace, side_effects::HookSideEffects};
 
 #[derive(Debug, Clone, Copy)]
 pub enum ResolvedExternal {
@@ -45,6 +45,8 @@ pub struct ResolvedId {
   pub package_json: Option<Arc<PackageJson>>,
   pub side_effects: Option<HookSideEffects>,
   pub is_external_without_side_effects: bool,
+  /// Only collected when `experimental.resolveTrace` is enabled.
+  pub resolve_trace: Option<Arc<ResolveTrace>>,
 }
 
 impl ResolvedId {
@@ -60,6 +62,7 @@ impl ResolvedId {
       package_json: None,
       side_effects: None,
       is_external_without_side_effects: false,
+      resolve_trace: None,
     }
   }
 
@@ -85,6 +88,7 @@ impl ResolvedId {
       package_json: None,
       side_effects: None,
       is_external_without_side_effects: true,
+      resolve_trace: None,
     }
   }
 }
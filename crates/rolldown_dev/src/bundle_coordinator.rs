@@ -1,13 +1,13 @@
 use std::{
   collections::VecDeque,
   path::PathBuf,
-  sync::{Arc, atomic::AtomicU32},
+  sync::{Arc, OnceLock, atomic::AtomicU32},
 };
 
 use arcstr::ArcStr;
 use futures::FutureExt;
 use notify::EventKind;
-use rolldown_common::WatcherChangeKind;
+use rolldown_common::{SharedNormalizedBundlerOptions, WatcherChangeKind, matches_input_globs};
 use rolldown_error::BuildResult;
 use rolldown_fs_watcher::{DynFsWatcher, FsEventResult, RecursiveMode};
 use rolldown_utils::{dashmap::FxDashSet, indexmap::FxIndexMap};
@@ -37,6 +37,9 @@ pub struct BundleCoordinator {
   rx: CoordinatorReceiver,
   watcher: Mutex<DynFsWatcher>,
   watched_files: FxDashSet<ArcStr>,
+  /// Options of the bundler, captured once watching starts to check changes against the globs of
+  /// `input` without waiting for the bundler lock.
+  bundler_options: OnceLock<SharedNormalizedBundlerOptions>,
   /// Tracks the state of the initial build
   state: CoordinatorState,
   /// File changes that arrived during initial build
@@ -61,6 +64,7 @@ impl BundleCoordinator {
       rx,
       watcher: Mutex::new(watcher),
       watched_files: FxDashSet::default(),
+      bundler_options: OnceLock::new(),
       state: CoordinatorState::Initialized,
       queued_file_changes_waited_for_full_build: FxIndexMap::default(),
       // Initialize build state with initial build task
@@ -184,7 +188,10 @@ impl BundleCoordinator {
         // The metal model for being `CoordinatorState::Failed` and receiving file changes is a bit of non-intuitive.
         // Like the file is edited 2 times, the first edit is invalid and the second edit fixes the error.
         // We just think the file is changed to second edit directly, ignoring the first invalid edit and follow the usual flow.
-        let task_input = if self.ctx.options.rebuild_strategy.is_always() {
+        let task_input = if self.adds_or_removes_entries(&changed_files) {
+          // The entries matched by the globs of `input` changed, which only a full build picks up.
+          TaskInput::FullBuild
+        } else if self.ctx.options.rebuild_strategy.is_always() {
           TaskInput::HmrRebuild { changed_files }
         } else {
           TaskInput::Hmr { changed_files }
@@ -212,6 +219,20 @@ impl BundleCoordinator {
     }
   }
 
+  /// Whether a file matching the globs of `input` is added or removed
+  fn adds_or_removes_entries(
+    &self,
+    changed_files: &FxIndexMap<PathBuf, WatcherChangeKind>,
+  ) -> bool {
+    let Some(options) = self.bundler_options.get() else {
+      return false;
+    };
+    changed_files.iter().any(|(path, kind)| {
+      matches!(kind, WatcherChangeKind::Create | WatcherChangeKind::Delete)
+        && matches_input_globs(&options.input, &path.relative(&options.cwd).to_slash_lossy())
+    })
+  }
+
   /// Handle build completion notification
   async fn handle_bundle_completed(
     &mut self,
@@ -422,6 +443,7 @@ impl BundleCoordinator {
   async fn update_watch_paths(&self) -> BuildResult<()> {
     let bundler = self.bundler.lock().await;
     let watch_files = bundler.watch_files();
+    let options = self.bundler_options.get_or_init(|| Arc::clone(bundler.options()));
 
     let mut watcher = self.watcher.lock().await;
     let mut paths_mut = watcher.paths_mut();
@@ -432,6 +454,20 @@ impl BundleCoordinator {
         paths_mut.add(watch_file.as_path(), RecursiveMode::NonRecursive)?;
       }
     }
+    // Watch the directories searched by the globs of `input` to pick up added entries.
+    for item in options.input.iter().filter(|item| item.is_glob() && !item.is_negated_glob()) {
+      let base = options.cwd.join(item.glob_base());
+      let base_str = base.to_string_lossy();
+      if base.is_dir() && !self.watched_files.contains(base_str.as_ref()) {
+        self.watched_files.insert(base_str.as_ref().into());
+        let mode = if item.glob_max_depth() == Some(0) {
+          RecursiveMode::NonRecursive
+        } else {
+          RecursiveMode::Recursive
+        };
+        paths_mut.add(&base, mode)?;
+      }
+    }
     paths_mut.commit()?;
     Ok(())
   }
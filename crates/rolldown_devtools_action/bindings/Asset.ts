@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type Asset = { 
+/**
+ * The id of the chunk that the asset is created from. Empty means the asset is not created from a chunk.
+ */
+chunk_id: number | null, content: string | null, 
+/**
+ * The size of the asset in bytes.
+ */
+size: number, filename: string, };
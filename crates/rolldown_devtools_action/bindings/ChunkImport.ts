@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ChunkImport = { 
+/**
+ * Id of the imported chunk
+ */
+chunk_id: number, kind: 'import-statement' | 'dynamic-import', };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HookLoadCallStart = { action: 'HookLoadCallStart', module_id: string, plugin_name: string, 
+/**
+ * The index of the plugin in the plugin list. It's unique to each plugin.
+ */
+plugin_id: number, call_id: string, };
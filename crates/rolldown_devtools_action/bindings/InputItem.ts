@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type InputItem = { 
+/**
+ * For `input: { main: './main.js' }`, `./main.js` has the name `main`.
+ * For `input: ['./main.js']`, `./main.js` doesn't have a name.
+ */
+name: string | null, 
+/**
+ * For `input: { main: './main.js' }`, `./main.js` is the filename.
+ */
+filename: string, };
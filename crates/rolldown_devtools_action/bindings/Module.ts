@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModuleImport } from "./ModuleImport";
+
+export type Module = { id: string, is_external: boolean, imports: Array<ModuleImport> | null, importers: Array<string> | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ModuleImport = { module_id: string, 
+/**
+ * - `import-statement`: `import { foo } from './lib.js';`
+ * - `dynamic-import`: `import('./lib.js')`
+ * - `require-call`: `require('./lib.js')`
+ * - `import-rule`: `@import 'bg-color.css'`
+ * - `url-token`: `url('./icon.png')`
+ * - `new-url`: `new URL('./worker.js', import.meta.url)`
+ * - `hot-accept`: `import.meta.hot.accept('./lib.js', () => {})`
+ */
+kind: 'import-statement' | 'dynamic-import' | 'require-call' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept', 
+/**
+ * `./lib.js` in `import { foo } from './lib.js';`
+ */
+module_request: string, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InputItem } from "./InputItem";
+import type { PluginItem } from "./PluginItem";
+
+export type SessionMeta = { action: 'SessionMeta', inputs: Array<InputItem>, plugins: Array<PluginItem>, cwd: string, platform: 'browser' | 'node' | 'neutral', format: 'esm' | 'cjs' | 'iife' | 'umd' | 'amd', 
+/**
+ * `OutputOptions.dir`
+ */
+dir: string | null, 
+/**
+ * `OutputOptions.file`
+ */
+file: string | null, };
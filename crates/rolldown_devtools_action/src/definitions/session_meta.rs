@@ -11,7 +11,7 @@ pub struct SessionMeta {
   #[ts(type = "'browser' | 'node' | 'neutral'")]
   // Refer to crates/rolldown_common/src/inner_bundler_options/types/platform.rs
   pub platform: String,
-  #[ts(type = "'esm' | 'cjs' | 'iife' | 'umd'")]
+  #[ts(type = "'esm' | 'cjs' | 'iife' | 'umd' | 'amd'")]
   // Refer to crates/rolldown_common/src/inner_bundler_options/types/output_format.rs
   pub format: String,
   /// `OutputOptions.dir`
@@ -3,10 +3,11 @@ use std::path::PathBuf;
 use arcstr::ArcStr;
 use oxc::{
   allocator::Allocator,
-  ast::AstBuilder,
+  ast::{AstBuilder, ast::Program},
   codegen::{Codegen, CodegenOptions, CodegenReturn, CommentOptions, LegalComment},
   minifier::{Minifier, MinifierOptions},
   parser::{ParseOptions, Parser},
+  semantic::{Scoping, SemanticBuilder},
   span::{SPAN, SourceType},
 };
 use oxc_sourcemap::SourceMap;
@@ -127,6 +128,38 @@ impl EcmaCompiler {
       .build(&program);
     (ret.code, ret.map)
   }
+
+  /// Minifies like [`EcmaCompiler::dce_or_minify`] with `compress`, except that the mangler leaves
+  /// the root scope alone and `rename_top_level` decides the names of its symbols instead.
+  #[expect(clippy::too_many_arguments)]
+  pub fn minify_with_top_level_renamer(
+    allocator: &Allocator,
+    source_text: &str,
+    source_type: SourceType,
+    enable_sourcemap: bool,
+    filename: &str,
+    mut minify_options: MinifierOptions,
+    codegen_options: CodegenOptions,
+    rename_top_level: impl FnOnce(&Program<'_>, &mut Scoping),
+  ) -> (String, Option<SourceMap>) {
+    let mut program = Parser::new(allocator, source_text, source_type).parse().program;
+    if let Some(mangle) = &mut minify_options.mangle {
+      mangle.top_level = false;
+    }
+    let ret = Minifier::new(minify_options).minify(allocator, &mut program);
+    let mut scoping =
+      ret.scoping.unwrap_or_else(|| SemanticBuilder::new().build(&program).semantic.into_scoping());
+    rename_top_level(&program, &mut scoping);
+    let ret = Codegen::new()
+      .with_options(CodegenOptions {
+        source_map_path: enable_sourcemap.then(|| PathBuf::from(filename)),
+        ..codegen_options
+      })
+      .with_scoping(Some(scoping))
+      .with_private_member_mappings(ret.class_private_mappings)
+      .build(&program);
+    (ret.code, ret.map)
+  }
 }
 
 #[test]
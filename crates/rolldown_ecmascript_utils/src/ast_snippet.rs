@@ -566,6 +566,93 @@ impl<'ast> AstSnippet<'ast> {
     ))
   }
 
+  /// ```js
+  /// new Promise((resolve, reject) => require([source], resolve, reject))
+  /// ```
+  pub fn amd_require_promise_expr(
+    &self,
+    source: Box<'ast, ast::StringLiteral<'ast>>,
+  ) -> ast::Expression<'ast> {
+    let param = |name: &'static str| {
+      self.builder.formal_parameter(
+        SPAN,
+        self.builder.vec(),
+        self.builder.binding_pattern(
+          self.builder.binding_pattern_kind_binding_identifier(SPAN, name),
+          NONE,
+          false,
+        ),
+        None,
+        false,
+        false,
+      )
+    };
+    // require([source], resolve, reject)
+    let require_call_expr = ast::Expression::CallExpression(self.builder.alloc_call_expression(
+      SPAN,
+      self.id_ref_expr("require", SPAN),
+      NONE,
+      self.builder.vec_from_array([
+        Argument::from(self.builder.expression_array(
+          SPAN,
+          self.builder.vec1(ast::ArrayExpressionElement::StringLiteral(source)),
+        )),
+        Argument::from(self.id_ref_expr("resolve", SPAN)),
+        Argument::from(self.id_ref_expr("reject", SPAN)),
+      ]),
+      false,
+    ));
+    let executor =
+      ast::Expression::ArrowFunctionExpression(self.builder.alloc_arrow_function_expression(
+        SPAN,
+        true,
+        false,
+        NONE,
+        self.builder.formal_parameters(
+          SPAN,
+          ast::FormalParameterKind::Signature,
+          self.builder.vec_from_array([param("resolve"), param("reject")]),
+          NONE,
+        ),
+        NONE,
+        self.builder.function_body(
+          SPAN,
+          self.builder.vec(),
+          self.builder.vec1(ast::Statement::ExpressionStatement(
+            self.builder.alloc_expression_statement(SPAN, require_call_expr),
+          )),
+        ),
+      ));
+    self.builder.expression_new(
+      SPAN,
+      self.id_ref_expr("Promise", SPAN),
+      NONE,
+      self.builder.vec1(Argument::from(executor)),
+    )
+  }
+
+  /// `Promise.all([expr1, expr2])`
+  pub fn promise_all_call_expr(
+    &self,
+    elements: impl IntoIterator<Item = ast::Expression<'ast>>,
+  ) -> ast::Expression<'ast> {
+    ast::Expression::CallExpression(self.builder.alloc_call_expression(
+      SPAN,
+      ast::Expression::StaticMemberExpression(self.builder.alloc_static_member_expression(
+        SPAN,
+        self.id_ref_expr("Promise", SPAN),
+        self.id_name("all", SPAN),
+        false,
+      )),
+      NONE,
+      self.builder.vec1(Argument::from(self.builder.expression_array(
+        SPAN,
+        self.builder.vec_from_iter(elements.into_iter().map(ast::ArrayExpressionElement::from)),
+      ))),
+      false,
+    ))
+  }
+
   pub fn callee_then_call_expr(
     &self,
     call_expr: ast::Expression<'ast>,
@@ -12,6 +12,9 @@ pub enum InvalidOptionType {
   InvalidContext(String),
   IncludeDependenciesRecursivelyWithConflictPreserveEntrySignatures(String),
   IncludeDependenciesRecursivelyWithImplicitPreserveEntrySignatures,
+  UnsupportedModuleFederationFormat(String),
+  NameCacheWithoutTopLevelMangle,
+  DuplicateGlobEntryName { name: String, imports: [String; 2] },
 }
 
 #[derive(Debug)]
@@ -65,6 +68,15 @@ impl BuildEvent for InvalidOption {
             "- Set `preserveEntrySignatures` either to `false` or 'allow-extension' in your config",
           ].join("\n")
         }
+        InvalidOptionType::UnsupportedModuleFederationFormat(format) => {
+          format!("Invalid value \"{format}\" for option \"output.format\" - `experimental.moduleFederation` loads remote and shared modules with top-level await, which is only supported by the \"esm\" format.")
+        }
+        InvalidOptionType::NameCacheWithoutTopLevelMangle => {
+          "`experimental.nameCache` only keeps the mangled names of top-level symbols, which are only mangled with `minify.mangle.toplevel`. The names of other symbols are picked again on every build.".to_string()
+        }
+        InvalidOptionType::DuplicateGlobEntryName { name, imports: [first, second] } => {
+          format!("Invalid value for option \"input\" - \"{first}\" and \"{second}\" are both named \"{name}\". Entries matched by a glob pattern are named after their path relative to the base of the pattern, you may give the glob patterns different names to prefix them.")
+        }
     }
   }
 }
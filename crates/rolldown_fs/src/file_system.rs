@@ -39,4 +39,28 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   ///
   /// * See [std::fs::remove_file]
   fn remove_file(&self, path: &Path) -> io::Result<()>;
+
+  /// # Errors
+  ///
+  /// * See [std::fs::rename]
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+  /// Writes `content` to a temporary file next to `path` and renames it into place, so a build
+  /// that is interrupted or runs at the same time never sees a partially written file.
+  ///
+  /// # Errors
+  ///
+  /// * See [FileSystem::write] and [FileSystem::rename]
+  fn write_atomically(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+      self.create_dir_all(dir)?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = PathBuf::from(temp_path);
+    self.write(&temp_path, content)?;
+    self.rename(&temp_path, path).inspect_err(|_| {
+      let _ = self.remove_file(&temp_path);
+    })
+  }
 }
@@ -48,6 +48,10 @@ impl FileSystem for OsFileSystem {
   fn remove_file(&self, path: &Path) -> io::Result<()> {
     std::fs::remove_file(path)
   }
+
+  fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+    std::fs::rename(from, to)
+  }
 }
 
 impl OxcResolverFileSystem for OsFileSystem {
@@ -149,13 +149,15 @@ impl NativePluginContextImpl {
     fn_asset_filename: Option<String>,
     fn_sanitized_file_name: Option<String>,
   ) -> anyhow::Result<ArcStr> {
+    // The options of the output being generated, which differ from `self.options` for the
+    // additional outputs of `Bundler::generate_outputs`.
+    let options = self.file_emitter.options();
     let file_name_is_none = file.file_name.is_none();
     let asset_filename_template = file_name_is_none.then(|| {
-      FilenameTemplate::new(self.options.asset_filenames.value(fn_asset_filename), "assetFileNames")
-    });
-    let sanitized_file_name = file_name_is_none.then(|| {
-      self.options.sanitize_filename.value(file.name_for_sanitize(), fn_sanitized_file_name)
+      FilenameTemplate::new(options.asset_filenames.value(fn_asset_filename), "assetFileNames")
     });
+    let sanitized_file_name = file_name_is_none
+      .then(|| options.sanitize_filename.value(file.name_for_sanitize(), fn_sanitized_file_name));
 
     self.file_emitter.emit_file(file, asset_filename_template, sanitized_file_name)
   }
@@ -164,8 +166,9 @@ impl NativePluginContextImpl {
     &self,
     file: rolldown_common::EmittedAsset,
   ) -> anyhow::Result<ArcStr> {
-    let asset_filename = self.options.asset_filename_with_file(&file).await?;
-    let sanitized_file_name = self.options.sanitize_file_name_with_file(&file).await?;
+    let options = self.file_emitter.options();
+    let asset_filename = options.asset_filename_with_file(&file).await?;
+    let sanitized_file_name = options.sanitize_file_name_with_file(&file).await?;
     self.file_emitter.emit_file(file, asset_filename, sanitized_file_name)
   }
 
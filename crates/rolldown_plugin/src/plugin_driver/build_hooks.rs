@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::{
   HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
-  HookResolveIdReturn, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
+  HookResolveIdOutput, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
   pluginable::HookTransformAstReturn,
   types::{
     hook_resolve_id_skipped::HookResolveIdSkipped, hook_transform_ast_args::HookTransformAstArgs,
@@ -54,11 +54,12 @@ impl PluginDriver {
     skipped_plugins
   }
 
+  /// Returns the first resolved output together with the index of the plugin that produced it.
   pub async fn resolve_id(
     &self,
     args: &HookResolveIdArgs<'_>,
     skipped_resolve_calls: Option<&Vec<Arc<HookResolveIdSkipped>>>,
-  ) -> HookResolveIdReturn {
+  ) -> Result<Option<(PluginIdx, HookResolveIdOutput)>> {
     let skipped_plugins =
       Self::get_resolve_call_skipped_plugins(args.specifier, args.importer, skipped_resolve_calls);
     for (plugin_idx, plugin, ctx) in
@@ -122,8 +123,8 @@ impl PluginDriver {
       ))
       .await
       .with_context(|| CausedPlugin::new(plugin.call_name()))?;
-      if ret.is_some() {
-        return Ok(ret);
+      if let Some(ret) = ret {
+        return Ok(Some((plugin_idx, ret)));
       }
     }
     Ok(None)
@@ -135,7 +136,7 @@ impl PluginDriver {
     &self,
     args: &HookResolveIdArgs<'_>,
     skipped_resolve_calls: Option<&Vec<Arc<HookResolveIdSkipped>>>,
-  ) -> HookResolveIdReturn {
+  ) -> Result<Option<(PluginIdx, HookResolveIdOutput)>> {
     let skipped_plugins =
       Self::get_resolve_call_skipped_plugins(args.specifier, args.importer, skipped_resolve_calls);
     for (plugin_idx, plugin, ctx) in
@@ -158,7 +159,7 @@ impl PluginDriver {
         .await;
       self.record_timing(plugin_idx, start);
       if let Some(r) = result.with_context(|| CausedPlugin::new(plugin.call_name()))? {
-        return Ok(Some(r));
+        return Ok(Some((plugin_idx, r)));
       }
     }
     Ok(None)
@@ -4,7 +4,8 @@ use crate::{
 };
 use arcstr::ArcStr;
 use rolldown_common::{
-  ImportKind, MakeAbsoluteExternalsRelative, NormalizedBundlerOptions, ResolvedExternal, ResolvedId,
+  ImportKind, MakeAbsoluteExternalsRelative, NodePolyfill, NormalizedBundlerOptions,
+  ResolvedExternal, ResolvedId,
 };
 use rolldown_resolver::{ResolveError, Resolver};
 use std::{path::Path, sync::Arc};
@@ -32,6 +33,19 @@ pub async fn resolve_id_check_external(
     return Ok(Ok(resolve_external(bundle_options, specifier, importer, true).await?.unwrap()));
   }
 
+  // Substitute Node built-in modules with their browser polyfills
+  let specifier = match bundle_options.node_polyfill(specifier) {
+    Some(NodePolyfill::Package(polyfill)) => polyfill,
+    Some(NodePolyfill::Empty) => {
+      return Ok(Ok(ResolvedId {
+        id: specifier.strip_prefix("node:").unwrap_or(specifier).into(),
+        ignored: true,
+        ..Default::default()
+      }));
+    }
+    None => specifier,
+  };
+
   let resolved_id = resolve_id_with_plugins(
     resolver,
     plugin_driver,
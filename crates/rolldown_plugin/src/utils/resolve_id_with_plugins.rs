@@ -3,7 +3,8 @@ use crate::{
   types::{custom_field::CustomField, hook_resolve_id_skipped::HookResolveIdSkipped},
 };
 use rolldown_common::{
-  ImportKind, ModuleDefFormat, PackageJson, ResolvedId, is_existing_node_builtin_modules,
+  ImportKind, ModuleDefFormat, PackageJson, PluginIdx, ResolveTrace, ResolveTraceStep, ResolvedId,
+  is_existing_node_builtin_modules,
 };
 use rolldown_resolver::{ResolveError, Resolver};
 use std::{path::Path, sync::Arc};
@@ -63,7 +64,7 @@ pub async fn resolve_id_with_plugins(
   is_user_defined_entry: bool,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   if matches!(import_kind, ImportKind::DynamicImport) {
-    if let Some(r) = plugin_driver
+    if let Some((plugin_idx, r)) = plugin_driver
       .resolve_dynamic_import(
         &HookResolveIdArgs {
           importer: importer.map(std::convert::AsRef::as_ref),
@@ -88,12 +89,13 @@ pub async fn resolve_id_with_plugins(
         normalize_external_id: r.normalize_external_id,
         side_effects: r.side_effects,
         package_json,
+        resolve_trace: trace_plugin_resolved(resolver, plugin_driver, plugin_idx),
         ..Default::default()
       }));
     }
   }
   // Run plugin resolve_id first, if it is None use internal resolver as fallback
-  if let Some(r) = plugin_driver
+  if let Some((plugin_idx, r)) = plugin_driver
     .resolve_id(
       &HookResolveIdArgs {
         specifier,
@@ -118,6 +120,7 @@ pub async fn resolve_id_with_plugins(
       normalize_external_id: r.normalize_external_id,
       side_effects: r.side_effects,
       package_json,
+      resolve_trace: trace_plugin_resolved(resolver, plugin_driver, plugin_idx),
       ..Default::default()
     }));
   }
@@ -134,6 +137,17 @@ pub async fn resolve_id_with_plugins(
   Ok(resolve_id(resolver, specifier, importer, import_kind, is_user_defined_entry))
 }
 
+fn trace_plugin_resolved(
+  resolver: &Resolver,
+  plugin_driver: &PluginDriver,
+  plugin_idx: PluginIdx,
+) -> Option<Arc<ResolveTrace>> {
+  resolver.is_trace_enabled().then(|| {
+    let plugin_name = plugin_driver.plugins()[plugin_idx].call_name().into_owned();
+    Arc::new(ResolveTrace { steps: vec![ResolveTraceStep::PluginResolved { plugin_name }] })
+  })
+}
+
 fn resolve_id(
   resolver: &Resolver,
   specifier: &str,
@@ -0,0 +1,104 @@
+use std::sync::LazyLock;
+
+use cow_utils::CowUtils as _;
+use regex::Regex;
+
+static HEAD_INJECT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([ \t]*)</head>").unwrap());
+static BODY_PREPEND_INJECT_RE: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"([ \t]*)<body[^>]*>").unwrap());
+static HTML_PREPEND_INJECT_RE: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"([ \t]*)<html[^>]*>").unwrap());
+static DOCTYPE_PREPEND_INJECT_RE: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"(?i)<!doctype html>").unwrap());
+
+pub enum HtmlTag {
+  Script { src: String },
+  InlineScript { code: String },
+  ModulePreload { href: String },
+  Stylesheet { href: String },
+}
+
+impl HtmlTag {
+  fn serialize(&self) -> String {
+    match self {
+      Self::Script { src } => {
+        rolldown_utils::concat_string!(
+          "<script type=\"module\" crossorigin src=\"",
+          escape_attr(src),
+          "\"></script>"
+        )
+      }
+      Self::InlineScript { code } => {
+        // `</script` would close the tag early.
+        rolldown_utils::concat_string!(
+          "<script type=\"module\">\n",
+          code.cow_replace("</script", "<\\/script"),
+          "</script>"
+        )
+      }
+      Self::ModulePreload { href } => rolldown_utils::concat_string!(
+        "<link rel=\"modulepreload\" crossorigin href=\"",
+        escape_attr(href),
+        "\">"
+      ),
+      Self::Stylesheet { href } => rolldown_utils::concat_string!(
+        "<link rel=\"stylesheet\" crossorigin href=\"",
+        escape_attr(href),
+        "\">"
+      ),
+    }
+  }
+}
+
+fn escape_attr(value: &str) -> String {
+  value
+    .cow_replace('&', "&amp;")
+    .cow_replace('"', "&quot;")
+    .cow_replace('<', "&lt;")
+    .cow_replace('>', "&gt;")
+    .into_owned()
+}
+
+fn serialize_tags(tags: &[HtmlTag], indent: &str) -> String {
+  tags.iter().map(|tag| rolldown_utils::concat_string!(indent, tag.serialize(), "\n")).collect()
+}
+
+/// Inserts `tags` at the end of `<head>`, or as early as possible in the document if there is
+/// no `<head>`.
+pub fn inject_to_head(html: &str, tags: &[HtmlTag]) -> String {
+  if tags.is_empty() {
+    return html.to_string();
+  }
+
+  if let Some(caps) = HEAD_INJECT_RE.captures(html) {
+    let indent = rolldown_utils::concat_string!(&caps[1], "  ");
+    let start = caps.get(0).expect("whole match always exists").start();
+    return rolldown_utils::concat_string!(
+      &html[..start],
+      serialize_tags(tags, &indent),
+      &html[start..]
+    );
+  }
+
+  if let Some(caps) = BODY_PREPEND_INJECT_RE.captures(html) {
+    let start = caps.get(0).expect("whole match always exists").start();
+    return rolldown_utils::concat_string!(
+      &html[..start],
+      serialize_tags(tags, &caps[1]),
+      &html[start..]
+    );
+  }
+
+  if let Some(m) =
+    HTML_PREPEND_INJECT_RE.find(html).or_else(|| DOCTYPE_PREPEND_INJECT_RE.find(html))
+  {
+    return rolldown_utils::concat_string!(
+      &html[..m.end()],
+      "\n",
+      serialize_tags(tags, ""),
+      &html[m.end()..]
+    );
+  }
+
+  rolldown_utils::concat_string!(serialize_tags(tags, ""), html)
+}
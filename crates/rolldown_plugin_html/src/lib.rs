@@ -0,0 +1,382 @@
+mod inject;
+mod scanner;
+
+use std::{
+  borrow::Cow,
+  ops::Range,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use arcstr::ArcStr;
+use rolldown_common::{
+  EmittedAsset, ModuleType, NormalizedBundlerOptions, Output, OutputChunk, StrOrBytes,
+  side_effects::HookSideEffects,
+};
+use rolldown_fs::{OsFileSystem, OxcResolverFileSystem as _};
+use rolldown_plugin::{
+  HookLoadOutput, HookResolveIdOutput, HookTransformOutput, HookUsage, LogWithoutPlugin, Plugin,
+};
+use rolldown_std_utils::PathExt as _;
+use rolldown_utils::dashmap::FxDashMap;
+use rustc_hash::FxHashSet;
+use sugar_path::SugarPath as _;
+
+use crate::{
+  inject::{HtmlTag, inject_to_head},
+  scanner::HtmlReference,
+};
+
+/// Edits applied to the source of an HTML entry once the bundle is generated.
+#[derive(Debug)]
+pub enum HtmlEdit {
+  /// Removes a tag whose script or stylesheet is bundled into the entry chunk.
+  Remove(Range<usize>),
+  /// Replaces an attribute value with the url of an emitted asset. `suffix` is the query or hash
+  /// of the original url.
+  Asset { value_span: Range<usize>, reference_id: ArcStr, suffix: String },
+}
+
+#[derive(Debug)]
+pub struct HtmlEntry {
+  pub source: String,
+  pub edits: Vec<HtmlEdit>,
+}
+
+/// Bundles `.html` files as entries, see `experimental.htmlEntries`.
+///
+/// An HTML file is transformed into a module importing its module scripts and stylesheets, while
+/// its other assets are emitted as files. The HTML itself is emitted in `generateBundle`, pointing
+/// to the output files.
+#[derive(Debug)]
+pub struct HtmlPlugin {
+  pub inline_script_limit: usize,
+  pub html_entries: FxDashMap<ArcStr, HtmlEntry>,
+  /// Code of `<script type="module">` tags without `src`, keyed by their virtual module id.
+  pub inline_scripts: FxDashMap<String, String>,
+  fs: OsFileSystem,
+}
+
+impl HtmlPlugin {
+  pub fn new(inline_script_limit: usize, fs: OsFileSystem) -> Self {
+    Self {
+      inline_script_limit,
+      html_entries: FxDashMap::default(),
+      inline_scripts: FxDashMap::default(),
+      fs,
+    }
+  }
+}
+
+impl Plugin for HtmlPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:html")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart
+      | HookUsage::ResolveId
+      | HookUsage::Load
+      | HookUsage::Transform
+      | HookUsage::GenerateBundle
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    _args: &rolldown_plugin::HookBuildStartArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    self.html_entries.clear();
+    self.inline_scripts.clear();
+    Ok(())
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveIdArgs<'_>,
+  ) -> rolldown_plugin::HookResolveIdReturn {
+    Ok(
+      self
+        .inline_scripts
+        .contains_key(args.specifier)
+        .then(|| HookResolveIdOutput::from_id(args.specifier)),
+    )
+  }
+
+  async fn load(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookLoadArgs<'_>,
+  ) -> rolldown_plugin::HookLoadReturn {
+    Ok(self.inline_scripts.get(args.id).map(|code| HookLoadOutput {
+      code: code.as_str().into(),
+      module_type: Some(ModuleType::Js),
+      ..Default::default()
+    }))
+  }
+
+  async fn transform(
+    &self,
+    ctx: rolldown_plugin::SharedTransformPluginContext,
+    args: &rolldown_plugin::HookTransformArgs<'_>,
+  ) -> rolldown_plugin::HookTransformReturn {
+    if !args.id.ends_with(".html") {
+      return Ok(None);
+    }
+
+    let mut js = String::new();
+    let mut edits = Vec::new();
+    let mut inline_script_count = 0usize;
+    for reference in scanner::scan_html(args.code) {
+      match reference {
+        HtmlReference::ModuleScript { src: url, tag_span }
+        | HtmlReference::Stylesheet { href: url, tag_span } => {
+          js.push_str(&import_statement(&to_import_specifier(args.id, &url))?);
+          edits.push(HtmlEdit::Remove(tag_span));
+        }
+        HtmlReference::InlineModuleScript { code, tag_span } => {
+          let id = rolldown_utils::concat_string!(
+            args.id,
+            "?html-inline-script&index=",
+            itoa::Buffer::new().format(inline_script_count),
+            ".js"
+          );
+          inline_script_count += 1;
+          js.push_str(&import_statement(&id)?);
+          self.inline_scripts.insert(id, code);
+          edits.push(HtmlEdit::Remove(tag_span));
+        }
+        HtmlReference::Asset { url, value_span } => {
+          let (path, suffix) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+          let resolved =
+            ctx.resolve(&to_import_specifier(args.id, path), Some(args.id), None).await?;
+          match resolved {
+            Ok(resolved_id) if !resolved_id.external.is_external() => {
+              let file = resolved_id.id.as_str();
+              ctx.add_watch_file(file);
+              match self.fs.read(Path::new(file)) {
+                Ok(source) => {
+                  let reference_id = ctx
+                    .emit_file_async(EmittedAsset {
+                      name: Path::new(file)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned()),
+                      original_file_name: Some(file.to_string()),
+                      file_name: None,
+                      source: StrOrBytes::Bytes(source),
+                    })
+                    .await?;
+                  edits.push(HtmlEdit::Asset {
+                    value_span,
+                    reference_id,
+                    suffix: suffix.to_string(),
+                  });
+                }
+                // Such as a directory, which is left to be resolved at runtime like missing files.
+                Err(err) => ctx.warn(LogWithoutPlugin {
+                  message: rolldown_utils::concat_string!(
+                    url,
+                    " referenced in ",
+                    args.id,
+                    " can't be read (",
+                    err.to_string(),
+                    "), it will remain unchanged to be resolved at runtime"
+                  ),
+                  ..Default::default()
+                }),
+              }
+            }
+            _ => ctx.warn(LogWithoutPlugin {
+              message: rolldown_utils::concat_string!(
+                url,
+                " referenced in ",
+                args.id,
+                " doesn't exist at build time, it will remain unchanged to be resolved at runtime"
+              ),
+              ..Default::default()
+            }),
+          }
+        }
+      }
+    }
+
+    self.html_entries.insert(args.id.into(), HtmlEntry { source: args.code.clone(), edits });
+
+    // Keep the module even if it has no side effects, so that the HTML always gets an entry chunk.
+    Ok(Some(HookTransformOutput {
+      code: Some(js),
+      side_effects: Some(HookSideEffects::NoTreeshake),
+      module_type: Some(ModuleType::Js),
+      ..Default::default()
+    }))
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &mut rolldown_plugin::HookGenerateBundleArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    // HTML files imported by other modules don't have an entry chunk and are left alone.
+    let mut entries = self
+      .html_entries
+      .iter()
+      .filter_map(|entry| {
+        let chunk = args.bundle.iter().find_map(|output| match output {
+          Output::Chunk(chunk)
+            if chunk.is_entry
+              && chunk
+                .facade_module_id
+                .as_ref()
+                .is_some_and(|id| id.resource_id().as_str() == entry.key().as_str()) =>
+          {
+            Some(Arc::clone(chunk))
+          }
+          _ => None,
+        })?;
+        Some((entry.key().clone(), chunk))
+      })
+      .collect::<Vec<_>>();
+    if entries.is_empty() {
+      return Ok(());
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // HTML files keep their location relative to the directory containing all of them.
+    let root = common_dir(entries.iter().map(|(id, _)| id.as_str()));
+    let mut removed_chunks = FxHashSet::default();
+    for (id, chunk) in entries {
+      let Some(entry) = self.html_entries.get(&id) else { continue };
+      let file_name = Path::new(id.as_str()).relative(&root).expect_to_slash();
+      let html_dir = Path::new(&file_name).parent().unwrap_or(Path::new("")).to_path_buf();
+      let url_for = |filename: &str| Path::new(filename).relative(&html_dir).expect_to_slash();
+
+      let mut s = string_wizard::MagicString::new(entry.source.as_str());
+      for edit in &entry.edits {
+        match edit {
+          HtmlEdit::Remove(span) => {
+            s.remove(span.start, span.end);
+          }
+          HtmlEdit::Asset { value_span, reference_id, suffix } => {
+            let url = url_for(&ctx.get_file_name(reference_id)?);
+            s.update(value_span.start, value_span.end, rolldown_utils::concat_string!(url, suffix));
+          }
+        }
+      }
+
+      let imported_chunks = get_imported_chunks(&chunk, args.bundle);
+      let mut tags = Vec::new();
+      if chunk.code.trim().is_empty() && imported_chunks.is_empty() {
+        // Nothing to load, e.g. the HTML doesn't contain any module script.
+        removed_chunks.insert(chunk.filename.clone());
+      } else if self.can_inline(&chunk, args.options) {
+        tags.push(HtmlTag::InlineScript { code: chunk.code.clone() });
+        removed_chunks.insert(chunk.filename.clone());
+      } else {
+        tags.push(HtmlTag::Script { src: url_for(&chunk.filename) });
+        tags.extend(
+          imported_chunks
+            .iter()
+            .map(|imported| HtmlTag::ModulePreload { href: url_for(&imported.filename) }),
+        );
+      }
+
+      let module_ids = std::iter::once(&chunk)
+        .chain(&imported_chunks)
+        .flat_map(|chunk| chunk.module_ids.iter().map(|id| id.resource_id().as_str()))
+        .collect::<FxHashSet<_>>();
+      tags.extend(args.bundle.iter().filter_map(|output| match output {
+        Output::Asset(asset)
+          if asset.filename.ends_with(".css")
+            && asset.original_file_names.iter().any(|file| module_ids.contains(file.as_str())) =>
+        {
+          Some(HtmlTag::Stylesheet { href: url_for(&asset.filename) })
+        }
+        _ => None,
+      }));
+
+      let html = inject_to_head(&s.to_string(), &tags);
+      drop(entry);
+      ctx
+        .emit_file_async(EmittedAsset {
+          name: None,
+          original_file_name: Some(id.to_string()),
+          file_name: Some(file_name.into()),
+          source: StrOrBytes::Str(html),
+        })
+        .await?;
+    }
+
+    args.bundle.retain(|output| match output {
+      Output::Chunk(chunk) => !removed_chunks.contains(&chunk.filename),
+      Output::Asset(_) => true,
+    });
+
+    Ok(())
+  }
+}
+
+impl HtmlPlugin {
+  /// Entry scripts are only inlined if they are self-contained, since the imports of an inlined
+  /// script would be resolved relative to the HTML instead of the chunk.
+  fn can_inline(&self, chunk: &OutputChunk, options: &NormalizedBundlerOptions) -> bool {
+    chunk.code.len() < self.inline_script_limit
+      && chunk.imports.is_empty()
+      && chunk.dynamic_imports.is_empty()
+      && options.sourcemap.is_none()
+  }
+}
+
+/// Urls in HTML are relative to the document unless they start with `/`, which is treated as the
+/// directory of the HTML file.
+fn to_import_specifier(html_id: &str, url: &str) -> String {
+  if let Some(path) = url.strip_prefix('/') {
+    let dir = Path::new(html_id).parent().unwrap_or(Path::new(""));
+    dir.join(path).to_string_lossy().into_owned()
+  } else if url.starts_with("./") || url.starts_with("../") {
+    url.to_string()
+  } else {
+    rolldown_utils::concat_string!("./", url)
+  }
+}
+
+fn import_statement(specifier: &str) -> anyhow::Result<String> {
+  Ok(rolldown_utils::concat_string!("import ", serde_json::to_string(specifier)?, ";\n"))
+}
+
+fn common_dir<'a>(ids: impl Iterator<Item = &'a str>) -> PathBuf {
+  let mut dirs = ids.map(|id| Path::new(id).parent().unwrap_or(Path::new("")));
+  let first = dirs.next().unwrap_or(Path::new("")).to_path_buf();
+  dirs.fold(first, |common, dir| {
+    common.ancestors().find(|ancestor| dir.starts_with(ancestor)).unwrap_or(Path::new("")).into()
+  })
+}
+
+/// The chunks statically imported by `chunk`, directly or not, dependencies first.
+fn get_imported_chunks(chunk: &OutputChunk, bundle: &[Output]) -> Vec<Arc<OutputChunk>> {
+  fn visit(
+    chunk: &OutputChunk,
+    bundle: &[Output],
+    seen: &mut FxHashSet<ArcStr>,
+    chunks: &mut Vec<Arc<OutputChunk>>,
+  ) {
+    for file in &chunk.imports {
+      let importee = bundle.iter().find_map(|output| match output {
+        Output::Chunk(c) if c.filename == *file => Some(c),
+        _ => None,
+      });
+      // Imports that aren't in the bundle are externals.
+      if let Some(importee) = importee
+        && seen.insert(file.clone())
+      {
+        visit(importee, bundle, seen, chunks);
+        chunks.push(Arc::clone(importee));
+      }
+    }
+  }
+
+  let mut seen = FxHashSet::default();
+  let mut chunks = Vec::new();
+  visit(chunk, bundle, &mut seen, &mut chunks);
+  chunks
+}
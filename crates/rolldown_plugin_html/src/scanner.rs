@@ -0,0 +1,151 @@
+use std::ops::Range;
+
+use html5gum::{DefaultEmitter, Token, Tokenizer};
+use rustc_hash::FxHashMap;
+
+/// A reference to another file found in an HTML document.
+#[derive(Debug)]
+pub enum HtmlReference {
+  /// `<script type="module" src="...">`
+  ModuleScript { src: String, tag_span: Range<usize> },
+  /// `<script type="module">...</script>`
+  InlineModuleScript { code: String, tag_span: Range<usize> },
+  /// `<link rel="stylesheet" href="...">`
+  Stylesheet { href: String, tag_span: Range<usize> },
+  /// Attributes such as `<img src="...">` whose value points to a static asset.
+  Asset { url: String, value_span: Range<usize> },
+}
+
+struct Attribute {
+  value: String,
+  /// Span of the value, without the surrounding quotes.
+  value_span: Option<Range<usize>>,
+}
+
+struct PendingScript {
+  start: usize,
+  src: Option<String>,
+  code: String,
+}
+
+/// Collects the references to scripts, stylesheets and assets in `html`, in source order.
+pub fn scan_html(html: &str) -> Vec<HtmlReference> {
+  let mut emitter = DefaultEmitter::<usize>::new_with_span();
+  emitter.naively_switch_states(true);
+
+  let mut references = Vec::new();
+  let mut pending_script: Option<PendingScript> = None;
+
+  for token in Tokenizer::new_with_emitter(html, emitter) {
+    match token {
+      Ok(Token::StartTag(tag)) => {
+        let name = String::from_utf8_lossy(&tag.name).into_owned();
+        let attrs = tag
+          .attributes
+          .into_iter()
+          .map(|(name, value)| {
+            (
+              String::from_utf8_lossy(&name.0).into_owned(),
+              Attribute {
+                value: String::from_utf8_lossy(&value.value.0).into_owned(),
+                value_span: attribute_value_span(html, value.span.start..value.span.end),
+              },
+            )
+          })
+          .collect::<FxHashMap<_, _>>();
+
+        match name.as_str() {
+          "script" => {
+            if attrs.get("type").is_some_and(|attr| attr.value == "module") {
+              let src =
+                attrs.get("src").map(|attr| attr.value.clone()).filter(|src| !is_excluded_url(src));
+              if src.is_some() || !attrs.contains_key("src") {
+                pending_script =
+                  Some(PendingScript { start: tag.span.start, src, code: String::new() });
+              }
+            }
+          }
+          "link" => {
+            let Some(href) = attrs.get("href").filter(|attr| !is_excluded_url(&attr.value)) else {
+              continue;
+            };
+            let Some(value_span) = href.value_span.clone() else {
+              continue;
+            };
+            let rels = attrs.get("rel").map(|attr| attr.value.to_ascii_lowercase());
+            let mut rels = rels.as_deref().unwrap_or_default().split_ascii_whitespace();
+            if rels.clone().any(|rel| rel == "stylesheet") {
+              if !attrs.contains_key("media") && !attrs.contains_key("disabled") {
+                references.push(HtmlReference::Stylesheet {
+                  href: href.value.clone(),
+                  tag_span: tag.span.start..tag.span.end,
+                });
+              }
+            } else if rels.any(|rel| {
+              matches!(rel, "icon" | "apple-touch-icon" | "apple-touch-startup-image" | "manifest")
+            }) {
+              references.push(HtmlReference::Asset { url: href.value.clone(), value_span });
+            }
+          }
+          _ => {
+            let asset_attrs: &[&str] = match name.as_str() {
+              "audio" | "embed" | "img" | "input" | "source" | "track" => &["src"],
+              "image" | "use" => &["href", "xlink:href"],
+              "object" => &["data"],
+              "video" => &["src", "poster"],
+              _ => &[],
+            };
+            for attr in asset_attrs.iter().filter_map(|name| attrs.get(*name)) {
+              if let Some(value_span) = &attr.value_span
+                && !attr.value.is_empty()
+                && !is_excluded_url(&attr.value)
+              {
+                references.push(HtmlReference::Asset {
+                  url: attr.value.clone(),
+                  value_span: value_span.clone(),
+                });
+              }
+            }
+          }
+        }
+      }
+      Ok(Token::String(text)) => {
+        if let Some(script) = &mut pending_script {
+          script.code.push_str(&String::from_utf8_lossy(&text.0));
+        }
+      }
+      Ok(Token::EndTag(tag)) => {
+        if tag.name.0 == b"script"
+          && let Some(script) = pending_script.take()
+        {
+          let tag_span = script.start..tag.span.end;
+          references.push(match script.src {
+            Some(src) => HtmlReference::ModuleScript { src, tag_span },
+            None => HtmlReference::InlineModuleScript { code: script.code, tag_span },
+          });
+        }
+      }
+      Ok(_) => {}
+      Err(_) => break,
+    }
+  }
+
+  references
+}
+
+/// The span html5gum reports for an attribute covers `name="value"`, narrow it down to `value`.
+fn attribute_value_span(html: &str, span: Range<usize>) -> Option<Range<usize>> {
+  let src = &html.as_bytes()[span.clone()];
+  let eq = src.iter().position(|&b| b == b'=')?;
+  let start = eq + 1 + src[eq + 1..].iter().position(|b| !b.is_ascii_whitespace())?;
+  let quote_len = usize::from(matches!(src[start], b'"' | b'\''));
+  Some(span.start + start + quote_len..span.end - quote_len)
+}
+
+/// Urls that point outside of the bundle, such as `https://...`, `//cdn...`, `data:...` or `#id`.
+pub fn is_excluded_url(url: &str) -> bool {
+  url.starts_with('#')
+    || url.starts_with("//")
+    || url.trim_start().get(..5).is_some_and(|prefix| prefix.eq_ignore_ascii_case("data:"))
+    || url.find("://").is_some_and(|i| i > 0 && url[..i].bytes().all(|b| b.is_ascii_lowercase()))
+}
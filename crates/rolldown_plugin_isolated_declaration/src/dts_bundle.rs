@@ -0,0 +1,673 @@
+use arcstr::ArcStr;
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{
+    BindingIdentifier, BindingPatternKind, Declaration, ExportDefaultDeclarationKind,
+    IdentifierReference, ImportDeclarationSpecifier, Statement, TSModuleDeclarationName,
+  },
+  ast_visit::Visit,
+  parser::Parser,
+  semantic::{Scoping, SemanticBuilder},
+  span::{GetSpan, SourceType, Span},
+  syntax::scope::ScopeFlags,
+};
+use rolldown_utils::{concat_string, conflictless_names::ConflictlessNames, indexmap::FxIndexMap};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The declaration of a module, collected in the `transform_ast` hook.
+#[derive(Debug, Clone)]
+pub struct DtsModule {
+  pub source: ArcStr,
+  /// Maps import specifiers to the ids of the modules they resolve to. `None` means the import is
+  /// kept in the bundled declaration as-is, e.g. types of an external package.
+  pub resolved_imports: FxHashMap<String, Option<ArcStr>>,
+}
+
+/// Rolls up the declarations reachable from the exports of `entry` into a single `.d.ts` file.
+///
+/// Only the declarations used by the exports are kept. Declarations of other modules are inlined
+/// and top-level names are deconflicted the same way as the names of JavaScript chunks.
+pub fn bundle_dts(entry: &str, modules: &FxHashMap<ArcStr, DtsModule>) -> Option<String> {
+  let allocator = Allocator::default();
+  let mut bundler = DtsBundler { allocator: &allocator, modules, parsed: FxIndexMap::default() };
+  let entry_idx = bundler.module_idx(entry)?;
+  Some(bundler.bundle(entry_idx))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ImportedName<'a> {
+  Name(&'a str),
+  Namespace,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ExportBinding<'a> {
+  Local(&'a str),
+  ReExport { source: &'a str, imported: ImportedName<'a> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Target<'a> {
+  Local(usize, &'a str),
+  Namespace(usize),
+  External { source: &'a str, imported: ImportedName<'a> },
+}
+
+#[derive(Debug)]
+struct DeclStatement<'a> {
+  span: Span,
+  /// Whether `declare ` needs to be prepended, which is required for most top-level declarations in
+  /// `.d.ts` files.
+  needs_declare: bool,
+  /// Where the name of an anonymous default export needs to be inserted.
+  anonymous_name_at: Option<u32>,
+  names: Vec<(&'a str, Span)>,
+  /// References to top-level declarations and imports.
+  references: Vec<(&'a str, Span)>,
+  /// References to global types and values, which must not be shadowed by a top-level name.
+  globals: Vec<&'a str>,
+}
+
+#[derive(Debug, Default)]
+struct ParsedModule<'a> {
+  id: &'a str,
+  source: &'a str,
+  statements: Vec<DeclStatement<'a>>,
+  /// A name may be declared by several statements, e.g. function overloads or merged interfaces.
+  declarations: FxHashMap<&'a str, Vec<usize>>,
+  imports: FxHashMap<&'a str, (&'a str, ImportedName<'a>)>,
+  exports: FxIndexMap<&'a str, ExportBinding<'a>>,
+  star_exports: Vec<&'a str>,
+  /// Statements that are always kept, like `declare global {}` and `declare module "x" {}`.
+  ambient_statements: Vec<usize>,
+}
+
+/// The name of an anonymous default export declared by a `DeclStatement`.
+const ANONYMOUS_DEFAULT_NAME: &str = "_default";
+
+struct ReferenceCollector<'s, 'a> {
+  scoping: &'s Scoping,
+  references: Vec<(&'a str, Span)>,
+  globals: Vec<&'a str>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'_, 'a> {
+  fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+    let symbol_id = it.reference_id.get().and_then(|id| self.scoping.get_reference(id).symbol_id());
+    match symbol_id {
+      Some(symbol_id) => {
+        // Type parameters, parameters and the members of namespaces are never renamed.
+        if self.scoping.symbol_scope_id(symbol_id) == self.scoping.root_scope_id() {
+          self.references.push((it.name.as_str(), it.span));
+        }
+      }
+      None => self.globals.push(it.name.as_str()),
+    }
+  }
+}
+
+impl<'a> ParsedModule<'a> {
+  fn parse(allocator: &'a Allocator, id: &'a str, source: &'a str) -> Self {
+    let mut module = ParsedModule { id, source, ..Default::default() };
+    let ret = Parser::new(allocator, source, SourceType::d_ts()).parse();
+    if ret.panicked {
+      return module;
+    }
+    let scoping = SemanticBuilder::new().build(&ret.program).semantic.into_scoping();
+    let scoping = &scoping;
+    for stmt in &ret.program.body {
+      match stmt {
+        Statement::ImportDeclaration(decl) => {
+          let source = decl.source.value.as_str();
+          for specifier in decl.specifiers.iter().flatten() {
+            let (local, imported) = match specifier {
+              ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                (s.local.name.as_str(), ImportedName::Name(s.imported.name().as_str()))
+              }
+              ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+                (s.local.name.as_str(), ImportedName::Name("default"))
+              }
+              ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => {
+                (s.local.name.as_str(), ImportedName::Namespace)
+              }
+            };
+            module.imports.insert(local, (source, imported));
+          }
+        }
+        Statement::ExportNamedDeclaration(decl) => {
+          if let Some(declaration) = &decl.declaration {
+            let names = module.add_declaration(scoping, declaration);
+            for name in names {
+              module.exports.insert(name, ExportBinding::Local(name));
+            }
+          }
+          for specifier in &decl.specifiers {
+            let exported = specifier.exported.name().as_str();
+            let local = specifier.local.name().as_str();
+            let binding = match &decl.source {
+              Some(source) => ExportBinding::ReExport {
+                source: source.value.as_str(),
+                imported: ImportedName::Name(local),
+              },
+              None => ExportBinding::Local(local),
+            };
+            module.exports.insert(exported, binding);
+          }
+        }
+        Statement::ExportAllDeclaration(decl) => {
+          let source = decl.source.value.as_str();
+          match &decl.exported {
+            Some(exported) => {
+              module.exports.insert(
+                exported.name().as_str(),
+                ExportBinding::ReExport { source, imported: ImportedName::Namespace },
+              );
+            }
+            None => module.star_exports.push(source),
+          }
+        }
+        Statement::ExportDefaultDeclaration(decl) => {
+          let local = match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => module
+              .add_function_or_class(scoping, func.span, func.id.as_ref(), |v| {
+                v.visit_function(func, ScopeFlags::empty());
+              }),
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+              module.add_function_or_class(scoping, class.span, class.id.as_ref(), |v| {
+                v.visit_class(class);
+              })
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+              module.add_statement(
+                scoping,
+                interface.span,
+                vec![(interface.id.name.as_str(), interface.id.span)],
+                |v| v.visit_ts_interface_declaration(interface),
+              );
+              interface.id.name.as_str()
+            }
+            ExportDefaultDeclarationKind::Identifier(ident) => ident.name.as_str(),
+            // Isolated declarations always hoist other default exported expressions into a variable.
+            _ => continue,
+          };
+          module.exports.insert("default", ExportBinding::Local(local));
+        }
+        _ => {
+          if let Some(declaration) = stmt.as_declaration() {
+            module.add_declaration(scoping, declaration);
+          }
+        }
+      }
+    }
+    module
+  }
+
+  fn add_declaration(&mut self, scoping: &Scoping, declaration: &Declaration<'a>) -> Vec<&'a str> {
+    let names: Vec<(&'a str, Span)> = match declaration {
+      Declaration::VariableDeclaration(decl) => decl
+        .declarations
+        .iter()
+        .filter_map(|declarator| match &declarator.id.kind {
+          BindingPatternKind::BindingIdentifier(id) => Some((id.name.as_str(), id.span)),
+          _ => None,
+        })
+        .collect(),
+      Declaration::FunctionDeclaration(func) => {
+        func.id.iter().map(|id| (id.name.as_str(), id.span)).collect()
+      }
+      Declaration::ClassDeclaration(class) => {
+        class.id.iter().map(|id| (id.name.as_str(), id.span)).collect()
+      }
+      Declaration::TSTypeAliasDeclaration(decl) => vec![(decl.id.name.as_str(), decl.id.span)],
+      Declaration::TSInterfaceDeclaration(decl) => vec![(decl.id.name.as_str(), decl.id.span)],
+      Declaration::TSEnumDeclaration(decl) => vec![(decl.id.name.as_str(), decl.id.span)],
+      Declaration::TSImportEqualsDeclaration(decl) => vec![(decl.id.name.as_str(), decl.id.span)],
+      Declaration::TSModuleDeclaration(decl) => match &decl.id {
+        TSModuleDeclarationName::Identifier(id) => vec![(id.name.as_str(), id.span)],
+        TSModuleDeclarationName::StringLiteral(_) => vec![],
+      },
+      Declaration::TSGlobalDeclaration(_) => vec![],
+    };
+    let declared_names = names.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    let is_ambient = declared_names.is_empty()
+      && matches!(
+        declaration,
+        Declaration::TSModuleDeclaration(_) | Declaration::TSGlobalDeclaration(_)
+      );
+    let idx =
+      self.add_statement(scoping, declaration.span(), names, |v| v.visit_declaration(declaration));
+    if is_ambient {
+      self.ambient_statements.push(idx);
+    }
+    declared_names
+  }
+
+  fn add_function_or_class(
+    &mut self,
+    scoping: &Scoping,
+    span: Span,
+    id: Option<&BindingIdentifier<'a>>,
+    visit: impl FnOnce(&mut ReferenceCollector<'_, 'a>),
+  ) -> &'a str {
+    let names = id.map(|id| (id.name.as_str(), id.span));
+    let local = names.map_or(ANONYMOUS_DEFAULT_NAME, |(name, _)| name);
+    let idx = self.add_statement(scoping, span, names.into_iter().collect(), visit);
+    if id.is_none() {
+      // Insert the name right after the `function` or `class` keyword.
+      let text = &self.source[span.start as usize..span.end as usize];
+      let keyword_end = ["function", "class"]
+        .iter()
+        .filter_map(|keyword| text.find(keyword).map(|pos| pos + keyword.len()))
+        .min()
+        .unwrap_or_default();
+      let at = span.start + u32::try_from(keyword_end).unwrap_or_default();
+      self.statements[idx].anonymous_name_at = Some(at);
+      self.declarations.entry(ANONYMOUS_DEFAULT_NAME).or_default().push(idx);
+    }
+    local
+  }
+
+  fn add_statement(
+    &mut self,
+    scoping: &Scoping,
+    span: Span,
+    names: Vec<(&'a str, Span)>,
+    visit: impl FnOnce(&mut ReferenceCollector<'_, 'a>),
+  ) -> usize {
+    let mut collector = ReferenceCollector { scoping, references: vec![], globals: vec![] };
+    visit(&mut collector);
+    let text = &self.source[span.start as usize..span.end as usize];
+    let needs_declare =
+      !["declare ", "interface ", "type ", "import "].iter().any(|prefix| text.starts_with(prefix));
+    let idx = self.statements.len();
+    for (name, _) in &names {
+      self.declarations.entry(*name).or_default().push(idx);
+    }
+    self.statements.push(DeclStatement {
+      span,
+      needs_declare,
+      anonymous_name_at: None,
+      names,
+      references: collector.references,
+      globals: collector.globals,
+    });
+    idx
+  }
+}
+
+struct DtsBundler<'a> {
+  allocator: &'a Allocator,
+  modules: &'a FxHashMap<ArcStr, DtsModule>,
+  parsed: FxIndexMap<&'a str, ParsedModule<'a>>,
+}
+
+impl<'a> DtsBundler<'a> {
+  fn module_idx(&mut self, id: &str) -> Option<usize> {
+    if let Some(idx) = self.parsed.get_index_of(id) {
+      return Some(idx);
+    }
+    let modules = self.modules;
+    let (id, module) = modules.get_key_value(id)?;
+    let parsed = ParsedModule::parse(self.allocator, id.as_str(), module.source.as_str());
+    Some(self.parsed.insert_full(id.as_str(), parsed).0)
+  }
+
+  fn resolve_source(
+    &mut self,
+    importer: usize,
+    source: &'a str,
+    imported: ImportedName<'a>,
+    visited: &mut FxHashSet<(usize, &'a str)>,
+  ) -> Option<Target<'a>> {
+    let importer_id = self.parsed[importer].id;
+    let resolved = self.modules[importer_id].resolved_imports.get(source).cloned().flatten();
+    let Some(module_idx) = resolved.and_then(|id| self.module_idx(&id)) else {
+      return Some(Target::External { source, imported });
+    };
+    match imported {
+      ImportedName::Namespace => Some(Target::Namespace(module_idx)),
+      ImportedName::Name(name) => self.resolve_export(module_idx, name, visited),
+    }
+  }
+
+  fn resolve_export(
+    &mut self,
+    module_idx: usize,
+    name: &'a str,
+    visited: &mut FxHashSet<(usize, &'a str)>,
+  ) -> Option<Target<'a>> {
+    if !visited.insert((module_idx, name)) {
+      return None;
+    }
+    match self.parsed[module_idx].exports.get(name).copied() {
+      Some(ExportBinding::Local(local)) => self.resolve_local(module_idx, local, visited),
+      Some(ExportBinding::ReExport { source, imported }) => {
+        self.resolve_source(module_idx, source, imported, visited)
+      }
+      None if name == "default" => None,
+      None => {
+        let star_exports = self.parsed[module_idx].star_exports.clone();
+        star_exports.into_iter().find_map(|source| {
+          match self.resolve_source(module_idx, source, ImportedName::Name(name), visited) {
+            // A name can't be known to be exported by an external module.
+            Some(Target::External { .. }) | None => None,
+            target => target,
+          }
+        })
+      }
+    }
+  }
+
+  fn resolve_local(
+    &mut self,
+    module_idx: usize,
+    local: &'a str,
+    visited: &mut FxHashSet<(usize, &'a str)>,
+  ) -> Option<Target<'a>> {
+    let module = &self.parsed[module_idx];
+    if module.declarations.contains_key(local) {
+      return Some(Target::Local(module_idx, local));
+    }
+    let (source, imported) = module.imports.get(local).copied()?;
+    self.resolve_source(module_idx, source, imported, visited)
+  }
+
+  /// Collects the exports of a module, including the ones from `export * from '...'`.
+  fn collect_exports(
+    &mut self,
+    module_idx: usize,
+    exports: &mut FxIndexMap<&'a str, Target<'a>>,
+    external_star_exports: &mut Vec<&'a str>,
+    visited_modules: &mut FxHashSet<usize>,
+  ) {
+    if !visited_modules.insert(module_idx) {
+      return;
+    }
+    let names = self.parsed[module_idx].exports.keys().copied().collect::<Vec<_>>();
+    for name in names {
+      if exports.contains_key(name) {
+        continue;
+      }
+      if let Some(target) = self.resolve_export(module_idx, name, &mut FxHashSet::default()) {
+        exports.insert(name, target);
+      }
+    }
+    let star_exports = self.parsed[module_idx].star_exports.clone();
+    for source in star_exports {
+      let importer_id = self.parsed[module_idx].id;
+      let resolved = self.modules[importer_id].resolved_imports.get(source).cloned().flatten();
+      match resolved.and_then(|id| self.module_idx(&id)) {
+        Some(idx) => {
+          let mut star_exports = FxIndexMap::default();
+          self.collect_exports(idx, &mut star_exports, external_star_exports, visited_modules);
+          for (name, target) in star_exports {
+            if name != "default" {
+              exports.entry(name).or_insert(target);
+            }
+          }
+        }
+        None => external_star_exports.push(source),
+      }
+    }
+  }
+
+  fn bundle(&mut self, entry_idx: usize) -> String {
+    let mut exports = FxIndexMap::default();
+    let mut external_star_exports = vec![];
+    self.collect_exports(
+      entry_idx,
+      &mut exports,
+      &mut external_star_exports,
+      &mut FxHashSet::default(),
+    );
+
+    // Tree-shake the declarations, starting from the exports and the ambient declarations.
+    let mut included_statements: FxHashSet<(usize, usize)> = FxHashSet::default();
+    let mut included_targets: FxIndexMap<Target<'a>, Option<&'a str>> = FxIndexMap::default();
+    let mut worklist: Vec<(Target<'a>, &'a str)> =
+      exports.iter().map(|(name, target)| (*target, *name)).collect();
+    let mut scanned_modules = 0;
+    loop {
+      // Modules may be parsed while resolving, so keep including the ambient declarations of them.
+      while scanned_modules < self.parsed.len() {
+        let ambient_statements = self.parsed[scanned_modules].ambient_statements.clone();
+        for stmt_idx in ambient_statements {
+          self.include_statement(
+            scanned_modules,
+            stmt_idx,
+            &mut included_statements,
+            &mut worklist,
+          );
+        }
+        scanned_modules += 1;
+      }
+      let Some((target, hint)) = worklist.pop() else { break };
+      if included_targets.contains_key(&target) {
+        continue;
+      }
+      included_targets.insert(target, Some(hint));
+      match target {
+        Target::Local(module_idx, name) => {
+          let stmts = self.parsed[module_idx].declarations.get(name).cloned().unwrap_or_default();
+          for stmt_idx in stmts {
+            self.include_statement(module_idx, stmt_idx, &mut included_statements, &mut worklist);
+          }
+        }
+        Target::Namespace(module_idx) => {
+          let mut namespace_exports = FxIndexMap::default();
+          self.collect_exports(
+            module_idx,
+            &mut namespace_exports,
+            &mut vec![],
+            &mut FxHashSet::default(),
+          );
+          worklist.extend(namespace_exports.into_iter().map(|(name, target)| (target, name)));
+        }
+        Target::External { .. } => {}
+      }
+    }
+
+    // Deconflict the top-level names. Modules are visited in the order they are parsed, so the
+    // names declared by the entry module are kept as they are. Like in JavaScript chunks, keywords
+    // and global objects are reserved, as well as any other global the declarations refer to, such
+    // as DOM types.
+    let mut names = ConflictlessNames::with_reserved([]);
+    let mut canonical_names: FxHashMap<Target<'a>, String> = FxHashMap::default();
+    let mut sorted_statements = included_statements.iter().copied().collect::<Vec<_>>();
+    sorted_statements.sort_unstable();
+    for &(module_idx, stmt_idx) in &sorted_statements {
+      for global in &self.parsed[module_idx].statements[stmt_idx].globals {
+        names.reserve((*global).into());
+      }
+    }
+    for &(module_idx, stmt_idx) in &sorted_statements {
+      let stmt = &self.parsed[module_idx].statements[stmt_idx];
+      let declared = stmt
+        .names
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(stmt.anonymous_name_at.map(|_| ANONYMOUS_DEFAULT_NAME));
+      for name in declared {
+        canonical_names
+          .entry(Target::Local(module_idx, name))
+          .or_insert_with(|| names.create(name).to_string());
+      }
+    }
+    for (target, hint) in &included_targets {
+      if !matches!(target, Target::Local(..)) {
+        let hint = match target {
+          Target::External { imported: ImportedName::Name(name), .. } if *name != "default" => {
+            *name
+          }
+          _ => hint.unwrap_or(ANONYMOUS_DEFAULT_NAME),
+        };
+        canonical_names.entry(*target).or_insert_with(|| names.create(hint).to_string());
+      }
+    }
+
+    let mut output = String::new();
+    Self::render_external_imports(&included_targets, &canonical_names, &mut output);
+    for source in &external_star_exports {
+      output.push_str(&concat_string!("export * from ", quote(source), ";\n"));
+    }
+    if !output.is_empty() {
+      output.push('\n');
+    }
+    for &(module_idx, stmt_idx) in &sorted_statements {
+      self.render_statement(module_idx, stmt_idx, &canonical_names, &mut output);
+    }
+    for target in included_targets.keys() {
+      if let Target::Namespace(module_idx) = target {
+        self.render_namespace(*module_idx, &canonical_names, &mut output);
+      }
+    }
+
+    let specifiers = exports
+      .iter()
+      .filter_map(|(exported, target)| {
+        let local = canonical_names.get(target)?;
+        Some(if local == exported {
+          local.clone()
+        } else {
+          concat_string!(local, " as ", exported)
+        })
+      })
+      .collect::<Vec<_>>();
+    if !specifiers.is_empty() {
+      output.push_str(&concat_string!("export { ", specifiers.join(", "), " };\n"));
+    }
+    output
+  }
+
+  fn include_statement(
+    &mut self,
+    module_idx: usize,
+    stmt_idx: usize,
+    included_statements: &mut FxHashSet<(usize, usize)>,
+    worklist: &mut Vec<(Target<'a>, &'a str)>,
+  ) {
+    if !included_statements.insert((module_idx, stmt_idx)) {
+      return;
+    }
+    let references = self.parsed[module_idx].statements[stmt_idx].references.clone();
+    for (name, _) in references {
+      if let Some(target) = self.resolve_local(module_idx, name, &mut FxHashSet::default()) {
+        worklist.push((target, name));
+      }
+    }
+  }
+
+  fn render_external_imports(
+    included_targets: &FxIndexMap<Target<'a>, Option<&'a str>>,
+    canonical_names: &FxHashMap<Target<'a>, String>,
+    output: &mut String,
+  ) {
+    let mut named_imports: FxIndexMap<&str, Vec<String>> = FxIndexMap::default();
+    for target in included_targets.keys() {
+      let Target::External { source, imported } = target else { continue };
+      let local = &canonical_names[target];
+      match imported {
+        ImportedName::Namespace => {
+          output.push_str(&concat_string!("import * as ", local, " from ", quote(source), ";\n"));
+        }
+        ImportedName::Name(name) => {
+          let specifier =
+            if name == local { local.clone() } else { concat_string!(name, " as ", local) };
+          named_imports.entry(source).or_default().push(specifier);
+        }
+      }
+    }
+    for (source, specifiers) in named_imports {
+      output.push_str(&concat_string!(
+        "import { ",
+        specifiers.join(", "),
+        " } from ",
+        quote(source),
+        ";\n"
+      ));
+    }
+  }
+
+  fn render_statement(
+    &mut self,
+    module_idx: usize,
+    stmt_idx: usize,
+    canonical_names: &FxHashMap<Target<'a>, String>,
+    output: &mut String,
+  ) {
+    let mut replacements: Vec<(Span, String)> = vec![];
+    let module = &self.parsed[module_idx];
+    let stmt = &module.statements[stmt_idx];
+    for (name, span) in &stmt.names {
+      if let Some(canonical) = canonical_names.get(&Target::Local(module_idx, name)) {
+        if canonical != name {
+          replacements.push((*span, canonical.clone()));
+        }
+      }
+    }
+    if let Some(at) = stmt.anonymous_name_at {
+      let canonical = &canonical_names[&Target::Local(module_idx, ANONYMOUS_DEFAULT_NAME)];
+      replacements.push((Span::empty(at), concat_string!(" ", canonical)));
+    }
+    let (span, needs_declare) = (stmt.span, stmt.needs_declare);
+    let source = module.source;
+    let references = stmt.references.clone();
+    for (name, ref_span) in references {
+      let Some(target) = self.resolve_local(module_idx, name, &mut FxHashSet::default()) else {
+        continue;
+      };
+      if let Some(canonical) = canonical_names.get(&target) {
+        if canonical != name {
+          replacements.push((ref_span, canonical.clone()));
+        }
+      }
+    }
+    replacements.sort_unstable_by_key(|(span, _)| span.start);
+
+    if needs_declare {
+      output.push_str("declare ");
+    }
+    let mut last = span.start as usize;
+    for (replaced, replacement) in replacements {
+      output.push_str(&source[last..replaced.start as usize]);
+      output.push_str(&replacement);
+      last = replaced.end as usize;
+    }
+    output.push_str(&source[last..span.end as usize]);
+    if !output.ends_with([';', '}']) {
+      output.push(';');
+    }
+    output.push('\n');
+  }
+
+  fn render_namespace(
+    &mut self,
+    module_idx: usize,
+    canonical_names: &FxHashMap<Target<'a>, String>,
+    output: &mut String,
+  ) {
+    let mut exports = FxIndexMap::default();
+    self.collect_exports(module_idx, &mut exports, &mut vec![], &mut FxHashSet::default());
+    let name = &canonical_names[&Target::Namespace(module_idx)];
+    output.push_str(&concat_string!("declare namespace ", name, " {\n"));
+    for (exported, target) in exports {
+      if let Some(local) = canonical_names.get(&target) {
+        output.push_str(&concat_string!("  export import ", exported, " = ", local, ";\n"));
+      }
+    }
+    output.push_str("}\n");
+  }
+}
+
+fn quote(source: &str) -> String {
+  concat_string!("\"", source.replace('"', "\\\""), "\"")
+}
+
+/// Returns the file name of the bundled declaration of a chunk, e.g. `index.d.mts` for `index.mjs`.
+pub fn dts_filename(chunk_filename: &str) -> String {
+  let (stem, extension) = [(".mjs", ".d.mts"), (".cjs", ".d.cts"), (".js", ".d.ts")]
+    .iter()
+    .find_map(|(ext, dts_ext)| chunk_filename.strip_suffix(ext).map(|stem| (stem, *dts_ext)))
+    .unwrap_or((chunk_filename, ".d.ts"));
+  concat_string!(stem, extension)
+}
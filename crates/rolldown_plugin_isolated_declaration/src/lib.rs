@@ -1,24 +1,40 @@
 use std::{borrow::Cow, path::Path};
 
 use arcstr::ArcStr;
+use dts_bundle::{DtsModule, bundle_dts, dts_filename};
 use oxc::{
   allocator::IntoIn,
+  ast::ast::Statement,
   ast_visit::VisitMut,
   codegen::Codegen,
   isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions},
 };
-use rolldown_common::{ModuleType, ResolvedExternal};
+use rolldown_common::{ModuleType, Output, ResolvedExternal};
 use rolldown_error::{BatchedBuildDiagnostic, BuildDiagnostic, Severity};
 use rolldown_plugin::{HookUsage, Plugin, PluginHookMeta, PluginOrder};
-use rolldown_utils::stabilize_id::stabilize_id;
+use rolldown_utils::{dashmap::FxDashMap, stabilize_id::stabilize_id};
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 use type_import_visitor::TypeImportVisitor;
 
+mod dts_bundle;
 mod type_import_visitor;
 
 #[derive(Debug, Default)]
 pub struct IsolatedDeclarationPlugin {
   pub strip_internal: bool,
+  /// Emits a single rolled-up `.d.ts` file for each entry chunk instead of one per module.
+  pub bundle: bool,
+  /// Kept across builds, since incremental builds don't transform unchanged modules again. The
+  /// declarations of modules removed from the graph are never reached from an entry, so they are
+  /// not bundled.
+  dts_modules: FxDashMap<ArcStr, DtsModule>,
+}
+
+impl IsolatedDeclarationPlugin {
+  pub fn new(strip_internal: bool, bundle: bool) -> Self {
+    Self { strip_internal, bundle, dts_modules: FxDashMap::default() }
+  }
 }
 
 impl Plugin for IsolatedDeclarationPlugin {
@@ -45,24 +61,58 @@ impl Plugin for IsolatedDeclarationPlugin {
         }
       }
 
-      let ret = args.ast.program.with_mut(|fields| {
-        IsolatedDeclarations::new(
-          fields.allocator,
-          IsolatedDeclarationsOptions { strip_internal: self.strip_internal },
-        )
-        .build(fields.program)
-      });
+      // The declarations live in the arena of the module, so they can't be held across the awaits
+      // below.
+      let (code, sources) = {
+        let ret = args.ast.program.with_mut(|fields| {
+          IsolatedDeclarations::new(
+            fields.allocator,
+            IsolatedDeclarationsOptions { strip_internal: self.strip_internal },
+          )
+          .build(fields.program)
+        });
 
-      if !ret.errors.is_empty() {
-        return Err(BatchedBuildDiagnostic::new(BuildDiagnostic::from_oxc_diagnostics(
-          ret.errors,
-          &ArcStr::from(ret.program.source_text),
-          &stabilize_id(args.id, ctx.cwd()),
-          &Severity::Error,
-        )))?;
-      }
+        if !ret.errors.is_empty() {
+          return Err(BatchedBuildDiagnostic::new(BuildDiagnostic::from_oxc_diagnostics(
+            ret.errors,
+            &ArcStr::from(ret.program.source_text),
+            &stabilize_id(args.id, ctx.cwd()),
+            &Severity::Error,
+          )))?;
+        }
 
-      let codegen_ret = Codegen::new().build(&ret.program);
+        let sources = ret
+          .program
+          .body
+          .iter()
+          .filter_map(|stmt| match stmt {
+            Statement::ImportDeclaration(decl) => Some(&decl.source),
+            Statement::ExportNamedDeclaration(decl) => decl.source.as_ref(),
+            Statement::ExportAllDeclaration(decl) => Some(&decl.source),
+            _ => None,
+          })
+          .map(|source| source.value.to_string())
+          .collect::<Vec<_>>();
+        (Codegen::new().build(&ret.program).code, sources)
+      };
+
+      if self.bundle {
+        let mut resolved_imports = FxHashMap::default();
+        for source in sources {
+          if resolved_imports.contains_key(&source) {
+            continue;
+          }
+          let resolved_id = ctx.resolve(&source, Some(args.id), None).await?.ok();
+          let resolved = resolved_id
+            .filter(|resolved_id| matches!(resolved_id.external, ResolvedExternal::Bool(false)))
+            .map(|resolved_id| resolved_id.id);
+          resolved_imports.insert(source, resolved);
+        }
+        self
+          .dts_modules
+          .insert(args.id.into(), DtsModule { source: code.into(), resolved_imports });
+        return Ok(args.ast);
+      }
 
       let mut emit_dts_path = Path::new(args.stable_id).to_path_buf();
       emit_dts_path.set_extension("d.ts");
@@ -72,7 +122,7 @@ impl Plugin for IsolatedDeclarationPlugin {
           original_file_name: None,
           // TODO make sure to the .d.ts file relative to the output entry file
           file_name: Some(emit_dts_path.to_slash_lossy().into()),
-          source: codegen_ret.code.into(),
+          source: code.into(),
         },
         None,
         None,
@@ -86,7 +136,38 @@ impl Plugin for IsolatedDeclarationPlugin {
     Some(PluginHookMeta { order: Some(PluginOrder::Post) })
   }
 
+  async fn generate_bundle(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &mut rolldown_plugin::HookGenerateBundleArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    if !self.bundle {
+      return Ok(());
+    }
+    let dts_modules: FxHashMap<ArcStr, DtsModule> =
+      self.dts_modules.iter().map(|entry| (entry.key().clone(), entry.value().clone())).collect();
+    for output in args.bundle.iter() {
+      let Output::Chunk(chunk) = output else { continue };
+      if !chunk.is_entry {
+        continue;
+      }
+      let Some(facade_module_id) = &chunk.facade_module_id else { continue };
+      let Some(code) = bundle_dts(facade_module_id.as_ref(), &dts_modules) else { continue };
+      ctx.emit_file(
+        rolldown_common::EmittedAsset {
+          name: None,
+          original_file_name: None,
+          file_name: Some(dts_filename(&chunk.filename).into()),
+          source: code.into(),
+        },
+        None,
+        None,
+      )?;
+    }
+    Ok(())
+  }
+
   fn register_hook_usage(&self) -> HookUsage {
-    HookUsage::TransformAst
+    HookUsage::TransformAst | HookUsage::GenerateBundle
   }
 }
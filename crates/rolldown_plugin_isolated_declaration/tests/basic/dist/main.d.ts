@@ -0,0 +1 @@
+export { foo } from "./foo";
@@ -0,0 +1,5 @@
+//#region foo.tsx
+const foo = "foo";
+
+//#endregion
+export { foo };
\ No newline at end of file
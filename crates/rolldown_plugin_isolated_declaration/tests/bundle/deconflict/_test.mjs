@@ -0,0 +1,15 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const dts = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.d.ts'), 'utf-8');
+
+// The type parameter shadows the renamed `T` of `box.ts`.
+assert.match(dts, /type T\$1 = string;/);
+assert.match(dts, /interface Box<T> \{\s*value: T;/);
+assert.match(dts, /interface Labelled \{\s*label: T\$1;/);
+// Declarations named after globals that aren't referenced keep their names.
+assert.match(dts, /interface Map \{/);
+assert.doesNotMatch(dts, /Map\$1/);
+// `Promise` is referenced, so nothing may shadow it.
+assert.match(dts, /count\(\): Promise<T>;/);
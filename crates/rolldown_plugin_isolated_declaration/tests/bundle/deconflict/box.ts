@@ -0,0 +1,9 @@
+type T = string;
+
+export interface Box<T> {
+  value: T;
+}
+
+export interface Labelled {
+  label: T;
+}
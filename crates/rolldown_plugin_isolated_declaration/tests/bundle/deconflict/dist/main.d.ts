@@ -0,0 +1,13 @@
+type T = number;
+interface Map {
+	size: T;
+}
+declare function count(): Promise<T>;
+type T$1 = string;
+interface Box<T> {
+	value: T;
+}
+interface Labelled {
+	label: T$1;
+}
+export { T, Map, Box, Labelled, count };
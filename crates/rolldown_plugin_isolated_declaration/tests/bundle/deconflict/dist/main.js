@@ -0,0 +1,7 @@
+//#region main.ts
+function count() {
+	return Promise.resolve(1);
+}
+
+//#endregion
+export { count };
\ No newline at end of file
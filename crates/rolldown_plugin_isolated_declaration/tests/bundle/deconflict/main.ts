@@ -0,0 +1,11 @@
+export type T = number;
+
+export interface Map {
+  size: T;
+}
+
+export type { Box, Labelled } from './box';
+
+export function count(): Promise<T> {
+  return Promise.resolve(1);
+}
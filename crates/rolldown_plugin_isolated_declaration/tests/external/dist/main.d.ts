@@ -0,0 +1,2 @@
+import type * as fs from "node:fs";
+export type { fs };
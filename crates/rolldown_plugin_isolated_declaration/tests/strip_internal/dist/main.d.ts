@@ -0,0 +1,3 @@
+export declare class A {
+	foo: number;
+}
@@ -0,0 +1,9 @@
+//#region main.ts
+var A = class {
+	foo = 1;
+	/** @internal */
+	bar = 2;
+};
+
+//#endregion
+export { A };
\ No newline at end of file
@@ -0,0 +1 @@
+export type D = string;
@@ -0,0 +1,5 @@
+import type { A } from "./a";
+import { type B } from "./b";
+export { A, B };
+export { type C } from "./c";
+export type { D } from "./d";
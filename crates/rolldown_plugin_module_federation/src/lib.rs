@@ -0,0 +1,318 @@
+mod rewrite;
+
+use std::{borrow::Cow, path::Path};
+
+use arcstr::ArcStr;
+use oxc::span::SourceType;
+use rolldown_common::{EmittedChunk, ModuleFederationOptions, ModuleType, PreserveEntrySignatures};
+use rolldown_plugin::{
+  HookLoadOutput, HookResolveIdOutput, HookTransformOutput, HookUsage, LogWithoutPlugin, Plugin,
+  PluginContext,
+};
+use rolldown_utils::futures::spawn_blocking;
+use rustc_hash::FxHashMap;
+use string_wizard::SourceMapOptions;
+
+const RUNTIME_MODULE_ID: &str = "\0rolldown/module-federation/runtime.js";
+const REMOTE_ENTRY_MODULE_ID: &str = "\0rolldown/module-federation/remote-entry.js";
+
+/// Implements `experimental.moduleFederation`.
+///
+/// - `exposes` emits a remote entry chunk implementing the container protocol: `init(shareScope)`
+///   adopts the share scope of the host, and `get(module)` resolves to a factory of the exposed
+///   module.
+/// - Imports of `remotes` and `shared` packages are rewritten into async lookups through the
+///   federation runtime, which loads remote containers and picks the version of shared packages
+///   to use across all containers of the page.
+#[derive(Debug)]
+pub struct ModuleFederationPlugin {
+  pub options: ModuleFederationOptions,
+}
+
+impl ModuleFederationPlugin {
+  pub fn new(options: ModuleFederationOptions) -> Self {
+    Self { options }
+  }
+
+  async fn generate_runtime(&self, ctx: &PluginContext) -> anyhow::Result<String> {
+    let package_json_path = ctx.cwd().join("package.json");
+    let dependencies = spawn_blocking(move || read_dependency_ranges(&package_json_path)).await?;
+    let mut code = rolldown_utils::concat_string!(
+      "const containerName = ",
+      serde_json::to_string(&self.options.name)?,
+      ";\nconst remotes = ",
+      serde_json::to_string(&self.options.remotes)?,
+      ";\nconst shared = {\n"
+    );
+    for (name, config) in self.options.shared.iter().flatten() {
+      let resolved = match ctx.resolve(name, None, None).await? {
+        Ok(resolved) if !resolved.external.is_external() => resolved,
+        _ => anyhow::bail!(
+          "The shared module {name} of the {} container can't be resolved.",
+          self.options.name
+        ),
+      };
+      let version = match &config.version {
+        Some(version) => version.clone(),
+        None => read_package_version(&resolved).await.ok_or_else(|| {
+          anyhow::anyhow!(
+            "The version of the shared module {name} can't be read from its package.json, set \
+             it with `version`."
+          )
+        })?,
+      };
+      let required_version = match &config.required_version {
+        Some(range) => Some(range.clone()),
+        None => dependencies.get(package_name(name)).and_then(|range| {
+          if is_supported_range(range) {
+            return Some(range.clone());
+          }
+          // Such as `workspace:*`, `npm:other@1` or a git url.
+          ctx.warn(LogWithoutPlugin {
+            message: format!(
+              "The range {range} of {} in package.json isn't a semver range, so any version of \
+               the shared module {name} is accepted. Set `requiredVersion` to check it.",
+              package_name(name)
+            ),
+            ..Default::default()
+          });
+          None
+        }),
+      };
+      code.push_str(&rolldown_utils::concat_string!(
+        "  ",
+        serde_json::to_string(name)?,
+        ": { version: ",
+        serde_json::to_string(&version)?,
+        ", requiredVersion: ",
+        serde_json::to_string(&required_version)?,
+        ", singleton: ",
+        if config.singleton.unwrap_or(false) { "true" } else { "false" },
+        ", get: () => import(",
+        serde_json::to_string(resolved.id.as_str())?,
+        ") },\n"
+      ));
+    }
+    code.push_str("};\n");
+    code.push_str(include_str!("runtime.js"));
+    Ok(code)
+  }
+
+  async fn generate_remote_entry(&self, ctx: &PluginContext) -> anyhow::Result<String> {
+    let mut code = rolldown_utils::concat_string!(
+      "import { initShareScope } from ",
+      serde_json::to_string(RUNTIME_MODULE_ID)?,
+      ";\nconst exposes = {\n"
+    );
+    for (exposed, path) in self.options.exposes.iter().flatten() {
+      let resolved = match ctx.resolve(path, None, None).await? {
+        Ok(resolved) if !resolved.external.is_external() => resolved,
+        _ => anyhow::bail!(
+          "{path} exposed as {exposed} by the {} container can't be resolved.",
+          self.options.name
+        ),
+      };
+      code.push_str(&rolldown_utils::concat_string!(
+        "  ",
+        serde_json::to_string(exposed)?,
+        ": () => import(",
+        serde_json::to_string(resolved.id.as_str())?,
+        "),\n"
+      ));
+    }
+    code.push_str(&rolldown_utils::concat_string!(
+      "};\nconst containerName = ",
+      serde_json::to_string(&self.options.name)?,
+      ";\n",
+      r#"export function init(shareScope) {
+  initShareScope(shareScope);
+}
+export async function get(module) {
+  const load = exposes[module];
+  if (!load) {
+    throw new Error(`Module "${module}" isn't exposed by the "${containerName}" container.`);
+  }
+  const exports = await load();
+  return () => exports;
+}
+"#
+    ));
+    Ok(code)
+  }
+}
+
+impl Plugin for ModuleFederationPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:module-federation")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::ResolveId | HookUsage::Load | HookUsage::Transform
+  }
+
+  async fn build_start(
+    &self,
+    ctx: &PluginContext,
+    _args: &rolldown_plugin::HookBuildStartArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    for (name, config) in self.options.shared.iter().flatten() {
+      if let Some(range) = config.required_version.as_ref().filter(|r| !is_supported_range(r)) {
+        anyhow::bail!(
+          "The required version {range} of the shared module {name} isn't a supported range. \
+           Use a semver range such as `^1.2.0`, `1.x` or `1.2 - 2`."
+        );
+      }
+    }
+    if self.options.exposes.as_ref().is_some_and(|exposes| !exposes.is_empty()) {
+      ctx
+        .emit_chunk(EmittedChunk {
+          file_name: Some(self.options.filename().into()),
+          id: REMOTE_ENTRY_MODULE_ID.to_string(),
+          preserve_entry_signatures: Some(PreserveEntrySignatures::Strict),
+          ..Default::default()
+        })
+        .await?;
+    }
+    Ok(())
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &rolldown_plugin::HookResolveIdArgs<'_>,
+  ) -> rolldown_plugin::HookResolveIdReturn {
+    Ok(
+      matches!(args.specifier, RUNTIME_MODULE_ID | REMOTE_ENTRY_MODULE_ID)
+        .then(|| HookResolveIdOutput::from_id(args.specifier)),
+    )
+  }
+
+  async fn load(
+    &self,
+    ctx: &PluginContext,
+    args: &rolldown_plugin::HookLoadArgs<'_>,
+  ) -> rolldown_plugin::HookLoadReturn {
+    let code = match args.id {
+      RUNTIME_MODULE_ID => self.generate_runtime(ctx).await?,
+      REMOTE_ENTRY_MODULE_ID => self.generate_remote_entry(ctx).await?,
+      _ => return Ok(None),
+    };
+    Ok(Some(HookLoadOutput {
+      code: ArcStr::from(code),
+      module_type: Some(ModuleType::Js),
+      ..Default::default()
+    }))
+  }
+
+  async fn transform(
+    &self,
+    _ctx: rolldown_plugin::SharedTransformPluginContext,
+    args: &rolldown_plugin::HookTransformArgs<'_>,
+  ) -> rolldown_plugin::HookTransformReturn {
+    let source_type = match args.module_type {
+      ModuleType::Js => SourceType::mjs(),
+      ModuleType::Jsx => SourceType::jsx(),
+      ModuleType::Ts => SourceType::ts(),
+      ModuleType::Tsx => SourceType::tsx(),
+      _ => return Ok(None),
+    };
+    let mentions_federated_module = self
+      .options
+      .remotes
+      .iter()
+      .flatten()
+      .map(|(remote, _)| remote)
+      .chain(self.options.shared.iter().flatten().map(|(name, _)| name))
+      .any(|name| args.code.contains(name.as_str()));
+    if args.id.starts_with('\0') || !mentions_federated_module {
+      return Ok(None);
+    }
+
+    let Some(s) = rewrite::rewrite_federated_imports(args.code, source_type, &self.options)? else {
+      return Ok(None);
+    };
+    Ok(Some(HookTransformOutput {
+      code: Some(s.to_string()),
+      map: Some(s.source_map(SourceMapOptions {
+        hires: string_wizard::Hires::True,
+        include_content: false,
+        source: args.id.into(),
+      })),
+      ..Default::default()
+    }))
+  }
+}
+
+/// Version ranges of the dependencies declared in the `package.json` at `package_json_path`.
+fn read_dependency_ranges(package_json_path: &Path) -> anyhow::Result<FxHashMap<String, String>> {
+  let Ok(content) = std::fs::read_to_string(package_json_path) else {
+    return Ok(FxHashMap::default());
+  };
+  let package_json: serde_json::Value = serde_json::from_str(&content)?;
+  // Later fields overwrite earlier ones, so `dependencies` takes precedence.
+  Ok(
+    ["devDependencies", "peerDependencies", "dependencies"]
+      .into_iter()
+      .filter_map(|field| package_json.get(field)?.as_object())
+      .flatten()
+      .filter_map(|(name, range)| Some((name.clone(), range.as_str()?.to_string())))
+      .collect(),
+  )
+}
+
+async fn read_package_version(resolved: &rolldown_common::ResolvedId) -> Option<String> {
+  let path = resolved.package_json.as_ref()?.realpath().to_path_buf();
+  let content = spawn_blocking(move || std::fs::read_to_string(path)).await.ok()?;
+  let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+  value.get("version")?.as_str().map(ToString::to_string)
+}
+
+/// Whether `range` is understood by `satisfies` in `runtime.js`: `*`, x-ranges, exact versions, `^`,
+/// `~`, comparisons, hyphen ranges and their combinations with spaces and `||`.
+fn is_supported_range(range: &str) -> bool {
+  range.split("||").all(|set| {
+    let tokens = set.split_whitespace().collect::<Vec<_>>();
+    if let [from, "-", to] = tokens.as_slice() {
+      return is_partial_version(from) && is_partial_version(to);
+    }
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+      let operator = ["^", "~", ">=", "<=", ">", "<", "="]
+        .into_iter()
+        .find(|operator| token.starts_with(operator))
+        .unwrap_or_default();
+      // `>= 1.2.0` is the same as `>=1.2.0`.
+      let version = if !operator.is_empty() && token.len() == operator.len() {
+        tokens.next()
+      } else {
+        Some(&token[operator.len()..])
+      };
+      if !version.is_some_and(is_partial_version) {
+        return false;
+      }
+    }
+    true
+  })
+}
+
+/// `1`, `1.2.x`, `*` or `v1.2.3-beta.1`
+fn is_partial_version(version: &str) -> bool {
+  let version = version.trim_start_matches(['v', '=']);
+  let (version, suffix) = version.find(['-', '+']).map_or((version, ""), |i| version.split_at(i));
+  let mut parts = version.split('.');
+  parts.clone().count() <= 3
+    && parts.all(|part| {
+      matches!(part, "x" | "X" | "*")
+        || (!part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+    })
+    && suffix.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'+' | b'.'))
+}
+
+/// `react/jsx-runtime` -> `react`, `@scope/pkg/sub` -> `@scope/pkg`
+fn package_name(specifier: &str) -> &str {
+  let segments = if specifier.starts_with('@') { 2 } else { 1 };
+  match specifier.match_indices('/').nth(segments - 1) {
+    Some((index, _)) => &specifier[..index],
+    None => specifier,
+  }
+}
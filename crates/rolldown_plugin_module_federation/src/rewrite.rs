@@ -0,0 +1,254 @@
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{
+    Expression, ImportDeclarationSpecifier, ImportExpression, ModuleExportName, Statement,
+  },
+  ast_visit::{Visit, walk},
+  parser::Parser,
+  span::{GetSpan as _, SourceType, Span},
+};
+use rolldown_common::ModuleFederationOptions;
+use string_wizard::MagicString;
+
+use crate::RUNTIME_MODULE_ID;
+
+const LOAD_REMOTE: &str = "__rolldown_mf_load_remote";
+const LOAD_SHARED: &str = "__rolldown_mf_load_shared";
+
+/// Rewrites imports of remote and shared modules into lookups through the federation runtime:
+///
+/// ```js
+/// import Button, { size } from 'app2/Button';
+/// // becomes
+/// const { "default": Button, "size": size } = await __rolldown_mf_load_remote("app2", "./Button");
+/// ```
+///
+/// Like the imports they replace, the lookups are hoisted to the top of the module in source
+/// order, so the bindings are initialized before any statement of the module runs.
+///
+/// Returns `None` if there is nothing to rewrite.
+pub fn rewrite_federated_imports<'code>(
+  code: &'code str,
+  source_type: SourceType,
+  options: &ModuleFederationOptions,
+) -> anyhow::Result<Option<MagicString<'code>>> {
+  let allocator = Allocator::default();
+  let ret = Parser::new(&allocator, code, source_type).parse();
+  if ret.panicked {
+    // Leave syntax errors to be reported by the bundler.
+    return Ok(None);
+  }
+
+  let mut s = MagicString::new(code);
+  let mut changed = false;
+  let mut hoisted = Vec::new();
+  let mut local_count = 0usize;
+  let mut fresh_local = || {
+    let local =
+      rolldown_utils::concat_string!("__rolldown_mf_", itoa::Buffer::new().format(local_count));
+    local_count += 1;
+    local
+  };
+
+  for stmt in &ret.program.body {
+    match stmt {
+      Statement::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+        let Some(loader) = loader_expr(decl.source.value.as_str(), options) else {
+          continue;
+        };
+        let specifiers = decl.specifiers.as_ref().map(|specifiers| {
+          specifiers.iter().filter(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+              !specifier.import_kind.is_type()
+            }
+            _ => true,
+          })
+        });
+        let mut namespace = None;
+        let mut properties = Vec::new();
+        for specifier in specifiers.into_iter().flatten() {
+          match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+              properties.push((property_key(&specifier.imported), specifier.local.name.as_str()));
+            }
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+              properties.push(("\"default\"".to_string(), specifier.local.name.as_str()));
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+              namespace = Some(specifier.local.name.as_str());
+            }
+          }
+        }
+        let declaration = match (namespace, properties.is_empty()) {
+          (None, true) => rolldown_utils::concat_string!("await ", loader, ";"),
+          (None, false) => {
+            destructure(&properties, &rolldown_utils::concat_string!("await ", loader))
+          }
+          (Some(namespace), is_empty) => {
+            let declaration =
+              rolldown_utils::concat_string!("const ", namespace, " = await ", loader, ";");
+            if is_empty {
+              declaration
+            } else {
+              rolldown_utils::concat_string!(declaration, " ", destructure(&properties, namespace))
+            }
+          }
+        };
+        hoisted.push(declaration);
+        s.remove(decl.span.start as usize, decl.span.end as usize);
+        changed = true;
+      }
+      Statement::ExportNamedDeclaration(decl) if !decl.export_kind.is_type() => {
+        let Some(loader) =
+          decl.source.as_ref().and_then(|source| loader_expr(source.value.as_str(), options))
+        else {
+          continue;
+        };
+        let mut properties = Vec::new();
+        let mut exports = Vec::new();
+        for specifier in decl.specifiers.iter().filter(|specifier| !specifier.export_kind.is_type())
+        {
+          let local = fresh_local();
+          properties.push((property_key(&specifier.local), local.clone()));
+          exports.push(rolldown_utils::concat_string!(
+            local,
+            " as ",
+            export_name(&specifier.exported)
+          ));
+        }
+        let properties =
+          properties.iter().map(|(key, local)| (key.clone(), local.as_str())).collect::<Vec<_>>();
+        hoisted.push(destructure(&properties, &rolldown_utils::concat_string!("await ", loader)));
+        s.update(
+          decl.span.start as usize,
+          decl.span.end as usize,
+          rolldown_utils::concat_string!("export { ", exports.join(", "), " };"),
+        );
+        changed = true;
+      }
+      Statement::ExportAllDeclaration(decl) if !decl.export_kind.is_type() => {
+        let Some(loader) = loader_expr(decl.source.value.as_str(), options) else {
+          continue;
+        };
+        let Some(exported) = &decl.exported else {
+          anyhow::bail!(
+            "`export * from '{}'` isn't supported, the exports of federated modules are only \
+             known at runtime. Re-export them by name instead.",
+            decl.source.value
+          );
+        };
+        let local = fresh_local();
+        hoisted.push(rolldown_utils::concat_string!("const ", local, " = await ", loader, ";"));
+        s.update(
+          decl.span.start as usize,
+          decl.span.end as usize,
+          rolldown_utils::concat_string!("export { ", local, " as ", export_name(exported), " };"),
+        );
+        changed = true;
+      }
+      _ => {}
+    }
+  }
+
+  let mut collector = DynamicImportCollector { options, spans: Vec::new() };
+  collector.visit_program(&ret.program);
+  for (span, loader) in collector.spans {
+    s.update(span.start as usize, span.end as usize, loader);
+    changed = true;
+  }
+
+  if !changed {
+    return Ok(None);
+  }
+
+  let mut prologue = rolldown_utils::concat_string!(
+    "import { loadRemote as ",
+    LOAD_REMOTE,
+    ", loadShared as ",
+    LOAD_SHARED,
+    " } from ",
+    serde_json::to_string(RUNTIME_MODULE_ID)?,
+    ";\n"
+  );
+  for declaration in hoisted {
+    prologue.push_str(&declaration);
+    prologue.push('\n');
+  }
+  match &ret.program.hashbang {
+    Some(hashbang) => {
+      s.append_left(hashbang.span.end as usize, rolldown_utils::concat_string!("\n", prologue))
+    }
+    None => s.prepend(prologue),
+  };
+  Ok(Some(s))
+}
+
+/// `import('app2/Button')` becomes `__rolldown_mf_load_remote("app2", "./Button")`, which resolves
+/// to the namespace of the remote module as well.
+struct DynamicImportCollector<'options> {
+  options: &'options ModuleFederationOptions,
+  spans: Vec<(Span, String)>,
+}
+
+impl<'a> Visit<'a> for DynamicImportCollector<'_> {
+  fn visit_import_expression(&mut self, it: &ImportExpression<'a>) {
+    if let Expression::StringLiteral(source) = &it.source
+      && let Some(loader) = loader_expr(source.value.as_str(), self.options)
+    {
+      self.spans.push((it.span(), loader));
+    }
+    walk::walk_import_expression(self, it);
+  }
+}
+
+/// Returns the runtime call that loads `specifier`, if it's a remote or shared module.
+fn loader_expr(specifier: &str, options: &ModuleFederationOptions) -> Option<String> {
+  let remote = options.remotes.iter().flatten().find_map(|(remote, _)| {
+    let rest = specifier.strip_prefix(remote.as_str())?;
+    if rest.is_empty() {
+      Some((remote, ".".to_string()))
+    } else {
+      rest.starts_with('/').then(|| (remote, rolldown_utils::concat_string!(".", rest)))
+    }
+  });
+  if let Some((remote, exposed)) = remote {
+    return Some(rolldown_utils::concat_string!(
+      LOAD_REMOTE,
+      "(",
+      json_string(remote),
+      ", ",
+      json_string(&exposed),
+      ")"
+    ));
+  }
+  options
+    .shared
+    .iter()
+    .flatten()
+    .any(|(name, _)| name == specifier)
+    .then(|| rolldown_utils::concat_string!(LOAD_SHARED, "(", json_string(specifier), ")"))
+}
+
+fn destructure(properties: &[(String, &str)], init: &str) -> String {
+  let properties = properties
+    .iter()
+    .map(|(key, local)| rolldown_utils::concat_string!(key, ": ", local))
+    .collect::<Vec<_>>()
+    .join(", ");
+  rolldown_utils::concat_string!("const { ", properties, " } = ", init, ";")
+}
+
+fn property_key(name: &ModuleExportName) -> String {
+  json_string(name.name().as_str())
+}
+
+fn export_name(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::StringLiteral(_) => property_key(name),
+    _ => name.name().to_string(),
+  }
+}
+
+fn json_string(value: &str) -> String {
+  serde_json::to_string(value).expect("strings are always serializable")
+}
@@ -0,0 +1,157 @@
+// `containerName`, `remotes` and `shared` are generated by the plugin before this code.
+
+// Maps the name of every shared package to the versions provided by the containers of the page.
+// It starts with the versions of this build, and is replaced by the share scope of the host once
+// this build is initialized as a remote.
+let shareScope = {};
+for (const [name, { version, get }] of Object.entries(shared)) {
+  shareScope[name] = { [version]: { from: containerName, get, loaded: undefined } };
+}
+
+export function initShareScope(scope) {
+  if (scope === shareScope) {
+    return;
+  }
+  for (const [name, versions] of Object.entries(shareScope)) {
+    const target = (scope[name] ??= {});
+    for (const [version, entry] of Object.entries(versions)) {
+      target[version] ??= entry;
+    }
+  }
+  shareScope = scope;
+}
+
+export function loadShared(name) {
+  const { version, requiredVersion, singleton } = shared[name];
+  const versions = shareScope[name];
+  const candidates = Object.keys(versions).sort((a, b) => compareVersions(b, a));
+  let selected;
+  if (singleton) {
+    // Once a singleton is loaded, every container has to use it.
+    selected = candidates.find((candidate) => versions[candidate].loaded) ?? candidates[0];
+    if (requiredVersion && !satisfies(selected, requiredVersion)) {
+      console.warn(
+        `[module federation] The singleton ${name}@${selected} provided by "${
+          versions[selected].from
+        }" doesn't satisfy the version ${requiredVersion} required by "${containerName}".`,
+      );
+    }
+  } else {
+    selected = candidates.find((candidate) =>
+      !requiredVersion || satisfies(candidate, requiredVersion)
+    ) ?? version;
+  }
+  const entry = versions[selected];
+  return (entry.loaded ??= entry.get());
+}
+
+const containers = {};
+
+export function loadRemote(remote, exposed) {
+  containers[remote] ??= import(/* @vite-ignore */ remotes[remote]).then(
+    async (container) => {
+      await container.init(shareScope);
+      return container;
+    },
+  );
+  return containers[remote]
+    .then((container) => container.get(exposed))
+    .then((factory) => factory());
+}
+
+function parseVersion(version) {
+  return version
+    .replace(/^[v=\s]+/, '')
+    .split(/[-+]/)[0]
+    .split('.')
+    .map((part) => Number.parseInt(part, 10) || 0);
+}
+
+function compareVersions(a, b) {
+  const left = parseVersion(a);
+  const right = parseVersion(b);
+  for (let i = 0; i < 3; i++) {
+    const diff = (left[i] ?? 0) - (right[i] ?? 0);
+    if (diff !== 0) {
+      return diff;
+    }
+  }
+  return 0;
+}
+
+// Supports the ranges of node-semver without prereleases: `*`, x-ranges such as `1.x` or `1.2`,
+// exact versions, `^`, `~`, comparisons, hyphen ranges such as `1.2 - 2` and their combinations
+// with spaces and `||`. The plugin rejects other ranges at build time.
+function satisfies(version, range) {
+  return range.split('||').some((set) =>
+    toComparators(set).every(([operator, base]) => {
+      const diff = compareVersions(version, base);
+      switch (operator) {
+        case '>=':
+          return diff >= 0;
+        case '<=':
+          return diff <= 0;
+        case '>':
+          return diff > 0;
+        case '<':
+          return diff < 0;
+        default:
+          return diff === 0;
+      }
+    })
+  );
+}
+
+// Turns a set of comparators into `[operator, version]` pairs whose versions are complete.
+function toComparators(set) {
+  const hyphen = /^\s*(\S+)\s+-\s+(\S+)\s*$/.exec(set);
+  if (hyphen) {
+    return [...desugar(`>=${hyphen[1]}`), ...desugar(`<=${hyphen[2]}`)];
+  }
+  return set
+    .trim()
+    .replace(/(\^|~|>=|<=|>|<|=)\s+/g, '$1')
+    .split(/\s+/)
+    .flatMap(desugar);
+}
+
+function desugar(comparator) {
+  const [, operator = '=', partial] = /^(\^|~|>=|<=|>|<|=)?(.*)$/.exec(comparator);
+  // `1.x` -> [1, null, null]
+  const parts = partial
+    .replace(/^[v=]+/, '')
+    .split(/[-+]/)[0]
+    .split('.')
+    .slice(0, 3);
+  const specified = parts.findIndex((part) => !/^\d+$/.test(part));
+  const count = specified === -1 ? parts.length : specified;
+  const [major = 0, minor = 0, patch = 0] = parts.slice(0, count).map(Number);
+  const lower = `${major}.${minor}.${patch}`;
+  // The first version above the ones matched by the partial version, `1.2` -> `1.3.0`.
+  const above = count === 1 ? `${major + 1}.0.0` : `${major}.${minor + 1}.0`;
+  if (count === 0) {
+    return operator === '<' || operator === '>' ? [['<', '0.0.0']] : [];
+  }
+  switch (operator) {
+    case '^': {
+      const upper = major > 0 || count === 1
+        ? `${major + 1}.0.0`
+        : minor > 0 || count === 2
+        ? `0.${minor + 1}.0`
+        : `0.0.${patch + 1}`;
+      return [['>=', lower], ['<', upper]];
+    }
+    case '~':
+      return [['>=', lower], ['<', count === 1 ? above : `${major}.${minor + 1}.0`]];
+    case '>=':
+      return [['>=', lower]];
+    case '<':
+      return [['<', lower]];
+    case '>':
+      return count === 3 ? [['>', lower]] : [['>=', above]];
+    case '<=':
+      return count === 3 ? [['<=', lower]] : [['<', above]];
+    default:
+      return count === 3 ? [['=', lower]] : [['>=', lower], ['<', above]];
+  }
+}
@@ -0,0 +1,282 @@
+mod lockfile;
+
+use std::{
+  borrow::Cow,
+  collections::BTreeMap,
+  fmt::Write as _,
+  path::{Path, PathBuf},
+  sync::{
+    Mutex,
+    atomic::{AtomicBool, Ordering},
+  },
+};
+
+use anyhow::Context as _;
+use rolldown_common::{ModuleType, RemoteFetcher, RemoteImportsOptions};
+use rolldown_fs::{FileSystem as _, OsFileSystem, OxcResolverFileSystem as _};
+use rolldown_plugin::{HookLoadOutput, HookResolveIdOutput, HookUsage, Plugin};
+use rolldown_utils::futures::spawn_blocking;
+use url::Url;
+
+#[derive(Debug)]
+pub enum Fetcher {
+  /// Reads `https://host/path` from `<dir>/host/path`, see [`fetch_dir_path`].
+  Directory(PathBuf),
+  Custom(RemoteFetcher),
+}
+
+/// Bundles `http(s)://` imports, see `experimental.remoteImports`.
+///
+/// Fetched contents are stored in a cache directory under their hash, and the hash of every url
+/// is recorded in a lockfile. Once a url is locked, its content must keep matching the recorded
+/// hash, whether it comes from the cache or is fetched again.
+#[derive(Debug)]
+pub struct RemoteImportsPlugin {
+  pub cache_dir: PathBuf,
+  pub lockfile: PathBuf,
+  pub offline: bool,
+  pub fetcher: Option<Fetcher>,
+  pub fs: OsFileSystem,
+  /// `url -> integrity` entries of the lockfile.
+  pub locked: Mutex<BTreeMap<String, String>>,
+  pub lockfile_changed: AtomicBool,
+}
+
+impl RemoteImportsPlugin {
+  pub fn new(cwd: &Path, options: &RemoteImportsOptions) -> Self {
+    let fetcher = match (&options.fetch_dir, &options.fetcher) {
+      (Some(dir), _) => Some(Fetcher::Directory(cwd.join(dir))),
+      (None, Some(fetcher)) => Some(Fetcher::Custom(fetcher.clone())),
+      (None, None) => None,
+    };
+    Self {
+      cache_dir: cwd.join(options.cache_dir()),
+      lockfile: cwd.join(options.lockfile()),
+      offline: options.is_offline(),
+      fetcher,
+      fs: OsFileSystem::new(false),
+      locked: Mutex::default(),
+      lockfile_changed: AtomicBool::new(false),
+    }
+  }
+
+  async fn load_remote(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+    let locked = self.locked.lock().expect("poisoned").get(url).cloned();
+
+    if let Some(integrity) = &locked {
+      let cache_file = self.cache_file(integrity)?;
+      let fs = self.fs.clone();
+      let path = cache_file.clone();
+      match spawn_blocking(move || fs.read(&path)).await {
+        Ok(content) => {
+          if integrity_of(&content) != *integrity {
+            anyhow::bail!(
+              "The cached content of {url} doesn't match the integrity {integrity} recorded in {}. \
+               Delete {} to fetch it again.",
+              self.lockfile.display(),
+              cache_file.display()
+            );
+          }
+          return Ok(content);
+        }
+        Err(_) if self.offline => anyhow::bail!(
+          "{url} is missing from the cache {} and can't be fetched in offline mode.",
+          self.cache_dir.display()
+        ),
+        Err(_) => {}
+      }
+    } else if self.offline {
+      anyhow::bail!(
+        "{url} isn't recorded in the lockfile {} and can't be fetched in offline mode. \
+         Run a build without `offline` to fetch it.",
+        self.lockfile.display()
+      );
+    }
+
+    let content = self.fetch(url).await?;
+    let integrity = integrity_of(&content);
+    if let Some(locked) = &locked
+      && *locked != integrity
+    {
+      anyhow::bail!(
+        "The content of {url} doesn't match the integrity recorded in {}: \
+         expected {locked}, got {integrity}. \
+         Remove its entry from the lockfile if the change is expected.",
+        self.lockfile.display()
+      );
+    }
+
+    let cache_file = self.cache_file(&integrity)?;
+    let fs = self.fs.clone();
+    let path = cache_file.clone();
+    // Written atomically, since another build may read the same cache at the same time.
+    let content = spawn_blocking(move || fs.write_atomically(&path, &content).map(|()| content))
+      .await
+      .with_context(|| format!("Failed to write {}", cache_file.display()))?;
+
+    if locked.is_none() {
+      self.locked.lock().expect("poisoned").insert(url.to_string(), integrity);
+      self.lockfile_changed.store(true, Ordering::Relaxed);
+    }
+    Ok(content)
+  }
+
+  async fn fetch(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+    match &self.fetcher {
+      Some(Fetcher::Directory(dir)) => {
+        let path = fetch_dir_path(dir, &Url::parse(url)?);
+        let fs = self.fs.clone();
+        let read_path = path.clone();
+        spawn_blocking(move || fs.read(&read_path))
+          .await
+          .map_err(|err| anyhow::anyhow!("Failed to fetch {url} from {}: {err}", path.display()))
+      }
+      Some(Fetcher::Custom(fetcher)) => fetcher
+        .call(url.to_string())
+        .await
+        .map_err(|err| anyhow::anyhow!("Failed to fetch {url}: {err}")),
+      None => anyhow::bail!(
+        "{url} isn't cached and there is no fetcher, \
+         set `experimental.remoteImports.fetchDir` or `experimental.remoteImports.fetch`."
+      ),
+    }
+  }
+
+  fn cache_file(&self, integrity: &str) -> anyhow::Result<PathBuf> {
+    match integrity.strip_prefix("blake3-") {
+      Some(hash) if hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()) => {
+        Ok(self.cache_dir.join(hash))
+      }
+      _ => anyhow::bail!(
+        "Invalid integrity {integrity} in {}, expected `blake3-<hex>`.",
+        self.lockfile.display()
+      ),
+    }
+  }
+}
+
+impl Plugin for RemoteImportsPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:remote-imports")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::ResolveId | HookUsage::Load | HookUsage::BuildEnd
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    _args: &rolldown_plugin::HookBuildStartArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    let fs = self.fs.clone();
+    let path = self.lockfile.clone();
+    let locked = spawn_blocking(move || lockfile::read_lockfile(&fs, &path)).await?;
+    *self.locked.lock().expect("poisoned") = locked;
+    self.lockfile_changed.store(false, Ordering::Relaxed);
+    Ok(())
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveIdArgs<'_>,
+  ) -> rolldown_plugin::HookResolveIdReturn {
+    if is_remote_url(args.specifier) {
+      return Ok(Some(HookResolveIdOutput::from_id(args.specifier)));
+    }
+    // Remote modules import their dependencies relative to their own url, e.g. `/react@19/x.js`.
+    if let Some(importer) = args.importer
+      && is_remote_url(importer)
+      && (args.specifier.starts_with('.') || args.specifier.starts_with('/'))
+    {
+      let url = Url::parse(importer)?.join(args.specifier)?;
+      return Ok(Some(HookResolveIdOutput::from_id(url.as_str())));
+    }
+    Ok(None)
+  }
+
+  async fn load(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookLoadArgs<'_>,
+  ) -> rolldown_plugin::HookLoadReturn {
+    if !is_remote_url(args.id) {
+      return Ok(None);
+    }
+    let content = self.load_remote(args.id).await?;
+    let code = String::from_utf8(content)
+      .map_err(|_| anyhow::anyhow!("The content of {} isn't valid UTF-8.", args.id))?;
+    Ok(Some(HookLoadOutput {
+      code: code.into(),
+      module_type: Some(module_type_of(args.id)),
+      ..Default::default()
+    }))
+  }
+
+  async fn build_end(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    _args: Option<&rolldown_plugin::HookBuildEndArgs<'_>>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    if self.lockfile_changed.swap(false, Ordering::Relaxed) {
+      let locked = self.locked.lock().expect("poisoned").clone();
+      let fs = self.fs.clone();
+      let path = self.lockfile.clone();
+      spawn_blocking(move || lockfile::write_lockfile(&fs, &path, &locked)).await?;
+    }
+    Ok(())
+  }
+}
+
+fn is_remote_url(specifier: &str) -> bool {
+  specifier.starts_with("https://") || specifier.starts_with("http://")
+}
+
+/// The file a url is read from by the `fetchDir` fetcher. The port is kept in the name of the host
+/// directory and the query in the file name, so that urls differing by either don't share a file:
+/// `https://host:8080/dir/mod.js?v=1` -> `<dir>/host_8080/dir/mod.js%3Fv=1`.
+fn fetch_dir_path(dir: &Path, url: &Url) -> PathBuf {
+  let host = url.host_str().unwrap_or_default();
+  let mut path = match url.port() {
+    Some(port) => dir.join(format!("{host}_{port}")),
+    None => dir.join(host),
+  };
+  path.extend(url.path_segments().into_iter().flatten().filter(|s| !s.is_empty()));
+  if let Some(query) = url.query() {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    let mut escaped = String::from("%3F");
+    // Escape the characters that can't be used in file names on every platform.
+    for c in query.chars() {
+      match c {
+        '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => {
+          let _ = write!(escaped, "%{:02X}", u32::from(c));
+        }
+        _ => escaped.push(c),
+      }
+    }
+    file_name.push(escaped);
+    path.set_file_name(file_name);
+  }
+  path
+}
+
+fn integrity_of(content: &[u8]) -> String {
+  rolldown_utils::concat_string!("blake3-", blake3::hash(content).to_hex().as_str())
+}
+
+/// CDNs often serve modules from urls without an extension, which are treated as JavaScript.
+fn module_type_of(url: &str) -> ModuleType {
+  let extension = Url::parse(url).ok().and_then(|url| {
+    let file_name = url.path_segments()?.next_back()?.to_string();
+    file_name.rsplit_once('.').map(|(_, extension)| extension.to_string())
+  });
+  match extension.as_deref() {
+    Some("jsx") => ModuleType::Jsx,
+    Some("ts" | "mts" | "cts") => ModuleType::Ts,
+    Some("tsx") => ModuleType::Tsx,
+    Some("json") => ModuleType::Json,
+    Some("css") => ModuleType::Css,
+    _ => ModuleType::Js,
+  }
+}
@@ -0,0 +1,54 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Context as _;
+use rolldown_fs::FileSystem;
+
+const LOCKFILE_VERSION: u64 = 1;
+
+/// Reads the `url -> integrity` entries of the lockfile. A missing lockfile has no entries.
+pub fn read_lockfile(
+  fs: &impl FileSystem,
+  path: &Path,
+) -> anyhow::Result<BTreeMap<String, String>> {
+  let content = match fs.read_to_string(path) {
+    Ok(content) => content,
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+    Err(err) => return Err(err).with_context(|| format!("Failed to read {}", path.display())),
+  };
+  let value: serde_json::Value = serde_json::from_str(&content)
+    .with_context(|| format!("Failed to parse the lockfile {}", path.display()))?;
+  match value.get("version").and_then(serde_json::Value::as_u64) {
+    Some(LOCKFILE_VERSION) => {}
+    Some(version) => anyhow::bail!(
+      "Unsupported lockfile {}: version {version} isn't supported, expected version \
+       {LOCKFILE_VERSION}. It may have been written by a newer version of Rolldown.",
+      path.display()
+    ),
+    None => anyhow::bail!("Invalid lockfile {}: expected a numeric `version`", path.display()),
+  }
+  let Some(remote) = value.get("remote").and_then(serde_json::Value::as_object) else {
+    anyhow::bail!("Invalid lockfile {}: expected a `remote` object", path.display());
+  };
+  remote
+    .iter()
+    .map(|(url, integrity)| match integrity.as_str() {
+      Some(integrity) => Ok((url.clone(), integrity.to_string())),
+      None => {
+        anyhow::bail!("Invalid lockfile {}: the integrity of {url} isn't a string", path.display())
+      }
+    })
+    .collect()
+}
+
+/// Writes the lockfile with its entries sorted by url, so that it diffs well.
+pub fn write_lockfile(
+  fs: &impl FileSystem,
+  path: &Path,
+  entries: &BTreeMap<String, String>,
+) -> anyhow::Result<()> {
+  let value = serde_json::json!({ "version": LOCKFILE_VERSION, "remote": entries });
+  let mut content = serde_json::to_string_pretty(&value)?;
+  content.push('\n');
+  fs.write_atomically(path, content.as_bytes())
+    .with_context(|| format!("Failed to write {}", path.display()))
+}
@@ -0,0 +1,7 @@
+//#region input.js
+process.env.DEBUG = "test";
+if (replaced === "production") console.log("");
+if (world == "world") console.log(world);
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
@@ -0,0 +1,9 @@
+//#region input.js
+console.log(`
+  <%replaced%>
+  <% original%>
+  <%original %>
+`);
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
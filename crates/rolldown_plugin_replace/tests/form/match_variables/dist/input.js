@@ -0,0 +1,5 @@
+//#region input.js
+console.log("beta version 1.0.0");
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
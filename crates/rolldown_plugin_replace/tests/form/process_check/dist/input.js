@@ -0,0 +1,7 @@
+//#region input.js
+if ("object" !== "undefined" && "object" === "object" && "production" === "production") {
+	console.log("production");
+}
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
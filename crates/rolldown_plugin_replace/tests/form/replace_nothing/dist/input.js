@@ -0,0 +1,5 @@
+//#region input.js
+console.log("as-it");
+console.log(typeof window.document);
+
+//#endregion
\ No newline at end of file
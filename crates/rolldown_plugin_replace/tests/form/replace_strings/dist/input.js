@@ -0,0 +1,5 @@
+//#region input.js
+console.log(42);
+console.log("object");
+
+//#endregion
\ No newline at end of file
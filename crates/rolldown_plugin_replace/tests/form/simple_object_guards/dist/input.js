@@ -0,0 +1,4 @@
+//#region input.js
+bar, foo.qux;
+
+//#endregion
\ No newline at end of file
@@ -0,0 +1,5 @@
+//#region input.js
+console.log(1);
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
@@ -0,0 +1,11 @@
+//#region input.js
+console.log(`
+  replaced
+  (replaced)
+  specially
+  especial
+  replaced.doSomething()
+`);
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
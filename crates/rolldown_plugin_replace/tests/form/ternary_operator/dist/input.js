@@ -0,0 +1,6 @@
+//#region input.js
+first ? second : third;
+console.log(first, second, third);
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
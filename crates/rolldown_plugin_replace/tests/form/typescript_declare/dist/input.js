@@ -0,0 +1,5 @@
+//#region input.ts
+console.log(replaced);
+
+//#endregion
+//# sourceMappingURL=input.js.map
\ No newline at end of file
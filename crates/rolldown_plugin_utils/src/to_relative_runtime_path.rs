@@ -16,6 +16,7 @@ pub fn create_to_import_meta_url_based_relative_runtime(
     OutputFormat::Esm => es,
     OutputFormat::Cjs => cjs,
     OutputFormat::Umd => umd,
+    OutputFormat::Amd => amd,
     OutputFormat::Iife => {
       if is_worker {
         worker_iife
@@ -54,6 +55,16 @@ fn umd(path: &str) -> String {
   )
 }
 
+fn amd(path: &str) -> String {
+  // `require.toUrl` only resolves ids relative to the module with a leading `./`.
+  let path =
+    if path.starts_with('.') { Cow::Borrowed(path) } else { Cow::Owned(format!("./{path}")) };
+  format!(
+    "new URL(require.toUrl('{}'), document.baseURI).href",
+    escape_id(&partial_encode_url_path(&path))
+  )
+}
+
 fn worker_iife(path: &str) -> String {
   format!("new URL('{}', self.location.href).href", escape_id(&partial_encode_url_path(path)))
 }
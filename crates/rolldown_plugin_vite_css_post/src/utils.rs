@@ -273,6 +273,9 @@ impl ViteCSSPostPlugin {
           OutputFormat::Cjs => {
             return Err(anyhow::anyhow!("CJS format is not supported for CSS injection"));
           }
+          OutputFormat::Amd => {
+            return Err(anyhow::anyhow!("AMD format is not supported for CSS injection"));
+          }
         };
 
         let content = serde_json::to_string(&self.finalize_css(css_chunk).await?)?;
@@ -2,6 +2,7 @@
 
 mod resolver;
 mod resolver_config;
+mod trace;
 
 pub mod error;
 
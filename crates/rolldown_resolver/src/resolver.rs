@@ -7,17 +7,18 @@ use anyhow::Context;
 use arcstr::ArcStr;
 use dashmap::DashMap;
 use oxc_resolver::{
-  ModuleType, PackageJson as OxcPackageJson, Resolution, ResolveError, ResolverGeneric,
-  TsConfig as OxcTsConfig,
+  ModuleType, PackageJson as OxcPackageJson, Resolution, ResolveContext, ResolveError,
+  ResolverGeneric, TsConfig as OxcTsConfig, TsconfigDiscovery,
 };
 use rolldown_common::{
-  ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions, ResolvedId, TsConfig,
+  ImportKind, ModuleDefFormat, PackageJson, Platform, ResolveOptions, ResolveTrace,
+  ResolveTraceStep, ResolvedId, TsConfig,
 };
 use rolldown_fs::{FileSystem, OsFileSystem};
-use rolldown_utils::dashmap::FxDashMap;
+use rolldown_utils::{concat_string, dashmap::FxDashMap, ecmascript::is_path_like_specifier};
 use sugar_path::SugarPath as _;
 
-use crate::resolver_config::ResolverConfig;
+use crate::{error::resolve_error_to_message, resolver_config::ResolverConfig, trace};
 
 #[derive(Debug)]
 #[expect(clippy::struct_field_names)]
@@ -34,6 +35,7 @@ pub struct Resolver<Fs: FileSystem = OsFileSystem> {
   // Resolver for `new URL(..., import.meta.url)`
   new_url_resolver: ResolverGeneric<Fs>,
   package_json_cache: FxDashMap<PathBuf, Arc<PackageJson>>,
+  trace: bool,
 }
 
 impl<Fs: FileSystem + Clone> Resolver<Fs> {
@@ -63,8 +65,16 @@ impl<Fs: FileSystem + Clone> Resolver<Fs> {
       css_resolver,
       new_url_resolver,
       package_json_cache: DashMap::default(),
+      trace: false,
     }
   }
+
+  /// Enables collecting a [`ResolveTrace`] for every resolution.
+  #[must_use]
+  pub fn with_trace(mut self, trace: bool) -> Self {
+    self.trace = trace;
+    self
+  }
 }
 
 #[derive(Debug)]
@@ -72,6 +82,7 @@ pub struct ResolveReturn {
   pub path: ArcStr,
   pub module_def_format: ModuleDefFormat,
   pub package_json: Option<Arc<PackageJson>>,
+  pub trace: Option<Arc<ResolveTrace>>,
 }
 
 impl From<ResolveReturn> for ResolvedId {
@@ -80,6 +91,7 @@ impl From<ResolveReturn> for ResolvedId {
       id: resolved_return.path,
       module_def_format: resolved_return.module_def_format,
       package_json: resolved_return.package_json,
+      resolve_trace: resolved_return.trace,
       ..Default::default()
     }
   }
@@ -96,6 +108,37 @@ impl<Fs: FileSystem> Resolver<Fs> {
     self.package_json_cache.clear();
   }
 
+  pub fn is_trace_enabled(&self) -> bool {
+    self.trace
+  }
+
+  /// Runs a failed [`Resolver::resolve`] call again to collect its [`ResolveTrace`].
+  ///
+  /// A failed resolution has nowhere to keep its trace, and most failures never reach a diagnostic
+  /// anyway, e.g. imports that are treated as external. So the trace is only collected on demand.
+  /// Always returns `None` if tracing is not enabled.
+  pub fn trace_failed_resolve(
+    &self,
+    importer: Option<&Path>,
+    specifier: &str,
+    import_kind: ImportKind,
+    is_user_defined_entry: bool,
+  ) -> Option<ResolveTrace> {
+    if !self.trace {
+      return None;
+    }
+    let mut trace = ResolveTrace::default();
+    let resolution =
+      self.resolve_inner(importer, specifier, import_kind, is_user_defined_entry, Some(&mut trace));
+    match resolution {
+      Ok(info) => trace.push(ResolveTraceStep::Resolved(
+        info.full_path().to_str().expect("Should be valid utf8").into(),
+      )),
+      Err(err) => trace.push(ResolveTraceStep::Failed(resolve_error_to_message(&err))),
+    }
+    Some(trace)
+  }
+
   pub fn resolve_tsconfig<T: AsRef<Path>>(
     &self,
     path: &T,
@@ -140,6 +183,30 @@ impl<Fs: FileSystem> Resolver<Fs> {
     import_kind: ImportKind,
     is_user_defined_entry: bool,
   ) -> Result<ResolveReturn, ResolveError> {
+    let mut trace = self.trace.then(ResolveTrace::default);
+    let resolution =
+      self.resolve_inner(importer, specifier, import_kind, is_user_defined_entry, trace.as_mut());
+
+    resolution.map(|info| {
+      let package_json = info.package_json().map(|p| self.cached_package_json(p));
+      let module_def_format = infer_module_def_format(&info);
+      let path: ArcStr = info.full_path().to_str().expect("Should be valid utf8").into();
+      let trace = trace.map(|mut trace| {
+        trace.push(ResolveTraceStep::Resolved(path.clone()));
+        Arc::new(trace)
+      });
+      ResolveReturn { path, module_def_format, package_json, trace }
+    })
+  }
+
+  fn resolve_inner(
+    &self,
+    importer: Option<&Path>,
+    specifier: &str,
+    import_kind: ImportKind,
+    is_user_defined_entry: bool,
+    mut trace: Option<&mut ResolveTrace>,
+  ) -> Result<Resolution, ResolveError> {
     let selected_resolver = match import_kind {
       ImportKind::Import | ImportKind::DynamicImport | ImportKind::HotAccept => {
         &self.import_resolver
@@ -149,26 +216,150 @@ impl<Fs: FileSystem> Resolver<Fs> {
       ImportKind::AtImport | ImportKind::UrlImport => &self.css_resolver,
     };
 
-    let mut resolution = if let Some(importer) = importer {
-      selected_resolver.resolve_file(self.cwd.join(importer), specifier)
-    } else {
-      selected_resolver.resolve(self.cwd.as_path(), specifier)
+    let importer_path = importer.map(|importer| self.cwd.join(importer));
+    let from = match &importer_path {
+      Some(importer) => ResolveFrom::File(importer),
+      None => ResolveFrom::Directory(&self.cwd),
     };
+    let resolution =
+      self.resolve_with_trace(selected_resolver, from, specifier, trace.as_deref_mut());
 
     if resolution.is_err() && is_user_defined_entry {
-      resolution =
-        self.try_rollup_compatibility_resolve(selected_resolver, importer, specifier, resolution);
+      return self.try_rollup_compatibility_resolve(
+        selected_resolver,
+        importer,
+        specifier,
+        resolution,
+        trace,
+      );
     }
+    resolution
+  }
 
-    resolution.map(|info| {
-      let package_json = info.package_json().map(|p| self.cached_package_json(p));
-      let module_def_format = infer_module_def_format(&info);
-      ResolveReturn {
-        path: info.full_path().to_str().expect("Should be valid utf8").into(),
-        module_def_format,
-        package_json,
+  fn resolve_with_trace(
+    &self,
+    resolver: &ResolverGeneric<Fs>,
+    from: ResolveFrom<'_>,
+    specifier: &str,
+    trace: Option<&mut ResolveTrace>,
+  ) -> Result<Resolution, ResolveError> {
+    let Some(trace) = trace else {
+      return match from {
+        ResolveFrom::File(importer) => resolver.resolve_file(importer, specifier),
+        ResolveFrom::Directory(dir) => resolver.resolve(dir, specifier),
+      };
+    };
+
+    // The same `tsconfig.json` as `resolve_file` and `resolve` use.
+    let options = resolver.options();
+    let (dir, tsconfig) = match from {
+      ResolveFrom::File(importer) => {
+        (importer.parent().unwrap_or(importer), resolver.find_tsconfig(importer)?)
       }
-    })
+      ResolveFrom::Directory(dir) => {
+        let tsconfig = match &options.tsconfig {
+          Some(TsconfigDiscovery::Manual(tsconfig)) => {
+            Some(resolver.resolve_tsconfig(&tsconfig.config_file)?)
+          }
+          _ => None,
+        };
+        (dir, tsconfig)
+      }
+    };
+
+    let mut context = ResolveContext::default();
+    let resolution =
+      resolver.resolve_with_context(dir, specifier, tsconfig.as_deref(), &mut context);
+
+    // Substitutions are tried in the same order as `oxc_resolver` does, tsconfig `paths` first.
+    let is_inside_node_modules = dir.components().any(|c| c.as_os_str() == "node_modules");
+    if let Some(step) = tsconfig
+      .as_deref()
+      .filter(|_| !is_inside_node_modules)
+      .and_then(|tsconfig| trace::match_tsconfig_paths(tsconfig, specifier))
+    {
+      trace.push(step);
+    }
+    let alias = trace::match_alias(&options.alias, specifier);
+    // An alias to a package is looked up in the package's `exports` instead.
+    let package_specifier = match &alias {
+      Some(ResolveTraceStep::Alias { key, replacements }) => replacements
+        .iter()
+        .flatten()
+        .map(|replacement| alias_target(key, replacement, specifier))
+        .find(|specifier| !is_path_like_specifier(specifier))
+        .unwrap_or_else(|| specifier.to_string()),
+      _ => specifier.to_string(),
+    };
+    trace.steps.extend(alias);
+
+    // Both sets are unordered, sort them to keep the trace stable.
+    let mut file_dependencies = context.file_dependencies.into_iter().collect::<Vec<_>>();
+    file_dependencies.sort_unstable();
+    for path in file_dependencies {
+      match path.file_name().and_then(|name| name.to_str()) {
+        Some("package.json") => {
+          trace.push(ResolveTraceStep::PackageJson(path));
+        }
+        Some(name) if name.starts_with("tsconfig") && name.ends_with(".json") => {
+          trace.push(ResolveTraceStep::Tsconfig(path));
+        }
+        _ => {}
+      }
+    }
+    if !is_path_like_specifier(&package_specifier) {
+      if let Some(step) = self.match_package_entry(dir, &package_specifier, options) {
+        trace.push(step);
+      }
+    }
+    let mut missing_dependencies = context.missing_dependencies.into_iter().collect::<Vec<_>>();
+    missing_dependencies.sort_unstable();
+    trace.steps.extend(missing_dependencies.into_iter().map(ResolveTraceStep::Candidate));
+
+    resolution
+  }
+
+  /// Finds the `exports` or `imports` entry used for `specifier`. The `package.json` files are
+  /// looked up again, since `oxc_resolver` doesn't report the ones it had cached already.
+  fn match_package_entry(
+    &self,
+    dir: &Path,
+    specifier: &str,
+    options: &oxc_resolver::ResolveOptions,
+  ) -> Option<ResolveTraceStep> {
+    let read = |path: &Path| trace::PackageEntries::read(&self.fs.read(path).ok()?);
+    let closest_package_json = dir
+      .ancestors()
+      .map(|dir| dir.join("package.json"))
+      .find_map(|path| Some((read(&path)?, path)));
+    if specifier.starts_with('#') {
+      let (entries, package_json) = closest_package_json?;
+      return entries.match_imports(&package_json, specifier, &options.condition_names);
+    }
+    // A package importing itself by name is checked before `node_modules`, like `oxc_resolver`.
+    if let Some(step) = closest_package_json.and_then(|(entries, package_json)| {
+      entries.match_exports(
+        &package_json,
+        &options.exports_fields,
+        specifier,
+        &options.condition_names,
+      )
+    }) {
+      return Some(step);
+    }
+    let (name, _) = trace::split_package_specifier(specifier);
+    let (entries, package_json) = dir.ancestors().find_map(|dir| {
+      options.modules.iter().find_map(|modules| {
+        let package_json = dir.join(modules).join(name).join("package.json");
+        Some((read(&package_json)?, package_json))
+      })
+    })?;
+    entries.match_exports(
+      &package_json,
+      &options.exports_fields,
+      specifier,
+      &options.condition_names,
+    )
   }
 
   fn cached_package_json(&self, oxc_pkg_json: &OxcPackageJson) -> Arc<PackageJson> {
@@ -197,6 +388,7 @@ impl<Fs: FileSystem> Resolver<Fs> {
     importer: Option<&Path>,
     specifier: &str,
     original_resolution: Result<Resolution, ResolveError>,
+    mut trace: Option<&mut ResolveTrace>,
   ) -> Result<Resolution, ResolveError> {
     if specifier.starts_with('.') || specifier.starts_with('/') {
       return original_resolution;
@@ -209,11 +401,39 @@ impl<Fs: FileSystem> Resolver<Fs> {
 
     // Try resolving relative to cwd as a fallback
     let specifier_path = self.cwd.join(specifier).normalize();
-    let fallback = resolver.resolve(importer_dir, &specifier_path.to_string_lossy());
+    if let Some(trace) = trace.as_deref_mut() {
+      trace.push(ResolveTraceStep::RollupCompatibility(specifier_path.clone()));
+    }
+    let fallback = self.resolve_with_trace(
+      resolver,
+      ResolveFrom::Directory(importer_dir),
+      &specifier_path.to_string_lossy(),
+      trace,
+    );
     if fallback.is_ok() { fallback } else { original_resolution }
   }
 }
 
+#[derive(Clone, Copy)]
+enum ResolveFrom<'a> {
+  /// The file importing the specifier.
+  File(&'a Path),
+  Directory(&'a Path),
+}
+
+/// The specifier `oxc_resolver` continues with after substituting an alias, mirroring
+/// `load_alias_value`.
+fn alias_target(key: &str, replacement: &str, specifier: &str) -> String {
+  let key = key.strip_suffix('$').unwrap_or(key);
+  match key.split_once('*') {
+    Some((prefix, suffix)) => {
+      let matched = &specifier[prefix.len()..specifier.len() - suffix.len()];
+      replacement.replacen('*', matched, 1)
+    }
+    None => concat_string!(replacement, &specifier[key.len()..]),
+  }
+}
+
 /// Infer module format from file extension and package.json type field
 /// Reference: https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L1446-L1460
 fn infer_module_def_format(info: &Resolution) -> ModuleDefFormat {
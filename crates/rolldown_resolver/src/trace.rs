@@ -0,0 +1,356 @@
+//! The steps of a [`ResolveTrace`] that `oxc_resolver` doesn't report by itself.
+//!
+//! `oxc_resolver` only reports the files it read and the candidates it tried. Which `tsconfig.json`
+//! `paths` pattern, `resolve.alias` entry and `exports` or `imports` entry were used is found here
+//! by following the same rules as `oxc_resolver`, in the same order. Tests in
+//! `tests/resolve_trace.rs` check that these steps agree with the resolution.
+
+use std::{cmp::Ordering, fmt, path::Path};
+
+use oxc_resolver::{Alias, AliasValue, TsConfig as OxcTsConfig};
+use rolldown_common::ResolveTraceStep;
+use rolldown_utils::concat_string;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+/// The `compilerOptions.paths` pattern that applies to `specifier`. Like `oxc_resolver`, an exact
+/// key wins, otherwise the pattern with the longest prefix before `*`.
+pub fn match_tsconfig_paths(tsconfig: &OxcTsConfig, specifier: &str) -> Option<ResolveTraceStep> {
+  if specifier.starts_with('.') {
+    return None;
+  }
+  let paths = tsconfig.compiler_options.paths.as_ref()?;
+  let (pattern, substitutions) = match paths.get_key_value(specifier) {
+    Some((pattern, substitutions)) => {
+      (pattern, substitutions.iter().map(|path| path.to_string_lossy().into_owned()).collect())
+    }
+    None => {
+      let (pattern, matched) = paths
+        .keys()
+        .filter_map(|pattern| {
+          let (prefix, suffix) = pattern.split_once('*')?;
+          let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+          Some((pattern, matched, prefix.len()))
+        })
+        // `max_by_key` would pick the last of the equally long prefixes, not the first.
+        .fold(None, |best: Option<(&String, &str, usize)>, candidate| match best {
+          Some(best) if best.2 >= candidate.2 => Some(best),
+          _ => Some(candidate),
+        })
+        .map(|(pattern, matched, _)| (pattern, matched))?;
+      let substitutions =
+        paths[pattern].iter().map(|path| path.to_string_lossy().replace('*', matched)).collect();
+      (pattern, substitutions)
+    }
+  };
+  Some(ResolveTraceStep::TsconfigPaths {
+    tsconfig: tsconfig.path().to_path_buf(),
+    pattern: pattern.clone(),
+    substitutions,
+  })
+}
+
+/// The first `resolve.alias` entry that `oxc_resolver` substitutes `specifier` with.
+///
+/// A key ending with `$` only matches exactly, a key containing `*` matches as a pattern and
+/// other keys also match as a path prefix. Replacements that `specifier` already points into are
+/// skipped, an entry without any other replacement is passed over.
+pub fn match_alias(alias: &Alias, specifier: &str) -> Option<ResolveTraceStep> {
+  let (key, values) = alias.iter().find(|(key, values)| {
+    let matched = if let Some(exact) = key.strip_suffix('$') {
+      specifier == exact
+    } else if let Some((prefix, suffix)) = key.split_once('*') {
+      specifier.len() >= prefix.len() + suffix.len()
+        && specifier.starts_with(prefix)
+        && specifier.ends_with(suffix)
+    } else {
+      specifier
+        .strip_prefix(key.as_str())
+        .is_some_and(|tail| tail.is_empty() || tail.starts_with(['/', '\\']))
+    };
+    matched
+      && values.iter().any(|value| match value {
+        AliasValue::Path(path) => {
+          specifier != path
+            && !specifier.strip_prefix(path.as_str()).is_some_and(|tail| tail.starts_with('/'))
+        }
+        AliasValue::Ignore => true,
+      })
+  })?;
+  Some(ResolveTraceStep::Alias {
+    key: key.clone(),
+    replacements: values
+      .iter()
+      .map(|value| match value {
+        AliasValue::Path(path) => Some(path.clone()),
+        AliasValue::Ignore => None,
+      })
+      .collect(),
+  })
+}
+
+/// The `package.json` fields used to look up a specifier, as read by [`PackageEntries::read`].
+pub struct PackageEntries {
+  name: Option<String>,
+  json: OrderedJson,
+}
+
+impl PackageEntries {
+  pub fn read(source: &[u8]) -> Option<Self> {
+    let json = serde_json::from_slice::<OrderedJson>(source).ok()?;
+    let name = match json.get("name") {
+      Some(OrderedJson::String(name)) => Some(name.clone()),
+      _ => None,
+    };
+    Some(Self { name, json })
+  }
+
+  /// The `exports` entry of the package at `package_json` that `oxc_resolver` uses for
+  /// `specifier`, like `pkg/feature`, and the conditions that select its target.
+  pub fn match_exports(
+    &self,
+    package_json: &Path,
+    exports_fields: &[Vec<String>],
+    specifier: &str,
+    condition_names: &[String],
+  ) -> Option<ResolveTraceStep> {
+    let (name, subpath) = split_package_specifier(specifier);
+    // Packages are found by their directory in `node_modules`, or by their name when they import
+    // themselves.
+    let is_package = package_json.parent().is_some_and(|dir| dir.ends_with(name))
+      || self.name.as_deref() == Some(name);
+    if !is_package {
+      return None;
+    }
+    let exports = exports_fields.iter().find_map(|field| self.json.get_path(field))?;
+    let (key, target) = if subpath == "." {
+      let main_export = match exports {
+        OrderedJson::Object(members) => match exports.get(".") {
+          Some(main_export) => Some(main_export),
+          None => (!members.iter().any(|(key, _)| key.starts_with("./") || key.starts_with('#')))
+            .then_some(exports),
+        },
+        _ => Some(exports),
+      };
+      main_export.map(|target| (".", target))
+    } else {
+      None
+    }
+    .or_else(|| match exports {
+      OrderedJson::Object(members) => match_subpath(members, &subpath),
+      _ => None,
+    })?;
+    package_entry_step(package_json, "exports", key, target, condition_names)
+  }
+
+  /// The `imports` entry used for `specifier`, like `#internal`, and the conditions that select
+  /// its target.
+  pub fn match_imports(
+    &self,
+    package_json: &Path,
+    specifier: &str,
+    condition_names: &[String],
+  ) -> Option<ResolveTraceStep> {
+    let OrderedJson::Object(members) = self.json.get("imports")? else {
+      return None;
+    };
+    let (key, target) = match_subpath(members, specifier)?;
+    package_entry_step(package_json, "imports", key, target, condition_names)
+  }
+}
+
+/// Only entries with a target are recorded, `null` targets exclude the subpath instead.
+fn package_entry_step(
+  package_json: &Path,
+  field: &'static str,
+  key: &str,
+  target: &OrderedJson,
+  condition_names: &[String],
+) -> Option<ResolveTraceStep> {
+  let mut conditions = vec![];
+  select_conditional_target(target, condition_names, &mut conditions).then(|| {
+    ResolveTraceStep::PackageEntry {
+      package_json: package_json.to_path_buf(),
+      field,
+      key: key.to_string(),
+      conditions,
+    }
+  })
+}
+
+/// Splits `pkg/sub/path` into `pkg` and `./sub/path`, and `pkg` into `pkg` and `.`.
+pub fn split_package_specifier(specifier: &str) -> (&str, String) {
+  let name_len = if specifier.starts_with('@') {
+    specifier.match_indices('/').nth(1).map_or(specifier.len(), |(idx, _)| idx)
+  } else {
+    specifier.find('/').unwrap_or(specifier.len())
+  };
+  let (name, rest) = specifier.split_at(name_len);
+  (name, concat_string!(".", rest))
+}
+
+/// Matches a subpath against the keys of `exports` or `imports` like `PACKAGE_IMPORTS_EXPORTS_RESOLVE`
+/// does. An exact key wins over patterns and folders, which are ordered by `PATTERN_KEY_COMPARE`.
+fn match_subpath<'a>(
+  members: &'a [(String, OrderedJson)],
+  subpath: &str,
+) -> Option<(&'a str, &'a OrderedJson)> {
+  if subpath.ends_with('/') {
+    return None;
+  }
+  if !subpath.contains('*') {
+    if let Some((key, target)) = members.iter().find(|(key, _)| key == subpath) {
+      return Some((key, target));
+    }
+  }
+  let mut best: Option<(&str, &OrderedJson)> = None;
+  for (key, target) in members {
+    if !key.starts_with("./") && !key.starts_with('#') {
+      continue;
+    }
+    let matched = match key.split_once('*') {
+      Some((base, trailer)) => {
+        subpath.starts_with(base)
+          && !trailer.contains('*')
+          && (trailer.is_empty() || (subpath.len() >= key.len() && subpath.ends_with(trailer)))
+      }
+      None => key.ends_with('/') && subpath.starts_with(key.as_str()),
+    };
+    if matched && pattern_key_compare(best.map_or("", |(key, _)| key), key).is_gt() {
+      best = Some((key, target));
+    }
+  }
+  best
+}
+
+/// `PATTERN_KEY_COMPARE` of the Node.js resolution algorithm, ordering more specific keys first.
+fn pattern_key_compare(key_a: &str, key_b: &str) -> Ordering {
+  if key_a.is_empty() {
+    return Ordering::Greater;
+  }
+  let star_a = key_a.find('*');
+  let star_b = key_b.find('*');
+  let base_length_a = star_a.map_or(key_a.len(), |idx| idx + 1);
+  let base_length_b = star_b.map_or(key_b.len(), |idx| idx + 1);
+  base_length_b
+    .cmp(&base_length_a)
+    .then_with(|| match (star_a, star_b) {
+      (None, _) => Ordering::Greater,
+      (_, None) => Ordering::Less,
+      _ => Ordering::Equal,
+    })
+    .then_with(|| key_b.len().cmp(&key_a.len()))
+}
+
+/// Picks the target of a conditional `exports` or `imports` entry the way `oxc_resolver` does,
+/// collecting the conditions that lead to it. Returns `false` if no target applies.
+///
+/// Like `oxc_resolver`, a `null` target under a matching condition moves on to the next one.
+fn select_conditional_target(
+  target: &OrderedJson,
+  condition_names: &[String],
+  conditions: &mut Vec<String>,
+) -> bool {
+  match target {
+    OrderedJson::String(_) => true,
+    OrderedJson::Array(targets) => {
+      targets.iter().any(|target| select_conditional_target(target, condition_names, conditions))
+    }
+    OrderedJson::Object(members) => members.iter().any(|(condition, target)| {
+      if condition != "default" && !condition_names.contains(condition) {
+        return false;
+      }
+      conditions.push(condition.clone());
+      let selected = select_conditional_target(target, condition_names, conditions);
+      if !selected {
+        conditions.pop();
+      }
+      selected
+    }),
+    OrderedJson::Other => false,
+  }
+}
+
+/// A JSON value that keeps object members in declaration order, which decides the condition
+/// used by `exports` and `imports` entries, without relying on `serde_json/preserve_order`.
+enum OrderedJson {
+  String(String),
+  Array(Vec<OrderedJson>),
+  Object(Vec<(String, OrderedJson)>),
+  /// `null`, booleans and numbers, none of which is a target.
+  Other,
+}
+
+impl OrderedJson {
+  fn get(&self, key: &str) -> Option<&OrderedJson> {
+    match self {
+      OrderedJson::Object(members) => {
+        members.iter().find_map(|(name, value)| (name == key).then_some(value))
+      }
+      _ => None,
+    }
+  }
+
+  fn get_path(&self, path: &[String]) -> Option<&OrderedJson> {
+    path.iter().try_fold(self, |value, key| value.get(key))
+  }
+}
+
+impl<'de> Deserialize<'de> for OrderedJson {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    struct OrderedJsonVisitor;
+
+    impl<'de> Visitor<'de> for OrderedJsonVisitor {
+      type Value = OrderedJson;
+
+      fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::String(value.to_string()))
+      }
+
+      fn visit_string<E>(self, value: String) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::String(value))
+      }
+
+      fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<OrderedJson, A::Error> {
+        let mut items = vec![];
+        while let Some(item) = seq.next_element()? {
+          items.push(item);
+        }
+        Ok(OrderedJson::Array(items))
+      }
+
+      fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<OrderedJson, A::Error> {
+        let mut members = vec![];
+        while let Some(member) = map.next_entry()? {
+          members.push(member);
+        }
+        Ok(OrderedJson::Object(members))
+      }
+
+      fn visit_unit<E>(self) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::Other)
+      }
+
+      fn visit_bool<E>(self, _: bool) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::Other)
+      }
+
+      fn visit_i64<E>(self, _: i64) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::Other)
+      }
+
+      fn visit_u64<E>(self, _: u64) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::Other)
+      }
+
+      fn visit_f64<E>(self, _: f64) -> Result<OrderedJson, E> {
+        Ok(OrderedJson::Other)
+      }
+    }
+
+    deserializer.deserialize_any(OrderedJsonVisitor)
+  }
+}
@@ -0,0 +1,289 @@
+//! The trace reports which `tsconfig.json` `paths`, `resolve.alias` and `package.json` entry was
+//! used. These are matched outside of `oxc_resolver`, so every case checks that the reported entry
+//! is also the one the resolution went through.
+
+use std::path::{Path, PathBuf};
+
+use rolldown_common::{ImportKind, Platform, ResolveOptions, ResolveTraceStep, TsConfig};
+use rolldown_fs::MemoryFileSystem;
+use rolldown_resolver::Resolver;
+
+fn resolver(
+  files: &[(&str, &str)],
+  tsconfig: Option<&TsConfig>,
+  resolve_options: ResolveOptions,
+) -> Resolver<MemoryFileSystem> {
+  let mut fs = MemoryFileSystem::default();
+  for (path, content) in files {
+    fs.add_file(Path::new(path), content);
+  }
+  Resolver::new(fs, PathBuf::from("/"), Platform::Neutral, tsconfig, resolve_options)
+    .with_trace(true)
+}
+
+/// Resolves `specifier` from `/src/main.js`, returning the resolved path and the substitutions and
+/// `package.json` entries reported by the trace.
+fn resolve(
+  resolver: &Resolver<MemoryFileSystem>,
+  specifier: &str,
+  import_kind: ImportKind,
+) -> (Option<String>, Vec<String>) {
+  let importer = Path::new("/src/main.js");
+  let trace = match resolver.resolve(Some(importer), specifier, import_kind, false) {
+    Ok(resolved) => resolved.trace.expect("trace should be enabled").as_ref().clone(),
+    Err(_) => resolver
+      .trace_failed_resolve(Some(importer), specifier, import_kind, false)
+      .expect("trace should be enabled"),
+  };
+  let matched = trace
+    .steps
+    .iter()
+    .filter(|step| {
+      matches!(
+        step,
+        ResolveTraceStep::Alias { .. }
+          | ResolveTraceStep::TsconfigPaths { .. }
+          | ResolveTraceStep::PackageEntry { .. }
+      )
+    })
+    .map(ToString::to_string)
+    .collect();
+  (trace.resolved().map(ToString::to_string), matched)
+}
+
+const MAIN: (&str, &str) = ("/src/main.js", "");
+
+#[test]
+fn exports_conditions_follow_import_kind() {
+  let resolver = resolver(
+    &[
+      MAIN,
+      (
+        "/node_modules/pkg/package.json",
+        r#"{ "exports": { ".": { "require": "./cjs.js", "import": "./esm.js" } } }"#,
+      ),
+      ("/node_modules/pkg/esm.js", ""),
+      ("/node_modules/pkg/cjs.js", ""),
+    ],
+    None,
+    ResolveOptions::default(),
+  );
+
+  assert_eq!(
+    resolve(&resolver, "pkg", ImportKind::Import),
+    (
+      Some("/node_modules/pkg/esm.js".to_string()),
+      vec![
+        "matched exports \".\" of /node_modules/pkg/package.json with conditions [import]"
+          .to_string()
+      ]
+    )
+  );
+  assert_eq!(
+    resolve(&resolver, "pkg", ImportKind::Require),
+    (
+      Some("/node_modules/pkg/cjs.js".to_string()),
+      vec![
+        "matched exports \".\" of /node_modules/pkg/package.json with conditions [require]"
+          .to_string()
+      ]
+    )
+  );
+}
+
+#[test]
+fn exports_null_condition_falls_through() {
+  let resolver = resolver(
+    &[
+      MAIN,
+      (
+        "/node_modules/pkg/package.json",
+        r#"{ "exports": { ".": { "import": null, "default": "./default.js" } } }"#,
+      ),
+      ("/node_modules/pkg/default.js", ""),
+    ],
+    None,
+    ResolveOptions::default(),
+  );
+
+  assert_eq!(
+    resolve(&resolver, "pkg", ImportKind::Import),
+    (
+      Some("/node_modules/pkg/default.js".to_string()),
+      vec![
+        "matched exports \".\" of /node_modules/pkg/package.json with conditions [default]"
+          .to_string()
+      ]
+    )
+  );
+}
+
+#[test]
+fn exports_patterns_by_specificity_and_null_exclusions() {
+  let resolver = resolver(
+    &[
+      MAIN,
+      (
+        "/node_modules/pkg/package.json",
+        r#"{
+          "exports": {
+            "./*": "./src/*.js",
+            "./features/*": "./features/*.js",
+            "./internal/*": null
+          }
+        }"#,
+      ),
+      ("/node_modules/pkg/src/a.js", ""),
+      ("/node_modules/pkg/features/a.js", ""),
+      ("/node_modules/pkg/src/internal/a.js", ""),
+    ],
+    None,
+    ResolveOptions::default(),
+  );
+
+  assert_eq!(
+    resolve(&resolver, "pkg/a", ImportKind::Import),
+    (
+      Some("/node_modules/pkg/src/a.js".to_string()),
+      vec!["matched exports \"./*\" of /node_modules/pkg/package.json".to_string()]
+    )
+  );
+  assert_eq!(
+    resolve(&resolver, "pkg/features/a", ImportKind::Import),
+    (
+      Some("/node_modules/pkg/features/a.js".to_string()),
+      vec!["matched exports \"./features/*\" of /node_modules/pkg/package.json".to_string()]
+    )
+  );
+  // A `null` target excludes the subpath instead of falling back to the less specific `./*`.
+  assert_eq!(resolve(&resolver, "pkg/internal/a", ImportKind::Import), (None, vec![]));
+}
+
+#[test]
+fn imports_use_closest_package_json() {
+  let resolver = resolver(
+    &[
+      MAIN,
+      (
+        "/package.json",
+        r##"{ "imports": { "#dep": { "node": "./node.js", "default": "./dep.js" } } }"##,
+      ),
+      ("/node.js", ""),
+      ("/dep.js", ""),
+    ],
+    None,
+    ResolveOptions::default(),
+  );
+
+  assert_eq!(
+    resolve(&resolver, "#dep", ImportKind::Import),
+    (
+      Some("/dep.js".to_string()),
+      vec!["matched imports \"#dep\" of /package.json with conditions [default]".to_string()]
+    )
+  );
+}
+
+#[test]
+fn alias_keys() {
+  let resolver = resolver(
+    &[
+      MAIN,
+      ("/lib/utils/a.js", ""),
+      ("/lib/exact.js", ""),
+      ("/src/feature.js", ""),
+      (
+        "/node_modules/preact/package.json",
+        r#"{ "exports": { "./compat": { "import": "./compat.mjs" } } }"#,
+      ),
+      ("/node_modules/preact/compat.mjs", ""),
+    ],
+    None,
+    ResolveOptions {
+      alias: Some(vec![
+        ("utils".to_string(), vec![Some("/lib/utils".to_string())]),
+        ("exact$".to_string(), vec![Some("/lib/exact.js".to_string())]),
+        ("@/*".to_string(), vec![Some("/src/*".to_string())]),
+        ("react".to_string(), vec![Some("preact/compat".to_string())]),
+      ]),
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(
+    resolve(&resolver, "utils/a", ImportKind::Import),
+    (
+      Some("/lib/utils/a.js".to_string()),
+      vec!["matched alias \"utils\" -> [\"/lib/utils\"]".to_string()]
+    )
+  );
+  assert_eq!(
+    resolve(&resolver, "exact", ImportKind::Import),
+    (
+      Some("/lib/exact.js".to_string()),
+      vec!["matched alias \"exact$\" -> [\"/lib/exact.js\"]".to_string()]
+    )
+  );
+  // `exact$` only matches the specifier itself.
+  assert_eq!(resolve(&resolver, "exact/a", ImportKind::Import), (None, vec![]));
+  assert_eq!(
+    resolve(&resolver, "@/feature", ImportKind::Import),
+    (
+      Some("/src/feature.js".to_string()),
+      vec!["matched alias \"@/*\" -> [\"/src/*\"]".to_string()]
+    )
+  );
+  // The package an alias points to is looked up by the substituted specifier.
+  assert_eq!(
+    resolve(&resolver, "react", ImportKind::Import),
+    (
+      Some("/node_modules/preact/compat.mjs".to_string()),
+      vec![
+        "matched alias \"react\" -> [\"preact/compat\"]".to_string(),
+        "matched exports \"./compat\" of /node_modules/preact/package.json with conditions \
+         [import]"
+          .to_string(),
+      ]
+    )
+  );
+}
+
+#[test]
+fn tsconfig_paths() {
+  let resolver = resolver(
+    &[
+      MAIN,
+      (
+        "/tsconfig.json",
+        r#"{
+          "compilerOptions": {
+            "paths": { "config": ["./src/config.js"], "~/*": ["./missing/*", "./src/*"] }
+          }
+        }"#,
+      ),
+      ("/src/config.js", ""),
+      ("/src/feature.js", ""),
+    ],
+    Some(&TsConfig::Auto),
+    ResolveOptions::default(),
+  );
+
+  assert_eq!(
+    resolve(&resolver, "config", ImportKind::Import),
+    (
+      Some("/src/config.js".to_string()),
+      vec!["matched paths \"config\" -> [\"/src/config.js\"] of /tsconfig.json".to_string()]
+    )
+  );
+  // Substitutions are listed in the order they are tried.
+  assert_eq!(
+    resolve(&resolver, "~/feature", ImportKind::Import),
+    (
+      Some("/src/feature.js".to_string()),
+      vec![
+        "matched paths \"~/*\" -> [\"/missing/feature\", \"/src/feature\"] of /tsconfig.json"
+          .to_string()
+      ]
+    )
+  );
+}
@@ -27,6 +27,10 @@ impl BitSet {
       self.entries[i] |= e;
     }
   }
+
+  pub fn is_subset_of(&self, other: &Self) -> bool {
+    self.entries.iter().zip(&other.entries).all(|(&a, &b)| a & !b == 0)
+  }
   // It is safe to convert `usize` to `u32` here because we ensure that the bitset is created with a maximum bit count that fits within `u32`.
   #[expect(clippy::cast_possible_truncation)]
   pub fn index_of_one(&self) -> Vec<u32> {
@@ -115,4 +119,18 @@ mod tests {
 
     assert_eq!(bits.index_of_one(), vec![1, 5, 8, 10, 13, 15]);
   }
+
+  #[test]
+  fn is_subset_of() {
+    let mut small = BitSet::new(16);
+    small.set_bit(1);
+    small.set_bit(9);
+    let mut large = small.clone();
+    large.set_bit(3);
+
+    assert!(small.is_subset_of(&large));
+    assert!(small.is_subset_of(&small));
+    assert!(!large.is_subset_of(&small));
+    assert!(BitSet::new(16).is_subset_of(&small));
+  }
 }
@@ -0,0 +1,74 @@
+use std::collections::hash_map::Entry;
+
+use oxc::span::CompactStr;
+use oxc::syntax::keyword::{GLOBAL_OBJECTS, RESERVED_KEYWORDS};
+use rustc_hash::FxHashMap;
+
+use crate::concat_string;
+
+/// Hands out top-level names that don't conflict with each other.
+///
+/// A conflicting name is suffixed with `$1`, `$2`, and so on until an unused name is found.
+#[derive(Debug, Default)]
+pub struct ConflictlessNames {
+  /// key is the original name,
+  /// value is the how many same variable name in the top level are used before
+  /// It is also used to calculate the candidate_name e.g.
+  /// ```js
+  /// // index.js
+  /// import {a as b} from './a.js'
+  /// const a = 1; // {a => 0}
+  /// const a$1 = 1000; // {a => 0, a$1 => 0}
+  ///
+  ///
+  /// // a.js
+  /// export const a = 100; // {a => 0, a$1 => 0}, first we try looking up `a`, it is used. So we try the
+  ///                       // candidate_name `a$1`(conflict_index + 1 = 1). Then we try `a$2`, so
+  ///                       // on and so forth. Until we find a name that is not used. In this case, `a$2` is not used
+  ///                       // so we rename `a` to `a$2`
+  /// ```
+  ///
+  used_names: FxHashMap<CompactStr, u32>,
+}
+
+impl ConflictlessNames {
+  /// Creates an instance that never hands out reserved keywords, global objects or `reserved`.
+  pub fn with_reserved<'a>(reserved: impl IntoIterator<Item = &'a str>) -> Self {
+    Self {
+      used_names: RESERVED_KEYWORDS
+        .iter()
+        .chain(GLOBAL_OBJECTS.iter())
+        .map(|s| (CompactStr::new(s), 0))
+        .chain(reserved.into_iter().map(|s| (CompactStr::new(s), 0)))
+        .collect(),
+    }
+  }
+
+  pub fn reserve(&mut self, name: CompactStr) {
+    self.used_names.insert(name, 0);
+  }
+
+  /// Returns `hint` itself if it's not used yet, otherwise a suffixed variant of it.
+  pub fn create(&mut self, hint: &str) -> CompactStr {
+    let mut candidate_name = CompactStr::new(hint);
+    loop {
+      match self.used_names.entry(candidate_name.clone()) {
+        Entry::Occupied(mut occ) => {
+          let next_conflict_index = *occ.get() + 1;
+          *occ.get_mut() = next_conflict_index;
+          candidate_name =
+            concat_string!(hint, "$", itoa::Buffer::new().format(next_conflict_index)).into();
+        }
+        Entry::Vacant(vac) => {
+          vac.insert(0);
+          break candidate_name;
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn used_names(&self) -> &FxHashMap<CompactStr, u32> {
+    &self.used_names
+  }
+}
@@ -42,6 +42,26 @@ async fn _test_block_on_spawn_all_non_static_future() {
   let _ = block_on_spawn_all(std::iter::once(non_static_future)).await;
 }
 
+/// Runs blocking work, such as file system calls, without blocking the threads of the async
+/// runtime. On wasm, the work runs in place.
+#[cfg_attr(target_family = "wasm", expect(clippy::unused_async))]
+pub async fn spawn_blocking<F, R>(f: F) -> R
+where
+  F: FnOnce() -> R + Send + 'static,
+  R: Send + 'static,
+{
+  #[cfg(target_family = "wasm")]
+  {
+    f()
+  }
+  #[cfg(not(target_family = "wasm"))]
+  {
+    tokio::task::spawn_blocking(f)
+      .await
+      .unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()))
+  }
+}
+
 pub fn block_on<F: Future>(f: F) -> F::Output {
   #[cfg(target_family = "wasm")]
   {
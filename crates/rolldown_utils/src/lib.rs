@@ -19,6 +19,7 @@ pub mod xxhash;
 pub use bitset::BitSet;
 pub mod commondir;
 pub mod concat_string;
+pub mod conflictless_names;
 pub mod filter_expression;
 pub mod hash_placeholder;
 pub mod index_vec_ext;
@@ -8,7 +8,7 @@ export type SessionMeta = {
   plugins: Array<PluginItem>;
   cwd: string;
   platform: 'browser' | 'node' | 'neutral';
-  format: 'esm' | 'cjs' | 'iife' | 'umd';
+  format: 'esm' | 'cjs' | 'iife' | 'umd' | 'amd';
   /**
    * `OutputOptions.dir`
    */
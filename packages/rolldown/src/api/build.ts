@@ -10,9 +10,16 @@ export interface BuildOptions extends InputOptions {
    * @default true
    */
   write?: boolean;
-  output?: OutputOptions;
+  /**
+   * The options of the output, or of each output to generate from a single scan of the modules.
+   * Only the first output can have `plugins`.
+   */
+  output?: OutputOptions | OutputOptions[];
 }
 
+async function build(
+  options: BuildOptions & { output: OutputOptions[] },
+): Promise<RolldownOutput[]>;
 async function build(options: BuildOptions): Promise<RolldownOutput>;
 /**
  * Build multiple outputs __sequentially__.
@@ -27,6 +34,11 @@ async function build(
     const { output, write = true, ...inputOptions } = options;
     const build = await rolldown(inputOptions);
     try {
+      if (Array.isArray(output)) {
+        return write
+          ? await build.writeOutputs(output)
+          : await build.generateOutputs(output);
+      }
       if (write) {
         return await build.write(output);
       } else {
@@ -8,7 +8,10 @@ import type { OutputOptions } from '../../options/output-options';
 import type { HasProperty, TypeAssert } from '../../types/assert';
 import type { RolldownOutput } from '../../types/rolldown-output';
 import { RolldownOutputImpl } from '../../types/rolldown-output-impl';
-import { createBundlerOptions } from '../../utils/create-bundler-option';
+import {
+  createAdditionalOutputOptions,
+  createBundlerOptions,
+} from '../../utils/create-bundler-option';
 import { unwrapBindingResult } from '../../utils/error';
 import { validateOption } from '../../utils/validator';
 
@@ -39,6 +42,28 @@ export class RolldownBuild {
     return this.#build(true, outputOptions);
   }
 
+  /**
+   * Generate an output for each of `outputsOptions`, scanning the modules only once.
+   *
+   * Options that affect scanning, like `platform`, are taken from the input options, and only
+   * the first output can have `plugins`.
+   */
+  async generateOutputs(
+    outputsOptions: OutputOptions[],
+  ): Promise<RolldownOutput[]> {
+    return this.#buildOutputs(false, outputsOptions);
+  }
+
+  /**
+   * Write an output for each of `outputsOptions`, scanning the modules only once.
+   *
+   * Options that affect scanning, like `platform`, are taken from the input options, and only
+   * the first output can have `plugins`.
+   */
+  async writeOutputs(outputsOptions: OutputOptions[]): Promise<RolldownOutput[]> {
+    return this.#buildOutputs(true, outputsOptions);
+  }
+
   /**
    * Close the build and free resources.
    */
@@ -91,6 +116,58 @@ export class RolldownBuild {
       throw e;
     }
   }
+
+  async #buildOutputs(
+    isWrite: boolean,
+    outputsOptions: OutputOptions[],
+  ): Promise<RolldownOutput[]> {
+    const [firstOutputOptions = {}, ...additionalOutputsOptions] =
+      outputsOptions;
+    for (const outputOptions of outputsOptions) {
+      validateOption('output', outputOptions);
+    }
+    await this.#stopWorkers?.();
+    const option = await createBundlerOptions(
+      this.#inputOptions,
+      firstOutputOptions,
+      false,
+    );
+
+    if (RolldownBuild.asyncRuntimeShutdown) {
+      startAsyncRuntime();
+    }
+
+    try {
+      this.#stopWorkers = option.stopWorkers;
+      const additionalOutputs = await Promise.all(
+        additionalOutputsOptions.map((outputOptions) =>
+          createAdditionalOutputOptions(
+            this.#inputOptions,
+            outputOptions,
+            option.onLog,
+          )
+        ),
+      );
+      let outputs: Awaited<ReturnType<BindingBundler['generateOutputs']>>;
+      if (isWrite) {
+        outputs = await this.#bundler.writeOutputs(
+          option.bundlerOptions,
+          additionalOutputs,
+        );
+      } else {
+        outputs = await this.#bundler.generateOutputs(
+          option.bundlerOptions,
+          additionalOutputs,
+        );
+      }
+      return unwrapBindingResult(outputs).map((output) =>
+        new RolldownOutputImpl(output)
+      );
+    } catch (e) {
+      await option.stopWorkers?.();
+      throw e;
+    }
+  }
 }
 
 function _assert() {
@@ -1369,6 +1369,13 @@ export declare class BindingBundler {
   constructor()
   generate(options: BindingBundlerOptions): Promise<BindingResult<BindingOutputs>>
   write(options: BindingBundlerOptions): Promise<BindingResult<BindingOutputs>>
+  /**
+   * Scans the modules once and generates the output of `options` and each of
+   * `additional_outputs`.
+   */
+  generateOutputs(options: BindingBundlerOptions, additionalOutputs: Array<BindingOutputOptions>): Promise<BindingResult<Array<BindingOutputs>>>
+  /** Scans the modules once and writes the output of `options` and each of `additional_outputs`. */
+  writeOutputs(options: BindingBundlerOptions, additionalOutputs: Array<BindingOutputOptions>): Promise<BindingResult<Array<BindingOutputs>>>
   scan(options: BindingBundlerOptions): Promise<BindingResult<undefined>>
   close(): Promise<undefined>
   get closed(): boolean
@@ -1444,7 +1451,7 @@ export declare class BindingNormalizedOptions {
   get assetFilenames(): string | undefined
   get dir(): string | null
   get file(): string | null
-  get format(): 'es' | 'cjs' | 'iife' | 'umd'
+  get format(): 'es' | 'cjs' | 'iife' | 'umd' | 'amd'
   get exports(): 'default' | 'named' | 'none' | 'auto'
   get esModule(): boolean | 'if-default-prop'
   get inlineDynamicImports(): boolean
@@ -1598,6 +1605,13 @@ export interface BindingAdvancedChunksOptions {
   maxSize?: number
   minModuleSize?: number
   maxModuleSize?: number
+  sizeMetric?: 'source' | 'rendered' | 'minified' | 'gzip'
+  splitStrategy?: 'size' | 'locality'
+}
+
+export interface BindingAmdOptions {
+  id?: string
+  define?: string
 }
 
 export interface BindingAssetSource {
@@ -1774,6 +1788,22 @@ export interface BindingExperimentalOptions {
   incrementalBuild?: boolean
   transformHiresSourcemap?: boolean | 'boundary'
   nativeMagicString?: boolean
+  resolveTrace?: boolean
+  nodePolyfills?: BindingNodePolyfillsOptions
+  standaloneRuntimeChunk?: boolean
+  minChunkSize?: number | BindingMinChunkSizeOptions
+  preloadDynamicImports?: boolean
+  nameCache?: string
+  mangleProps?: BindingManglePropsOptions
+  commonjsToEsm?: boolean
+  detectExternalCommonjsExports?: boolean
+  concurrentTopLevelAwait?: boolean
+  topLevelAwaitWrapper?: boolean
+  preserveDirectives?: boolean
+  htmlEntries?: boolean | BindingHtmlEntriesOptions
+  importMap?: BindingImportMap
+  remoteImports?: BindingRemoteImportsOptions
+  moduleFederation?: BindingModuleFederationOptions
 }
 
 export interface BindingFilterToken {
@@ -1871,6 +1901,15 @@ export interface BindingHookTransformOutput {
   moduleType?: string
 }
 
+export interface BindingHtmlEntriesOptions {
+  inlineScriptLimit?: number
+}
+
+export interface BindingImportMap {
+  imports?: Record<string, string>
+  scopes?: Record<string, Record<string, string>>
+}
+
 export interface BindingInjectImportNamed {
   tagNamed: true
   imported: string
@@ -1927,6 +1966,8 @@ export interface BindingInputOptions {
 
 export interface BindingIsolatedDeclarationPluginConfig {
   stripInternal?: boolean
+  /** Emit a single rolled-up `.d.ts` file for each entry chunk instead of one per module. */
+  bundle?: boolean
 }
 
 export interface BindingJsonSourcemap {
@@ -1963,6 +2004,13 @@ export type BindingMakeAbsoluteExternalsRelative =
   | { type: 'Bool', field0: boolean }
   | { type: 'IfRelativeSource' }
 
+export interface BindingManglePropsOptions {
+  regex: RegExp
+  reserved?: Array<string>
+  quoted?: boolean
+  cache?: string
+}
+
 export interface BindingMatchGroup {
   name: string | ((id: string, ctx: BindingChunkingContext) => VoidNullable<string>)
   test?: string | RegExp | ((id: string) => VoidNullable<boolean>)
@@ -1974,6 +2022,19 @@ export interface BindingMatchGroup {
   maxSize?: number
 }
 
+export interface BindingMinChunkSizeOptions {
+  minSize: number
+  tolerance?: number
+}
+
+export interface BindingModuleFederationOptions {
+  name: string
+  filename?: string
+  exposes?: Record<string, string>
+  remotes?: Record<string, string>
+  shared?: Record<string, BindingSharedModuleOptions>
+}
+
 export interface BindingModulePreloadOptions {
   polyfill: boolean
   resolveDependencies?: (filename: string, deps: string[], context: { hostId: string, hostType: 'html' | 'js' }) => string[]
@@ -1990,6 +2051,11 @@ export interface BindingModuleSideEffectsRule {
   external?: boolean | undefined
 }
 
+export interface BindingNodePolyfillsOptions {
+  modules?: Record<string, string | boolean>
+  globals?: boolean
+}
+
 export interface BindingNotifyOption {
   pollInterval?: number
   compareContents?: boolean
@@ -2008,6 +2074,7 @@ export interface BindingOutputOptions {
   cssEntryFileNames?: string | ((chunk: PreRenderedChunk) => string)
   cssChunkFileNames?: string | ((chunk: PreRenderedChunk) => string)
   sanitizeFileName?: boolean | ((name: string) => string)
+  amd?: BindingAmdOptions
   banner?: (chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>
   dir?: string
   file?: string
@@ -2016,7 +2083,7 @@ export interface BindingOutputOptions {
   extend?: boolean
   externalLiveBindings?: boolean
   footer?: (chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>
-  format?: 'es' | 'cjs' | 'iife' | 'umd'
+  format?: 'es' | 'cjs' | 'iife' | 'umd' | 'amd'
   generatedCode?: BindingGeneratedCodeOptions
   globals?: Record<string, string> | ((name: string) => string)
   hashCharacters?: 'base64' | 'base36' | 'hex'
@@ -2052,6 +2119,7 @@ export interface BindingPluginContextResolvedId {
   packageJsonPath?: string
   external: boolean | 'absolute' | 'relative'
   moduleSideEffects?: boolean | 'no-treeshake'
+  resolveTrace?: BindingResolveTrace
 }
 
 export interface BindingPluginContextResolveOptions {
@@ -2162,6 +2230,14 @@ export declare enum BindingRebuildStrategy {
   Never = 2
 }
 
+export interface BindingRemoteImportsOptions {
+  cacheDir?: string
+  lockfile?: string
+  offline?: boolean
+  fetchDir?: string
+  fetch?: (url: string) => MaybePromise<string | Uint8Array>
+}
+
 export interface BindingRenderBuiltUrlConfig {
   ssr: boolean
   type: 'asset' | 'public'
@@ -2204,6 +2280,24 @@ export interface BindingResolveOptions {
   yarnPnp?: boolean
 }
 
+/** How a specifier was resolved, only present when `experimental.resolveTrace` is enabled. */
+export interface BindingResolveTrace {
+  /** Every step, formatted the same way as in resolution diagnostics. */
+  steps: Array<string>
+  /** The candidate paths that were tried but didn't exist. */
+  candidates: Array<string>
+  /** The plugin whose `resolveId` hook resolved the specifier. */
+  resolvedByPlugin?: string
+  /** The conditions that selected the target of the `exports` or `imports` entry used. */
+  matchedConditions?: Array<string>
+}
+
+export interface BindingSharedModuleOptions {
+  singleton?: boolean
+  requiredVersion?: string
+  version?: string
+}
+
 export interface BindingSourcemap {
   inner: string | BindingJsonSourcemap
 }
@@ -2220,6 +2314,7 @@ export interface BindingTreeshake {
   commonjs?: boolean
   propertyReadSideEffects?: BindingPropertyReadSideEffects
   propertyWriteSideEffects?: BindingPropertyWriteSideEffects
+  members?: boolean
 }
 
 export interface BindingViteAliasPluginAlias {
@@ -35,6 +35,7 @@ import type {
 } from './options/normalized-output-options';
 import type {
   AddonFunction,
+  AmdOptions,
   ChunkFileNamesFunction,
   ChunkingContext,
   GeneratedCodeOptions,
@@ -62,6 +63,7 @@ import type {
   ResolvedId,
   ResolveIdExtraOptions,
   ResolveIdResult,
+  ResolveTrace,
   RolldownPlugin,
   RolldownPluginOption,
   SourceDescription,
@@ -115,6 +117,7 @@ export { build, defineConfig, rolldown, VERSION, watch };
 export { BindingMagicString } from './binding.cjs';
 export type {
   AddonFunction,
+  AmdOptions,
   AsyncPluginHooks,
   BufferEncoding,
   BuildOptions,
@@ -174,6 +177,7 @@ export type {
   ResolvedId,
   ResolveIdExtraOptions,
   ResolveIdResult,
+  ResolveTrace,
   RolldownBuild,
   RolldownDirectoryEntry,
   RolldownFileStats,
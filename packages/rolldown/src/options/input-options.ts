@@ -7,7 +7,11 @@ import type {
 } from '../log/logging';
 import type { RolldownPluginOption } from '../plugin';
 import type { TreeshakingOptions } from '../types/module-side-effects';
-import type { NullValue, StringOrRegExp } from '../types/utils';
+import type {
+  MaybePromise,
+  NullValue,
+  StringOrRegExp,
+} from '../types/utils';
 import type { ChecksOptions } from './generated/checks-options';
 import type { TransformOptions } from './transform-options';
 
@@ -81,6 +85,9 @@ export type OptimizationOptions = {
    *  - `mode: 'all'`: Inline all imported constants wherever they are used.
    *  - `pass`: Number of passes to perform for inlining constants.
    *
+   * Members of top-level TypeScript enums whose values are all constant are inlined as well, and the
+   * enum object is removed once all of its uses are inlined.
+   *
    * **example**
    * ```js
    * // Input files:
@@ -314,6 +321,284 @@ export interface InputOptions {
      * > For a complete working example, see [examples/native-magic-string](https://github.com/rolldown/rolldown/tree/main/examples/native-magic-string)
      */
     nativeMagicString?: boolean;
+    /**
+     * Record how each import was resolved: the plugin whose `resolveId` hook returned a result,
+     * matched `resolve.alias` entries, condition names, and every candidate path that was tried.
+     *
+     * The trace is attached to `UNRESOLVED_IMPORT` and `RESOLVE_ERROR` diagnostics. Collecting it
+     * makes resolution slower, so only enable this while debugging.
+     *
+     * @default false
+     */
+    resolveTrace?: boolean;
+    /**
+     * Substitute Node built-in modules with browser polyfills when `platform` is `'browser'`.
+     *
+     * - `modules` maps a built-in (without the `node:` prefix) to a polyfill package. `true` uses the
+     *   package with the same name as the built-in, `false` replaces it with an empty module.
+     * - `globals` injects `process` and `Buffer` from their polyfills when they are referenced.
+     *   Defaults to `true`.
+     *
+     * @example
+     * ```js
+     * export default {
+     *   platform: 'browser',
+     *   experimental: {
+     *     nodePolyfills: {
+     *       modules: { buffer: true, process: 'process/browser', fs: false },
+     *     },
+     *   },
+     * }
+     * ```
+     */
+    nodePolyfills?: {
+      modules?: Record<string, string | boolean>;
+      globals?: boolean;
+    };
+    /**
+     * Always put the runtime helpers (e.g. `__toESM`, `__commonJS`, `__export`) into their own chunk
+     * named `rolldown-runtime`, instead of letting code splitting decide where they are placed.
+     *
+     * This keeps other chunks from being invalidated when the placement of the runtime would change.
+     * Has no effect for `iife` and `umd` formats or with `inlineDynamicImports`.
+     *
+     * @default false
+     */
+    standaloneRuntimeChunk?: boolean;
+    /**
+     * Merge common chunks whose rendered code is shorter than `minSize` bytes into another common
+     * chunk, to avoid emitting many tiny chunks.
+     *
+     * A merge never runs side effects in an entry that didn't run them before, and never creates
+     * a circular import between chunks. `tolerance` is how many bytes of code a merge may add to an
+     * entry that didn't load it before. Defaults to `minSize`.
+     *
+     * A number is a shorthand for `{ minSize }`.
+     */
+    minChunkSize?:
+      | number
+      | {
+          minSize: number;
+          tolerance?: number;
+        };
+    /**
+     * Rewrite each dynamic import so that the chunks it statically depends on, and their CSS files,
+     * start loading at the same time as the imported chunk, instead of after it has been parsed.
+     *
+     * Only applies to the `esm` format. The preloading is done with `<link>` tags, so it's a no-op
+     * outside of browsers.
+     *
+     * @default false
+     */
+    preloadDynamicImports?: boolean;
+    /**
+     * Path to a JSON file, relative to `cwd`, that stores the mangled names of each chunk and the
+     * names picked by `output.minifyInternalExports`. The file is read before generating and written
+     * back afterwards, so unchanged code keeps the same short names between builds.
+     *
+     * The file is created if it doesn't exist. It's meant to be committed alongside releases.
+     *
+     * Only top-level names are kept, so mangled names are only cached with `minify.mangle.toplevel`,
+     * and a warning is emitted without it. Names inside functions are picked again on every build.
+     */
+    nameCache?: string;
+    /**
+     * Rename object properties that match `regex`, such as private-by-convention `_fields`, with
+     * the same short name in every chunk.
+     *
+     * This breaks code that accesses those properties by computed names or from outside the
+     * bundle, so the pattern should only match properties that never leave it.
+     */
+    mangleProps?: {
+      /** Properties whose names match are mangled. */
+      regex: RegExp;
+      /** Names that are never mangled, even if they match `regex`. */
+      reserved?: string[];
+      /**
+       * Also mangle quoted properties, such as `obj['_x']` and `{ '_x': 1 }`.
+       * @default false
+       */
+      quoted?: boolean;
+      /**
+       * Path to a JSON file, relative to `cwd`, that keeps the name picked for each property
+       * between builds. Setting a property to `false` in this file prevents it from being mangled.
+       */
+      cache?: string;
+    };
+    /**
+     * Turn CommonJS modules that only assign their exports at the top level into ES modules, so
+     * they are scope hoisted and tree-shaken per export instead of being wrapped with
+     * `__commonJS`.
+     *
+     * Modules that are loaded with `require()` or `import()`, or used as entries, are kept as is.
+     *
+     * @default false
+     */
+    commonjsToEsm?: boolean;
+    /**
+     * Read the entry files of external CommonJS packages to find their named exports, the same
+     * way Node.js does, and report an error for named imports that don't exist instead of failing
+     * at runtime.
+     *
+     * Only applies to the `esm` output format.
+     *
+     * @default false
+     */
+    detectExternalCommonjsExports?: boolean;
+    /**
+     * Evaluate modules using top-level await concurrently with their siblings, as the spec does,
+     * instead of one after another.
+     *
+     * Modules that use top-level await, or import modules that do, are wrapped in async
+     * initializers, and the initializers of a module's imports are awaited together with
+     * `Promise.all`.
+     *
+     * @default false
+     */
+    concurrentTopLevelAwait?: boolean;
+    /**
+     * Allow top-level await with the `cjs`, `iife` and `umd` output formats.
+     *
+     * Chunks containing top-level await are wrapped in an async function. For `cjs`,
+     * `module.exports` is a promise that resolves to the exports once the chunk has run. For
+     * `iife` and `umd`, the factory function returns a promise.
+     *
+     * @default false
+     */
+    topLevelAwaitWrapper?: boolean;
+    /**
+     * Keep top-level directives other than `"use strict"`, such as `"use client"`, in the output.
+     *
+     * Modules with different directives are never put into the same chunk, and each chunk starts
+     * with the directives of its modules.
+     *
+     * @default false
+     */
+    preserveDirectives?: boolean;
+    /**
+     * Bundle `.html` files in `input` as entries.
+     *
+     * Module scripts, stylesheets and assets referenced by the HTML are bundled, and the HTML is
+     * emitted with references to the hashed output files, `modulepreload` links for the chunks the
+     * entry imports and entry scripts smaller than `inlineScriptLimit` bytes inlined.
+     *
+     * @default false
+     */
+    htmlEntries?: boolean | {
+      /**
+       * Entry scripts smaller than this size in bytes are inlined into the HTML. Set to `0` to
+       * never inline.
+       *
+       * @default 4096
+       */
+      inlineScriptLimit?: number;
+    };
+    /**
+     * Resolve specifiers with a [WHATWG import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps)
+     * before resolving them as usual.
+     *
+     * Specifiers mapped to urls are treated as externals, while specifiers mapped to paths are
+     * resolved from these paths. Paths in the import map are relative to `cwd`.
+     *
+     * @example
+     * ```js
+     * importMap: JSON.parse(fs.readFileSync('import_map.json', 'utf-8'))
+     * ```
+     */
+    importMap?: {
+      imports?: Record<string, string>;
+      scopes?: Record<string, Record<string, string>>;
+    };
+    /**
+     * Bundle `https://` and `http://` imports instead of treating them as externals.
+     *
+     * Remote modules are stored in a content-addressed cache, and the integrity of every url is
+     * recorded in a lockfile. A url whose content no longer matches its recorded integrity fails
+     * the build. With `offline`, modules are only read from the cache.
+     *
+     * @default false
+     */
+    remoteImports?: boolean | {
+      /**
+       * Directory of the cache, relative to `cwd`.
+       *
+       * @default 'node_modules/.rolldown/remote'
+       */
+      cacheDir?: string;
+      /**
+       * Path of the lockfile, relative to `cwd`.
+       *
+       * @default 'rolldown-lock.json'
+       */
+      lockfile?: string;
+      /**
+       * Only read remote modules from the cache. Urls missing from the lockfile or the cache fail
+       * the build.
+       *
+       * @default false
+       */
+      offline?: boolean;
+      /**
+       * Read `https://host/path` from `<fetchDir>/host/path` instead of fetching it. Ports and
+       * queries are kept, e.g. `https://host:8080/path?v=1` is read from
+       * `<fetchDir>/host_8080/path%3Fv=1`.
+       */
+      fetchDir?: string;
+      /**
+       * Fetch the content of a url. Defaults to the global `fetch`.
+       */
+      fetch?: (url: string) => MaybePromise<string | Uint8Array>;
+    };
+    /**
+     * Expose modules to other builds and consume modules exposed by them at runtime.
+     *
+     * `exposes` emits a remote entry chunk with `get` and `init` exports. Imports of `remotes` are
+     * rewritten to load the remote entry of the other build and look the module up in it, while
+     * imports of `shared` packages pick a version provided by any build of the page.
+     *
+     * Modules importing remote or shared modules use top-level await, so the output format has to
+     * be `esm`.
+     */
+    moduleFederation?: {
+      /**
+       * Name of the container.
+       */
+      name: string;
+      /**
+       * File name of the remote entry chunk.
+       *
+       * @default 'remoteEntry.js'
+       */
+      filename?: string;
+      /**
+       * Modules exposed to other builds, e.g. `{ './Button': './src/Button.jsx' }`.
+       */
+      exposes?: Record<string, string>;
+      /**
+       * Remote entries of other builds, e.g. `{ app2: 'https://example.com/remoteEntry.js' }`.
+       * `import Button from 'app2/Button'` then loads `./Button` exposed by `app2`.
+       */
+      remotes?: Record<string, string>;
+      /**
+       * Packages shared between the builds of a page.
+       */
+      shared?: string[] | Record<string, {
+        /**
+         * Only load a single version of the package on the page.
+         *
+         * @default false
+         */
+        singleton?: boolean;
+        /**
+         * Range of versions this build works with. Defaults to the range in `package.json`.
+         */
+        requiredVersion?: string;
+        /**
+         * Version provided by this build. Defaults to the version of the installed package.
+         */
+        version?: string;
+      }>;
+    };
   };
   /**
    * Configure how the code is transformed. This process happens after the `transform` hook.
@@ -9,6 +9,7 @@ import { PlainObjectLike } from '../types/plain-object-like';
 import type { StringOrRegExp } from '../types/utils';
 import type {
   AddonFunction,
+  AmdOptions,
   AssetFileNamesFunction,
   ChunkFileNamesFunction,
   GlobalsFunction,
@@ -18,7 +19,7 @@ import type {
 
 type PathsFunction = (id: string) => string;
 
-export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd';
+export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd' | 'amd';
 
 export interface NormalizedOutputOptions {
   name: string | undefined;
@@ -43,6 +44,7 @@ export interface NormalizedOutputOptions {
   extend: boolean;
   globals: Record<string, string> | GlobalsFunction;
   paths: Record<string, string> | PathsFunction | undefined;
+  amd: AmdOptions | undefined;
   hashCharacters: 'base64' | 'base36' | 'hex';
   sourcemapDebugIds: boolean;
   sourcemapIgnoreList:
@@ -94,7 +96,7 @@ export class NormalizedOutputOptionsImpl extends PlainObjectLike
   }
 
   @lazyProp
-  get format(): 'es' | 'cjs' | 'iife' | 'umd' {
+  get format(): 'es' | 'cjs' | 'iife' | 'umd' | 'amd' {
     return this.inner.format;
   }
 
@@ -190,6 +192,11 @@ export class NormalizedOutputOptionsImpl extends PlainObjectLike
     return this.outputOptions.paths;
   }
 
+  @lazyProp
+  get amd(): AmdOptions | undefined {
+    return this.outputOptions.amd;
+  }
+
   @lazyProp
   get hashCharacters(): 'base64' | 'base36' | 'hex' {
     return this.inner.hashCharacters;
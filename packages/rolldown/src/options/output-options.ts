@@ -54,7 +54,8 @@ export type ModuleFormat =
   | 'module'
   | 'commonjs'
   | 'iife'
-  | 'umd';
+  | 'umd'
+  | 'amd';
 
 export type AddonFunction = (chunk: RenderedChunk) => string | Promise<string>;
 
@@ -97,6 +98,20 @@ export interface ChunkingContext {
   getModuleInfo(moduleId: string): ModuleInfo | null;
 }
 
+export interface AmdOptions {
+  /**
+   * The id to use for the module, e.g. `define('my-bundle', ['dependency'], ...)`.
+   * Anonymous modules are generated if not specified.
+   */
+  id?: string;
+  /**
+   * The function name to use instead of `define`.
+   *
+   * @default 'define'
+   */
+  define?: string;
+}
+
 export interface OutputOptions {
   dir?: string;
   file?: string;
@@ -108,6 +123,7 @@ export interface OutputOptions {
    * - `'cjs'` and `'commonjs'` are the same format, all stand for CommonJS module.
    * - `'iife'` stands for [Immediately Invoked Function Expression](https://developer.mozilla.org/en-US/docs/Glossary/IIFE).
    * - `'umd'` stands for [Universal Module Definition](https://github.com/umdjs/umd).
+   * - `'amd'` stands for [Asynchronous Module Definition](https://github.com/amdjs/amdjs-api/wiki/AMD).
    *
    * @default 'esm'
    */
@@ -204,6 +220,10 @@ export interface OutputOptions {
    * ```
    */
   paths?: Record<string, string> | PathsFunction;
+  /**
+   * Options for the `amd` format.
+   */
+  amd?: AmdOptions;
   generatedCode?: Partial<GeneratedCodeOptions>;
   externalLiveBindings?: boolean;
   inlineDynamicImports?: boolean;
@@ -299,6 +319,28 @@ export interface OutputOptions {
      * Global fallback of [`{group}.minShareCount`](#advancedchunks-groups-minsharecount), if it's not specified in the group.
      */
     minShareCount?: number;
+    /**
+     * - Type: `'source' | 'rendered' | 'minified' | 'gzip'`
+     * - Default: `'source'`
+     *
+     * How the sizes compared with `minSize`, `maxSize`, `minModuleSize` and `maxModuleSize` are measured.
+     *
+     * - `'source'`: the length of the module source, before tree shaking.
+     * - `'rendered'`: the length of the code that survives tree shaking.
+     * - `'minified'`: the estimated length of the rendered code after removing comments and whitespace.
+     * - `'gzip'`: the estimated length of the minified code after gzip compression.
+     */
+    sizeMetric?: 'source' | 'rendered' | 'minified' | 'gzip';
+    /**
+     * - Type: `'size' | 'locality'`
+     * - Default: `'size'`
+     *
+     * How a group that exceeds `maxSize` is split into smaller parts.
+     *
+     * - `'size'`: sort modules by size and fill the parts from the smallest module.
+     * - `'locality'`: sort modules by path and split where neighboring paths differ the most, keeping modules of the same package and directory together.
+     */
+    splitStrategy?: 'size' | 'locality';
     /**
      * Groups to be used for advanced chunking.
      */
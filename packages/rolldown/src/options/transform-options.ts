@@ -60,8 +60,30 @@ export interface TransformOptions extends
    * }
    *
    * ```
+   *
+   * - Replace `import.meta.env` with an object, whose members are also replaced one by one, so
+   *   `import.meta.env.MODE` becomes `"production"` without the rest of the object
+   *
+   * ```js rolldown.config.js
+   * export default defineConfig({
+   *   transform: { define: { 'import.meta.env': { MODE: 'production', DEV: false } } },
+   * })
+   * ```
+   *
+   * - Replace every other member of `process.env` with `undefined`, using a key ending with `.*`
+   *
+   * ```js rolldown.config.js
+   * export default defineConfig({
+   *   transform: {
+   *     define: { 'process.env.NODE_ENV': "'production'", 'process.env.*': 'undefined' },
+   *   },
+   * })
+   * ```
+   *
+   * A member defined explicitly, like `import.meta.env.MODE`, takes precedence over the member of
+   * an object defined for `import.meta.env`.
    */
-  define?: Record<string, string>;
+  define?: Record<string, string | Record<string, unknown>>;
   /**
    * Inject import statements on demand.
    *
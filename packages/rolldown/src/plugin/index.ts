@@ -63,9 +63,22 @@ export interface ModuleOptions {
   packageJsonPath?: string;
 }
 
+/** How a specifier was resolved, only present when `experimental.resolveTrace` is enabled. */
+export interface ResolveTrace {
+  /** Every step, formatted the same way as in resolution diagnostics. */
+  steps: string[];
+  /** The candidate paths that were tried but didn't exist. */
+  candidates: string[];
+  /** The plugin whose `resolveId` hook resolved the specifier. */
+  resolvedByPlugin?: string;
+  /** The conditions that selected the target of the `exports` or `imports` entry used. */
+  matchedConditions?: string[];
+}
+
 export interface ResolvedId extends ModuleOptions {
   external: boolean | 'absolute';
   id: string;
+  resolveTrace?: ResolveTrace;
 }
 
 export interface PartialResolvedId extends Partial<PartialNull<ModuleOptions>> {
@@ -190,6 +190,7 @@ export class PluginContextImpl extends MinimalPluginContextImpl {
       moduleSideEffects: info.moduleSideEffects ?? res.moduleSideEffects ??
         null,
       packageJsonPath: res.packageJsonPath,
+      resolveTrace: res.resolveTrace,
     };
   }
 
@@ -19,4 +19,5 @@ export type TreeshakingOptions = {
   commonjs?: boolean;
   propertyReadSideEffects?: false | 'always';
   propertyWriteSideEffects?: false | 'always';
+  members?: boolean;
 };
@@ -212,9 +212,67 @@ function bindingifyExperimental(
     onDemandWrapping: experimental?.onDemandWrapping,
     incrementalBuild: experimental?.incrementalBuild,
     nativeMagicString: experimental?.nativeMagicString,
+    resolveTrace: experimental?.resolveTrace,
+    nodePolyfills: experimental?.nodePolyfills,
+    standaloneRuntimeChunk: experimental?.standaloneRuntimeChunk,
+    minChunkSize: experimental?.minChunkSize,
+    preloadDynamicImports: experimental?.preloadDynamicImports,
+    nameCache: experimental?.nameCache,
+    mangleProps: experimental?.mangleProps,
+    commonjsToEsm: experimental?.commonjsToEsm,
+    detectExternalCommonjsExports: experimental?.detectExternalCommonjsExports,
+    concurrentTopLevelAwait: experimental?.concurrentTopLevelAwait,
+    topLevelAwaitWrapper: experimental?.topLevelAwaitWrapper,
+    preserveDirectives: experimental?.preserveDirectives,
+    htmlEntries: experimental?.htmlEntries,
+    importMap: experimental?.importMap,
+    remoteImports: bindingifyRemoteImports(experimental?.remoteImports),
+    moduleFederation: bindingifyModuleFederation(
+      experimental?.moduleFederation,
+    ),
   };
 }
 
+function bindingifyModuleFederation(
+  moduleFederation: NonNullable<
+    InputOptions['experimental']
+  >['moduleFederation'],
+): BindingExperimentalOptions['moduleFederation'] {
+  if (!moduleFederation) {
+    return undefined;
+  }
+  const { shared, ...rest } = moduleFederation;
+  return {
+    ...rest,
+    shared: Array.isArray(shared)
+      ? Object.fromEntries(shared.map((name) => [name, {}]))
+      : shared,
+  };
+}
+
+function bindingifyRemoteImports(
+  remoteImports: NonNullable<InputOptions['experimental']>['remoteImports'],
+): BindingExperimentalOptions['remoteImports'] {
+  if (!remoteImports) {
+    return undefined;
+  }
+  const options = remoteImports === true ? {} : remoteImports;
+  return {
+    ...options,
+    fetch: options.fetch ?? (options.fetchDir ? undefined : fetchRemoteModule),
+  };
+}
+
+async function fetchRemoteModule(url: string): Promise<Uint8Array> {
+  const response = await fetch(url);
+  if (!response.ok) {
+    throw new Error(
+      `${response.status} ${response.statusText} while fetching ${url}`,
+    );
+  }
+  return new Uint8Array(await response.arrayBuffer());
+}
+
 function bindingifyResolve(
   resolve: InputOptions['resolve'],
 ): BindingInputOptions['resolve'] {
@@ -365,6 +423,7 @@ function bindingifyTreeshakeOptions(
     manualPureFunctions: config.manualPureFunctions,
     unknownGlobalSideEffects: config.unknownGlobalSideEffects,
     commonjs: config.commonjs,
+    members: config.members,
   };
   switch (config.propertyReadSideEffects) {
     case 'always':
@@ -31,6 +31,7 @@ export function bindingifyOutputOptions(
     esModule,
     globals,
     paths,
+    amd,
     generatedCode,
     file,
     sanitizeFileName,
@@ -67,6 +68,7 @@ export function bindingifyOutputOptions(
     extend: outputOptions.extend,
     globals,
     paths,
+    amd,
     generatedCode,
     esModule,
     name,
@@ -126,6 +128,9 @@ function bindingifyFormat(
     case 'umd': {
       return 'umd';
     }
+    case 'amd': {
+      return 'amd';
+    }
     default:
       unimplemented(`output.format: ${format}`);
   }
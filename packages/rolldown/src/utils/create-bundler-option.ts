@@ -1,4 +1,7 @@
-import type { BindingBundlerOptions } from '../binding.cjs';
+import type {
+  BindingBundlerOptions,
+  BindingOutputOptions,
+} from '../binding.cjs';
 import type { LogHandler } from '../log/log-handler';
 import { getLogger, getOnLog } from '../log/logger';
 import { LOG_LEVEL_INFO } from '../log/logging';
@@ -89,6 +92,33 @@ export async function createBundlerOptions(
   }
 }
 
+/**
+ * Converts the options of an additional output generated from the same build, see
+ * `RolldownBuild#generateOutputs`. The modules are scanned once, so only the first output can
+ * have plugins.
+ */
+export async function createAdditionalOutputOptions(
+  inputOptions: InputOptions,
+  outputOptions: OutputOptions,
+  onLog: LogHandler,
+): Promise<BindingOutputOptions> {
+  if ((await normalizePluginOption(outputOptions.plugins)).length > 0) {
+    throw new Error(
+      'Only the first output can have `plugins` when generating multiple outputs.',
+    );
+  }
+  const inputPlugins = await normalizePluginOption(inputOptions.plugins);
+  const logLevel = inputOptions.logLevel || LOG_LEVEL_INFO;
+  outputOptions = PluginDriver.callOutputOptionsHook(
+    inputPlugins,
+    outputOptions,
+    onLog,
+    logLevel,
+    false,
+  );
+  return bindingifyOutputOptions(outputOptions);
+}
+
 export interface BundlerOptionWithStopWorker {
   bundlerOptions: BindingBundlerOptions;
   inputOptions: InputOptions;
@@ -18,8 +18,12 @@ export function normalizeTransformOptions(
 ): NormalizedTransformOptions {
   const transform = inputOptions.transform;
 
+  // Objects are passed as JSON, their members are defined one by one on the Rust side.
   const define = transform?.define
-    ? Object.entries(transform.define)
+    ? Object.entries(transform.define).map(([key, value]): [string, string] => [
+      key,
+      typeof value === 'string' ? value : JSON.stringify(value),
+    ])
     : undefined;
   const inject = transform?.inject;
   const dropLabels = transform?.dropLabels;
@@ -37,7 +37,7 @@ import type {
   SourcemapPathTransformOption,
 } from '../types/misc';
 import type { RenderedChunk } from '../types/rolldown-output';
-import type { AnyFn } from '../types/utils';
+import type { AnyFn, MaybePromise } from '../types/utils';
 import { flattenValibotSchema } from './flatten-valibot-schema';
 import { styleText } from './style-text';
 
@@ -197,7 +197,12 @@ const TransformOptionsSchema = v.object({
     v.description('The JavaScript target environment'),
   ),
   define: v.pipe(
-    v.optional(v.record(v.string(), v.string())),
+    v.optional(
+      v.record(
+        v.string(),
+        v.union([v.string(), v.record(v.string(), v.unknown())]),
+      ),
+    ),
     v.description('Define global variables (syntax: key=value,key2=value2)'),
   ),
   inject: v.pipe(
@@ -433,6 +438,7 @@ const TreeshakingOptionsSchema = v.union([
     propertyWriteSideEffects: v.optional(
       v.union([v.literal(false), v.literal('always')]),
     ),
+    members: v.optional(v.boolean()),
   }),
 ]);
 
@@ -568,6 +574,85 @@ const InputOptionsSchema = v.strictObject({
         }),
       ])),
       nativeMagicString: v.optional(v.boolean()),
+      resolveTrace: v.optional(v.boolean()),
+      nodePolyfills: v.optional(
+        v.strictObject({
+          modules: v.optional(
+            v.record(v.string(), v.union([v.string(), v.boolean()])),
+          ),
+          globals: v.optional(v.boolean()),
+        }),
+      ),
+      standaloneRuntimeChunk: v.optional(v.boolean()),
+      minChunkSize: v.optional(
+        v.union([
+          v.number(),
+          v.strictObject({
+            minSize: v.number(),
+            tolerance: v.optional(v.number()),
+          }),
+        ]),
+      ),
+      preloadDynamicImports: v.optional(v.boolean()),
+      nameCache: v.optional(v.string()),
+      mangleProps: v.optional(
+        v.strictObject({
+          regex: v.instance(RegExp),
+          reserved: v.optional(v.array(v.string())),
+          quoted: v.optional(v.boolean()),
+          cache: v.optional(v.string()),
+        }),
+      ),
+      commonjsToEsm: v.optional(v.boolean()),
+      detectExternalCommonjsExports: v.optional(v.boolean()),
+      concurrentTopLevelAwait: v.optional(v.boolean()),
+      topLevelAwaitWrapper: v.optional(v.boolean()),
+      preserveDirectives: v.optional(v.boolean()),
+      htmlEntries: v.optional(v.union([
+        v.boolean(),
+        v.strictObject({
+          inlineScriptLimit: v.optional(v.number()),
+        }),
+      ])),
+      importMap: v.optional(
+        v.strictObject({
+          imports: v.optional(v.record(v.string(), v.string())),
+          scopes: v.optional(
+            v.record(v.string(), v.record(v.string(), v.string())),
+          ),
+        }),
+      ),
+      remoteImports: v.optional(v.union([
+        v.boolean(),
+        v.strictObject({
+          cacheDir: v.optional(v.string()),
+          lockfile: v.optional(v.string()),
+          offline: v.optional(v.boolean()),
+          fetchDir: v.optional(v.string()),
+          fetch: v.optional(
+            vFunction<(url: string) => MaybePromise<string | Uint8Array>>(),
+          ),
+        }),
+      ])),
+      moduleFederation: v.optional(
+        v.strictObject({
+          name: v.string(),
+          filename: v.optional(v.string()),
+          exposes: v.optional(v.record(v.string(), v.string())),
+          remotes: v.optional(v.record(v.string(), v.string())),
+          shared: v.optional(v.union([
+            v.array(v.string()),
+            v.record(
+              v.string(),
+              v.strictObject({
+                singleton: v.optional(v.boolean()),
+                requiredVersion: v.optional(v.string()),
+                version: v.optional(v.string()),
+              }),
+            ),
+          ])),
+        }),
+      ),
     }),
   ),
   transform: v.optional(TransformOptionsSchema),
@@ -652,6 +737,7 @@ const ModuleFormatSchema = v.union([
   v.literal('commonjs'),
   v.literal('iife'),
   v.literal('umd'),
+  v.literal('amd'),
 ]);
 
 const AddonFunctionSchema = v.pipe(
@@ -735,6 +821,15 @@ const AdvancedChunksSchema = v.strictObject({
   minModuleSize: v.optional(v.number()),
   maxModuleSize: v.optional(v.number()),
   minShareCount: v.optional(v.number()),
+  sizeMetric: v.optional(
+    v.union([
+      v.literal('source'),
+      v.literal('rendered'),
+      v.literal('minified'),
+      v.literal('gzip'),
+    ]),
+  ),
+  splitStrategy: v.optional(v.union([v.literal('size'), v.literal('locality')])),
   groups: v.optional(
     v.array(
       v.strictObject({
@@ -764,6 +859,17 @@ const GeneratedCodePresetSchema = v.union([
   v.literal('es2015'),
 ]);
 
+const AmdOptionsSchema = v.strictObject({
+  id: v.pipe(
+    v.optional(v.string()),
+    v.description('ID for AMD module (default is anonymous)'),
+  ),
+  define: v.pipe(
+    v.optional(v.string()),
+    v.description('Function to use in place of `define`'),
+  ),
+});
+
 const GeneratedCodeOptionsSchema = v.strictObject({
   symbols: v.pipe(
     v.optional(v.boolean()),
@@ -810,7 +916,7 @@ const OutputOptionsSchema = v.strictObject({
     v.description(
       `Output format of the generated bundle (supports ${
         styleText('underline', 'esm')
-      }, cjs, and iife)`,
+      }, cjs, iife, umd, and amd)`,
     ),
   ),
   sourcemap: v.pipe(
@@ -887,6 +993,10 @@ const OutputOptionsSchema = v.strictObject({
       'Maps external module IDs to paths',
     ),
   ),
+  amd: v.pipe(
+    v.optional(AmdOptionsSchema),
+    v.description('Options for the AMD format'),
+  ),
   generatedCode: v.pipe(
     v.optional(
       v.partial(GeneratedCodeOptionsSchema),
@@ -0,0 +1,125 @@
+import { mkdtempSync, readFileSync, rmSync, writeFileSync } from 'node:fs';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { rolldown } from 'rolldown';
+import { afterEach, beforeEach, expect, test } from 'vitest';
+
+let cwd: string;
+
+beforeEach(() => {
+  cwd = mkdtempSync(join(tmpdir(), 'rolldown-name-cache-'));
+});
+
+afterEach(() => {
+  rmSync(cwd, { recursive: true, force: true });
+});
+
+const declare = (name: string) =>
+  `function ${name}(value) { console.log('${name}', value); return value }\n`;
+
+async function build(source: string) {
+  writeFileSync(join(cwd, 'main.js'), source);
+  const bundle = await rolldown({
+    input: './main.js',
+    cwd,
+    experimental: { nameCache: 'name-cache.json' },
+  });
+  const output = await bundle.generate({ minify: true });
+  await bundle.close();
+  const cache = JSON.parse(
+    readFileSync(join(cwd, 'name-cache.json'), 'utf-8'),
+  );
+  const [names] = Object.values<Record<string, Record<string, string>>>(
+    cache.outputs['esm:dist/[name].js'].chunks,
+  );
+  return { code: output.output[0].code, names };
+}
+
+test('keeps the mangled names of unchanged declarations across builds', async () => {
+  const usage = (names: string[]) =>
+    `globalThis.run = () => [${names.map((name) => `${name}(1), ${name}(2)`).join(', ')}]\n`;
+
+  const first = await build(
+    declare('alpha') + declare('beta') + usage(['alpha', 'beta']),
+  );
+  // A new declaration before the others would take their names without the cache.
+  const second = await build(
+    declare('gamma') +
+      declare('alpha') +
+      declare('beta') +
+      usage(['gamma', 'gamma', 'alpha', 'beta']),
+  );
+
+  expect(second.names.alpha).toBe(first.names.alpha);
+  expect(second.names.beta).toBe(first.names.beta);
+  expect(second.names.gamma).not.toBe(first.names.alpha);
+  expect(second.names.gamma).not.toBe(first.names.beta);
+  expect(second.code).toContain(`function ${first.names.alpha}(`);
+  expect(second.code).toContain(`function ${first.names.beta}(`);
+});
+
+test('only avoids the local names that would shadow a declaration', async () => {
+  // Enough locals to use up the short names, none of which reference `alpha`.
+  const locals = Array.from({ length: 60 }, (_, i) => `v${i}`);
+  const withLocals = (name: string) =>
+    `function ${name}() { ${locals.map((local) => `let ${local} = Math.random();`).join(' ')} ` +
+    `return [${locals.map((local) => `${local}, ${local}`).join(', ')}] }\n`;
+  const { names } = await build(
+    declare('alpha') +
+      withLocals('many') +
+      'globalThis.run = () => [alpha(1), alpha(2), many(), many()]\n',
+  );
+
+  expect(names.alpha).toHaveLength(1);
+  expect(names.many).toHaveLength(1);
+});
+
+test('keeps a separate entry for each output', async () => {
+  writeFileSync(
+    join(cwd, 'main.js'),
+    declare('alpha') + 'globalThis.run = () => [alpha(1), alpha(2)]\n',
+  );
+  const bundle = await rolldown({
+    input: './main.js',
+    cwd,
+    experimental: { nameCache: 'name-cache.json' },
+  });
+  await bundle.generateOutputs([
+    { format: 'esm', minify: true },
+    { format: 'cjs', entryFileNames: '[name].cjs', minify: true },
+  ]);
+  await bundle.close();
+
+  const cache = JSON.parse(
+    readFileSync(join(cwd, 'name-cache.json'), 'utf-8'),
+  );
+  expect(Object.keys(cache.outputs)).toStrictEqual([
+    'cjs:dist/[name].cjs',
+    'esm:dist/[name].js',
+  ]);
+  for (const output of Object.values<any>(cache.outputs)) {
+    const [names] = Object.values<Record<string, string>>(output.chunks);
+    expect(names.alpha).toBeDefined();
+  }
+});
+
+test('warns that only top-level names are cached', async () => {
+  writeFileSync(
+    join(cwd, 'main.js'),
+    declare('alpha') + 'globalThis.run = () => [alpha(1), alpha(2)]\n',
+  );
+  const logs: string[] = [];
+  const bundle = await rolldown({
+    input: './main.js',
+    cwd,
+    experimental: { nameCache: 'name-cache.json' },
+    onLog: (_level, log) => {
+      if (log.code === 'INVALID_OPTION') logs.push(log.message);
+    },
+  });
+  await bundle.generate({ minify: { mangle: { toplevel: false } } });
+  await bundle.close();
+
+  expect(logs).toHaveLength(1);
+  expect(logs[0]).toContain('minify.mangle.toplevel');
+});
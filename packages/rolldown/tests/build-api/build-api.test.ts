@@ -1,5 +1,5 @@
 import path from 'node:path';
-import { rolldown } from 'rolldown';
+import { build, rolldown } from 'rolldown';
 import { expect, test } from 'vitest';
 
 test('rolldown write twice', async () => {
@@ -145,3 +145,83 @@ test('output properties are enumerable and can be spread', async () => {
   expect(Object.keys(result.output[0])).toContain('code');
   expect(Object.keys(result.output[0])).toContain('exports');
 });
+
+test('generateOutputs scans once and generates each output', async () => {
+  let buildStartCalls = 0;
+  const bundle = await rolldown({
+    input: './main.js',
+    cwd: import.meta.dirname,
+    plugins: [
+      {
+        name: 'test',
+        buildStart() {
+          buildStartCalls++;
+        },
+      },
+    ],
+  });
+  const [esmOutput, cjsOutput, iifeOutput] = await bundle.generateOutputs([
+    { format: 'esm', entryFileNames: 'main.mjs' },
+    { format: 'cjs', entryFileNames: 'main.cjs' },
+    { format: 'iife', entryFileNames: 'main.iife.js' },
+  ]);
+  await bundle.close();
+
+  expect(buildStartCalls).toBe(1);
+  expect(esmOutput.output[0].fileName).toBe('main.mjs');
+  expect(cjsOutput.output[0].fileName).toBe('main.cjs');
+  expect(iifeOutput.output[0].fileName).toBe('main.iife.js');
+  expect(iifeOutput.output[0].code.includes('(function() {')).toBe(true);
+});
+
+test('generateOutputs names the assets emitted while building per output', async () => {
+  const logs: string[] = [];
+  const bundle = await rolldown({
+    input: './main.js',
+    cwd: import.meta.dirname,
+    onLog(_level, log) {
+      logs.push(log.code!);
+    },
+    plugins: [
+      {
+        name: 'test',
+        buildStart() {
+          this.emitFile({ type: 'asset', name: 'data.txt', source: 'data' });
+        },
+      },
+    ],
+  });
+  const [first, second] = await bundle.generateOutputs([
+    { assetFileNames: 'first/[name][extname]' },
+    { assetFileNames: 'second/[name]-[hash][extname]', hashCharacters: 'hex' },
+  ]);
+  await bundle.close();
+
+  const assetFileNames = (output: typeof first) =>
+    output.output.filter((o) => o.type === 'asset').map((o) => o.fileName);
+  expect(assetFileNames(first)).toStrictEqual(['first/data.txt']);
+  expect(assetFileNames(second)).toHaveLength(1);
+  expect(assetFileNames(second)[0]).toMatch(/^second\/data-[0-9a-f]{8}\.txt$/);
+  expect(logs).not.toContain('FILENAME_CONFLICT');
+});
+
+test('build generates each output of an `output` array', async () => {
+  const outputs = await build({
+    input: './main.js',
+    cwd: import.meta.dirname,
+    write: false,
+    output: [{ format: 'esm' }, { format: 'cjs', entryFileNames: 'main.cjs' }],
+  });
+  expect(outputs.map((output) => output.output[0].fileName)).toStrictEqual([
+    'main.js',
+    'main.cjs',
+  ]);
+});
+
+test('generateOutputs rejects plugins in additional outputs', async () => {
+  const bundle = await rolldown({ input: './main.js', cwd: import.meta.dirname });
+  await expect(
+    bundle.generateOutputs([{}, { plugins: [{ name: 'output-plugin' }] }]),
+  ).rejects.toThrow('Only the first output can have `plugins`');
+  await bundle.close();
+});
@@ -0,0 +1,42 @@
+import fs from 'node:fs';
+import path from 'node:path';
+import { rolldown } from 'rolldown';
+import { defineTest } from 'rolldown-tests';
+import { expect } from 'vitest';
+
+const url = 'https://cdn.example.com:8443/lib.js?v=1';
+const remoteImports = {
+  cacheDir: 'temp/cache',
+  lockfile: 'temp/rolldown-lock.json',
+};
+
+export default defineTest({
+  async beforeTest() {
+    fs.rmSync(path.join(import.meta.dirname, 'temp'), {
+      recursive: true,
+      force: true,
+    });
+    // Fetch the url from `fetch/cdn.example.com_8443/lib.js%3Fv=1` to fill the cache.
+    const build = await rolldown({
+      input: 'main.js',
+      cwd: import.meta.dirname,
+      experimental: { remoteImports: { ...remoteImports, fetchDir: 'fetch' } },
+    });
+    await build.generate();
+    await build.close();
+  },
+  config: {
+    // Without a fetcher, the url can only come from the cache.
+    experimental: { remoteImports: { ...remoteImports, offline: true } },
+  },
+  afterTest(output) {
+    expect(output.output[0].code).toContain('cached');
+    const lockfile = JSON.parse(
+      fs.readFileSync(
+        path.join(import.meta.dirname, 'temp/rolldown-lock.json'),
+        'utf-8',
+      ),
+    );
+    expect(Object.keys(lockfile.remote)).toStrictEqual([url]);
+  },
+});
@@ -0,0 +1,3 @@
+import { value } from 'https://cdn.example.com:8443/lib.js?v=1'
+
+export { value }
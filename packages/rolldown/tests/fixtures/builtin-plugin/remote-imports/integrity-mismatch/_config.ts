@@ -0,0 +1,39 @@
+import fs from 'node:fs';
+import path from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect } from 'vitest';
+
+const temp = path.join(import.meta.dirname, 'temp');
+const url = 'https://cdn.example.com/lib.js';
+const integrity = `blake3-${'0'.repeat(64)}`;
+
+export default defineTest({
+  beforeTest() {
+    fs.rmSync(temp, { recursive: true, force: true });
+    fs.mkdirSync(temp);
+    // Recorded for other content, which isn't in the cache, so the url is fetched again.
+    fs.writeFileSync(
+      path.join(temp, 'rolldown-lock.json'),
+      JSON.stringify({ version: 1, remote: { [url]: integrity } }),
+    );
+  },
+  config: {
+    experimental: {
+      remoteImports: {
+        cacheDir: 'temp/cache',
+        lockfile: 'temp/rolldown-lock.json',
+        fetchDir: 'fetch',
+      },
+    },
+  },
+  catchError(err) {
+    const message = (err as Error).message;
+    expect(message).toContain(
+      `The content of ${url} doesn't match the integrity recorded`,
+    );
+    expect(message).toContain(`expected ${integrity}`);
+  },
+  afterTest() {
+    expect.unreachable('the fetched content should not match the lockfile');
+  },
+});
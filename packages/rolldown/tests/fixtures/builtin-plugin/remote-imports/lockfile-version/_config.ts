@@ -0,0 +1,35 @@
+import fs from 'node:fs';
+import path from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect } from 'vitest';
+
+const temp = path.join(import.meta.dirname, 'temp');
+
+export default defineTest({
+  beforeTest() {
+    fs.rmSync(temp, { recursive: true, force: true });
+    fs.mkdirSync(temp);
+    // Written by a newer version, whose entries may not mean the same thing.
+    fs.writeFileSync(
+      path.join(temp, 'rolldown-lock.json'),
+      JSON.stringify({ version: 2, remote: {} }),
+    );
+  },
+  config: {
+    experimental: {
+      remoteImports: {
+        cacheDir: 'temp/cache',
+        lockfile: 'temp/rolldown-lock.json',
+        offline: true,
+      },
+    },
+  },
+  catchError(err) {
+    expect((err as Error).message).toContain(
+      "version 2 isn't supported, expected version 1",
+    );
+  },
+  afterTest() {
+    expect.unreachable('the lockfile version should be rejected');
+  },
+});
@@ -0,0 +1,34 @@
+import fs from 'node:fs';
+import path from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect } from 'vitest';
+
+export default defineTest({
+  beforeTest() {
+    fs.rmSync(path.join(import.meta.dirname, 'temp'), {
+      recursive: true,
+      force: true,
+    });
+  },
+  config: {
+    experimental: {
+      remoteImports: {
+        cacheDir: 'temp/cache',
+        lockfile: 'temp/rolldown-lock.json',
+        offline: true,
+        // Never used in offline mode.
+        fetchDir: 'fetch',
+      },
+    },
+  },
+  catchError(err) {
+    const message = (err as Error).message;
+    expect(message).toContain(
+      "https://cdn.example.com/lib.js isn't recorded in the lockfile",
+    );
+    expect(message).toContain("can't be fetched in offline mode");
+  },
+  afterTest() {
+    expect.unreachable('offline builds should not fetch');
+  },
+});
@@ -0,0 +1,3 @@
+import { value } from 'https://cdn.example.com/lib.js'
+
+export { value }
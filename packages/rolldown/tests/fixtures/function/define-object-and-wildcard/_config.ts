@@ -0,0 +1,37 @@
+import { defineTest } from 'rolldown-tests';
+import { expect, vi } from 'vitest';
+
+const fn = vi.fn();
+
+export default defineTest({
+  config: {
+    transform: {
+      define: {
+        'import.meta.env': { MODE: 'production', UNUSED: 'unused-value' },
+        // Overlaps with `import.meta.env`, and takes precedence over its `MODE` member
+        'import.meta.env.MODE': '"development"',
+        'process.env.NODE_ENV': '"production"',
+        'process.env.*': 'undefined',
+      },
+    },
+    onLog(_level, log) {
+      fn(log);
+    },
+  },
+  beforeTest: () => {
+    fn.mockClear();
+  },
+  async afterTest(output) {
+    expect(output.output[0].code).not.toContain('unused-value');
+    const { mode, nodeEnv, other } = await import('./dist/main.js');
+    expect(mode).toBe('development');
+    expect(nodeEnv).toBe('production');
+    expect(other).toBeUndefined();
+
+    // Defining both a key and one of its members is common and not reported
+    const invalidOptionLogs = fn.mock.calls
+      .map(([log]) => log)
+      .filter((log) => log.code === 'INVALID_OPTION');
+    expect(invalidOptionLogs).toHaveLength(0);
+  },
+});
@@ -0,0 +1,3 @@
+export const mode = import.meta.env.MODE
+export const nodeEnv = process.env.NODE_ENV
+export const other = process.env.OTHER
@@ -0,0 +1,41 @@
+import path from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect, vi } from 'vitest';
+
+const resolveIdFn = vi.fn();
+
+export default defineTest({
+  config: {
+    experimental: {
+      importMap: {
+        imports: {
+          // Entries aren't mapped
+          './main.js': './src/not-an-entry.js',
+          utils: './src/utils.js',
+          react: 'https://esm.sh/react@19',
+        },
+      },
+    },
+    plugins: [
+      {
+        name: 'test-plugin',
+        resolveId(id, _importer, options) {
+          resolveIdFn(id, options.isEntry);
+        },
+      },
+    ],
+  },
+  beforeTest: () => {
+    resolveIdFn.mockClear();
+  },
+  afterTest: (output) => {
+    // The import map is applied before `resolveId`, which sees the mapped path instead of
+    // `utils`. Urls are externals and don't reach `resolveId`.
+    expect(resolveIdFn.mock.calls).toStrictEqual([
+      ['main.js', true],
+      [path.join(__dirname, 'src/utils.js'), false],
+    ]);
+    expect(output.output[0].code).toContain('https://esm.sh/react@19');
+    expect(output.output[0].code).not.toContain('not an entry');
+  },
+});
@@ -0,0 +1,4 @@
+import { utils } from 'utils';
+import * as React from 'react';
+
+console.log(utils, React);
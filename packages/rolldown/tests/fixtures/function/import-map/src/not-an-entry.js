@@ -0,0 +1 @@
+console.log('not an entry');
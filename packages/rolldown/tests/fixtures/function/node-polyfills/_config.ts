@@ -0,0 +1,31 @@
+import nodePath from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect } from 'vitest';
+
+export default defineTest({
+  config: {
+    platform: 'browser',
+    resolve: {
+      // Stands in for the `events` package from npm
+      alias: {
+        events: nodePath.join(import.meta.dirname, 'events-shim.js'),
+      },
+    },
+    experimental: {
+      nodePolyfills: {
+        modules: {
+          // `true` uses the package with the same name as the built-in
+          events: true,
+          // `false` uses an empty module
+          fs: false,
+        },
+      },
+    },
+  },
+  async afterTest(output) {
+    expect(output.output[0].code).not.toMatch(/from ["'](node:events|fs)["']/);
+    const { events, fsKeys } = await import('./dist/main.js');
+    expect(events).toBe(true);
+    expect(fsKeys).toStrictEqual([]);
+  },
+});
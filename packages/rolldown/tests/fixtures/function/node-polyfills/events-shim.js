@@ -0,0 +1,3 @@
+export class EventEmitter {
+  static polyfilled = true
+}
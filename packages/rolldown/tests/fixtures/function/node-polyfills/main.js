@@ -0,0 +1,5 @@
+import { EventEmitter } from 'node:events'
+import * as fs from 'fs'
+
+export const events = EventEmitter.polyfilled
+export const fsKeys = Object.keys(fs)
@@ -0,0 +1,50 @@
+import path from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect, vi } from 'vitest';
+
+const fn = vi.fn();
+
+export default defineTest({
+  config: {
+    resolve: {
+      modules: ['modules'],
+    },
+    experimental: {
+      resolveTrace: true,
+    },
+    plugins: [
+      {
+        name: 'virtual',
+        resolveId(id) {
+          if (id === 'virtual') {
+            return '\0virtual';
+          }
+        },
+      },
+      {
+        name: 'test-plugin-context',
+        async buildStart() {
+          const importer = path.join(import.meta.dirname, 'main.js');
+
+          const pkg = await this.resolve('pkg', importer);
+          expect(pkg?.id).toBe(
+            path.join(import.meta.dirname, 'modules/pkg/esm.js'),
+          );
+          expect(pkg?.resolveTrace?.matchedConditions).toStrictEqual(['import']);
+          expect(pkg?.resolveTrace?.resolvedByPlugin).toBeFalsy();
+          expect(pkg?.resolveTrace?.steps.at(-1)).toBe(`resolved to ${pkg?.id}`);
+
+          const virtual = await this.resolve('virtual', importer);
+          expect(virtual?.resolveTrace?.resolvedByPlugin).toBe('virtual');
+          expect(virtual?.resolveTrace?.steps).toStrictEqual([
+            'resolved by the `resolveId` hook of plugin "virtual"',
+          ]);
+          fn();
+        },
+      },
+    ],
+  },
+  afterTest: () => {
+    expect(fn).toHaveBeenCalledTimes(1);
+  },
+});
@@ -0,0 +1,3 @@
+import value from 'pkg';
+
+export default value;
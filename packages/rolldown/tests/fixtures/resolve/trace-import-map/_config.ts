@@ -0,0 +1,25 @@
+import path from 'node:path';
+import { defineTest } from 'rolldown-tests';
+import { expect } from 'vitest';
+
+export default defineTest({
+  config: {
+    experimental: {
+      resolveTrace: true,
+      importMap: {
+        imports: {
+          utils: './src/utils.js',
+        },
+      },
+    },
+  },
+  catchError(err: any) {
+    const message = String(err);
+    // The trace belongs to the specifier the import map rewrote `utils` to.
+    expect(message).toContain('Resolution trace:');
+    expect(message).toContain(
+      `tried ${path.join(import.meta.dirname, 'src/utils.js')}`,
+    );
+    expect(message).toContain('failed: ');
+  },
+});
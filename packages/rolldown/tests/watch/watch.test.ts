@@ -782,6 +782,30 @@ if (process.platform === 'win32') {
   });
 }
 
+test.sequential('watch re-expands input globs', async () => {
+  const cwd = path.join(import.meta.dirname, 'temp', 'watch-input-globs');
+  fs.rmSync(cwd, { recursive: true, force: true });
+  fs.mkdirSync(path.join(cwd, 'pages'), { recursive: true });
+  fs.writeFileSync(path.join(cwd, 'pages/a.js'), 'console.log("a")');
+  await sleep(60);
+  const watcher = watch({
+    cwd,
+    input: ['pages/*.js'],
+    watch: {
+      buildDelay: 50,
+    },
+  });
+  onTestFinished(() => watcher.close());
+  await waitBuildFinished(watcher);
+  expect(fs.readdirSync(path.join(cwd, 'dist'))).toEqual(['a.js']);
+
+  // Adding a file matching the pattern adds an entry.
+  fs.writeFileSync(path.join(cwd, 'pages/b.js'), 'console.log("b")');
+  await waitUtil(() => {
+    expect(fs.existsSync(path.join(cwd, 'dist/b.js'))).toBe(true);
+  });
+});
+
 test.sequential('watch close immediately', async () => {
   const { input, output } = await createTestInputAndOutput(
     'watch-close-immediately',